@@ -1013,6 +1013,10 @@ pub enum UntaggedOperatorName {
     SQLIndexOfCP,
     #[serde(rename = "$sqlIs")]
     SQLIs,
+    #[serde(rename = "$sqlLeft")]
+    SQLLeft,
+    #[serde(rename = "$sqlLPad")]
+    SQLLPad,
     #[serde(rename = "$sqlLog")]
     SQLLog,
     #[serde(rename = "$sqlLt")]
@@ -1031,6 +1035,10 @@ pub enum UntaggedOperatorName {
     SQLOr,
     #[serde(rename = "$sqlPos")]
     SQLPos,
+    #[serde(rename = "$sqlRight")]
+    SQLRight,
+    #[serde(rename = "$sqlRPad")]
+    SQLRPad,
     #[serde(rename = "$sqlRound")]
     SQLRound,
     #[serde(rename = "$sqlSin")]
@@ -1364,6 +1372,7 @@ pub struct Filter {
     #[serde(rename = "as")]
     pub _as: Option<String>,
     pub cond: Box<Expression>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<Box<Expression>>,
 }
 
@@ -655,6 +655,12 @@ mod stage_test {
             expected = Stage::Count("a".to_string()),
             input = r#"stage: {"$count": "a"}"#
         );
+
+        test_serde_stage!(
+            count_preserves_field_name,
+            expected = Stage::Count("Total Count_2".to_string()),
+            input = r#"stage: {"$count": "Total Count_2"}"#
+        );
     }
 
     mod sort {
@@ -1170,7 +1176,9 @@ mod stage_test {
 
     mod add_fields {
         use crate::{
-            definitions::{Expression, LiteralValue, Stage},
+            definitions::{
+                Expression, LiteralValue, Ref, Stage, UntaggedOperator, UntaggedOperatorName,
+            },
             map,
         };
 
@@ -1198,6 +1206,20 @@ mod stage_test {
             input = r#"stage: {"$addFields": {"a": 1, "b": false, "c": 2.4}}"#
         );
 
+        test_serde_stage!(
+            computed_field,
+            expected = Stage::AddFields(map! {
+                "sum".to_string() => Expression::UntaggedOperator(UntaggedOperator {
+                    op: UntaggedOperatorName::Add,
+                    args: vec![
+                        Expression::Ref(Ref::FieldRef("a".to_string())),
+                        Expression::Literal(LiteralValue::Int32(1)),
+                    ]
+                }),
+            }),
+            input = r#"stage: {"$addFields": {"sum": {"$add": ["$a", 1]}}}"#
+        );
+
         test_serde_stage!(
             set_alias,
             expected = Stage::AddFields(map! {
@@ -1242,6 +1264,12 @@ mod stage_test {
             ])),
             input = r#"stage: {"$unset": ["foo", "bar", "baz"]}"#
         );
+
+        test_serde_stage!(
+            multiple_with_dotted_path,
+            expected = Stage::Unset(Unset::Multiple(vec!["a".to_string(), "b.c".to_string()])),
+            input = r#"stage: {"$unset": ["a", "b.c"]}"#
+        );
     }
 
     mod set_window_fields {
@@ -1662,7 +1690,10 @@ mod stage_test {
 
     mod facet {
         use crate::{
-            definitions::{ProjectItem, ProjectStage, Stage},
+            definitions::{
+                Expression, LiteralValue, Lookup, LookupFrom, ProjectItem, ProjectStage, Stage,
+                SubqueryLookup,
+            },
             map,
         };
 
@@ -1700,6 +1731,47 @@ mod stage_test {
                 "outputField2": [{"$project": {"_id": 0}}, {"$count": "x"}],
             }}"#
         );
+
+        test_serde_stage!(
+            documents_and_project_in_each_facet,
+            expected = Stage::Facet(map! {
+                "f1".to_string() => vec![
+                    Stage::Documents(vec![
+                        map! {"a".to_string() => Expression::Literal(LiteralValue::Int32(1)) }
+                    ]),
+                    Stage::Project(ProjectStage {
+                        items: map! { "a".to_string() => ProjectItem::Inclusion },
+                    }),
+                ],
+                "f2".to_string() => vec![
+                    Stage::Documents(vec![
+                        map! {"b".to_string() => Expression::Literal(LiteralValue::Int32(2)) }
+                    ]),
+                    Stage::Project(ProjectStage {
+                        items: map! { "_id".to_string() => ProjectItem::Exclusion },
+                    }),
+                ],
+            }),
+            input = r#"stage: {"$facet": {
+                "f1": [{"$documents": [{"a": 1}]}, {"$project": {"a": 1}}],
+                "f2": [{"$documents": [{"b": 2}]}, {"$project": {"_id": 0}}],
+            }}"#
+        );
+
+        test_serde_stage!(
+            nested_lookup,
+            expected = Stage::Facet(map! {
+                "f".to_string() => vec![Stage::Lookup(Lookup::Subquery(SubqueryLookup {
+                    from: Some(LookupFrom::Collection("bar".to_string())),
+                    let_body: None,
+                    pipeline: vec![Stage::Limit(1)],
+                    as_var: "b".to_string()
+                }))],
+            }),
+            input = r#"stage: {"$facet": {
+                "f": [{"$lookup": {"from": "bar", "pipeline": [{"$limit": 1}], "as": "b"}}],
+            }}"#
+        );
     }
 
     mod fill {
@@ -2093,6 +2165,17 @@ mod expression_test {
             expected = Expression::Ref(Ref::VariableRef("v".to_string())),
             input = r#"expr: "$$v""#
         );
+
+        #[test]
+        fn refs_serialize_with_prefixes() {
+            use crate::serde_test::expression_test::TestExpr;
+
+            let e: TestExpr = serde_yaml::from_str(r#"expr: ["$a.b", "$$v"]"#).unwrap();
+            assert_eq!(
+                r#"{"expr":["$a.b","$$v"]}"#,
+                serde_json::to_string(&e).unwrap()
+            );
+        }
     }
 
     mod array {
@@ -2825,6 +2908,20 @@ mod expression_test {
             }}"#
         );
 
+        test_serde_expr!(
+            filter_input_and_cond_only,
+            expected = Expression::TaggedOperator(TaggedOperator::Filter(Filter {
+                input: Box::new(Expression::Ref(Ref::FieldRef("a".to_string()))),
+                _as: None,
+                cond: Box::new(Expression::Ref(Ref::VariableRef("this".to_string()))),
+                limit: None,
+            })),
+            input = r#"expr: {"$filter": {
+                                "input": "$a",
+                                "cond": "$$this"
+            }}"#
+        );
+
         test_serde_expr!(
             filter_with_as,
             expected = Expression::TaggedOperator(TaggedOperator::Filter(Filter {
@@ -2889,6 +2986,19 @@ mod expression_test {
             }}"#
         );
 
+        test_serde_expr!(
+            map_without_as_references_this,
+            expected = Expression::TaggedOperator(TaggedOperator::Map(Map {
+                input: Box::new(Expression::Ref(Ref::FieldRef("a".to_string()))),
+                _as: None,
+                inside: Box::new(Expression::Ref(Ref::VariableRef("this".to_string()))),
+            })),
+            input = r#"expr: {"$map": {
+                                "input": "$a",
+                                "in": "$$this"
+            }}"#
+        );
+
         test_serde_expr!(
             max_n_array_element,
             expected =
@@ -3558,7 +3668,7 @@ mod expression_test {
         }
 
         mod cond {
-            use crate::definitions::{Cond, Expression, LiteralValue, TaggedOperator};
+            use crate::definitions::{Cond, Expression, LiteralValue, Ref, TaggedOperator};
 
             test_serde_expr!(
                 tagged_input,
@@ -3583,6 +3693,24 @@ mod expression_test {
                 })),
                 input = r#"expr: {"$cond": [false, 0, "x"]}"#
             );
+
+            test_serde_expr!(
+                nested_with_field_refs,
+                expected = Expression::TaggedOperator(TaggedOperator::Cond(Cond {
+                    r#if: Box::new(Expression::Ref(Ref::FieldRef("a".to_string()))),
+                    then: Box::new(Expression::TaggedOperator(TaggedOperator::Cond(Cond {
+                        r#if: Box::new(Expression::Ref(Ref::FieldRef("b".to_string()))),
+                        then: Box::new(Expression::Literal(LiteralValue::Int32(1))),
+                        r#else: Box::new(Expression::Literal(LiteralValue::Int32(2))),
+                    }))),
+                    r#else: Box::new(Expression::Literal(LiteralValue::Int32(3))),
+                })),
+                input = r#"expr: {"$cond": {
+                                    "if": "$a",
+                                    "then": {"$cond": {"if": "$b", "then": 1, "else": 2}},
+                                    "else": 3,
+                }}"#
+            );
         }
     }
 
@@ -3610,6 +3738,22 @@ mod expression_test {
             input = r#"expr: {"$sqlSqrt": ["$x"]}"#
         );
 
+        #[test]
+        fn one_argument_forms_serialize_identically() {
+            use crate::serde_test::expression_test::TestExpr;
+
+            let non_array: TestExpr = serde_yaml::from_str(r#"expr: {"$sqlSqrt": "$x"}"#).unwrap();
+            let array: TestExpr = serde_yaml::from_str(r#"expr: {"$sqlSqrt": ["$x"]}"#).unwrap();
+            let output = serde_json::to_string(&non_array).unwrap();
+
+            assert_eq!(r#"{"expr":{"$sqlSqrt":["$x"]}}"#, output);
+            assert_eq!(output, serde_json::to_string(&array).unwrap());
+
+            // serializing is a fixed point after the first round trip
+            let reparsed: TestExpr = serde_yaml::from_str(&output).unwrap();
+            assert_eq!(output, serde_json::to_string(&reparsed).unwrap());
+        }
+
         test_serde_expr!(
             multiple_arguments,
             expected = Expression::UntaggedOperator(UntaggedOperator {
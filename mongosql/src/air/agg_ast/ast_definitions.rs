@@ -121,6 +121,7 @@ impl From<(Option<air::Stage>, Stage)> for air::Stage {
                                 function: accumulator_expr.function.into(),
                                 distinct,
                                 arg: Box::new(air::Expression::from(*var)),
+                                separator: None,
                             }
                         }
                         // accumulators of form: $<acc>: <expr>
@@ -129,6 +130,7 @@ impl From<(Option<air::Stage>, Stage)> for air::Stage {
                             function: accumulator_expr.function.into(),
                             distinct: false,
                             arg: Box::new(expr.into()),
+                            separator: None,
                         },
                     })
                     .collect();
@@ -136,6 +138,7 @@ impl From<(Option<air::Stage>, Stage)> for air::Stage {
                     source: Box::new(source.expect("$group without valid source stage")),
                     keys,
                     aggregations,
+                    scalar_id: false,
                 })
             }
             Stage::Join(j) => {
@@ -693,6 +696,10 @@ impl From<UntaggedOperator> for air::Expression {
             UntaggedOperatorName::SQLToUpper => sql_op!(air::SQLOperator::ToUpper),
             UntaggedOperatorName::SQLToLower => sql_op!(air::SQLOperator::ToLower),
             UntaggedOperatorName::SQLSplit => sql_op!(air::SQLOperator::Split),
+            UntaggedOperatorName::SQLLeft => sql_op!(air::SQLOperator::Left),
+            UntaggedOperatorName::SQLRight => sql_op!(air::SQLOperator::Right),
+            UntaggedOperatorName::SQLLPad => sql_op!(air::SQLOperator::LPad),
+            UntaggedOperatorName::SQLRPad => sql_op!(air::SQLOperator::RPad),
             UntaggedOperatorName::Concat => mql_op!(air::MQLOperator::Concat),
             UntaggedOperatorName::Cond => mql_op!(air::MQLOperator::Cond),
             UntaggedOperatorName::IfNull => mql_op!(air::MQLOperator::IfNull),
@@ -712,6 +719,7 @@ impl From<UntaggedOperator> for air::Expression {
             UntaggedOperatorName::Or => mql_op!(air::MQLOperator::Or),
             UntaggedOperatorName::Slice => mql_op!(air::MQLOperator::Slice),
             UntaggedOperatorName::Size => mql_op!(air::MQLOperator::Size),
+            UntaggedOperatorName::Range => mql_op!(air::MQLOperator::Range),
             UntaggedOperatorName::ArrayElemAt => mql_op!(air::MQLOperator::ElemAt),
             UntaggedOperatorName::In => mql_op!(air::MQLOperator::In),
             UntaggedOperatorName::First => mql_op!(air::MQLOperator::First),
@@ -739,7 +739,8 @@ mod stage {
             expected = air::Stage::Group(air::Group {
                 source: Box::new(default_source()),
                 keys: vec![],
-                aggregations: vec![]
+                aggregations: vec![],
+                scalar_id: false,
             }),
             input = agg_ast::Stage::Group(agg_ast::Group {
                 keys: agg_ast::Expression::Literal(agg_ast::LiteralValue::Null),
@@ -756,8 +757,10 @@ mod stage {
                     alias: "acc".to_string(),
                     function: air::AggregationFunction::Sum,
                     distinct: true,
-                    arg: Box::new(air::Expression::FieldRef("a".to_string().into()))
-                }]
+                    arg: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                    separator: None,
+                }],
+                scalar_id: false,
             }),
             input = agg_ast::Stage::Group(agg_ast::Group {
                 keys: agg_ast::Expression::Literal(agg_ast::LiteralValue::Null),
@@ -786,15 +789,18 @@ mod stage {
                         alias: "acc_one".to_string(),
                         function: air::AggregationFunction::Sum,
                         distinct: true,
-                        arg: Box::new(air::Expression::FieldRef("a".to_string().into()))
+                        arg: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                        separator: None,
                     },
                     air::AccumulatorExpr {
                         alias: "acc_two".to_string(),
                         function: air::AggregationFunction::Avg,
                         distinct: true,
-                        arg: Box::new(air::Expression::FieldRef("b".to_string().into()))
+                        arg: Box::new(air::Expression::FieldRef("b".to_string().into())),
+                        separator: None,
                     },
-                ]
+                ],
+                scalar_id: false,
             }),
             input = agg_ast::Stage::Group(agg_ast::Group {
                 keys: agg_ast::Expression::Document(map! {
@@ -828,8 +834,10 @@ mod stage {
                     alias: "acc".to_string(),
                     function: air::AggregationFunction::AddToSet,
                     distinct: false,
-                    arg: Box::new(air::Expression::FieldRef("a".to_string().into()))
-                }]
+                    arg: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                    separator: None,
+                }],
+                scalar_id: false,
             }),
             input = agg_ast::Stage::Group(agg_ast::Group {
                 keys: agg_ast::Expression::Literal(agg_ast::LiteralValue::Null),
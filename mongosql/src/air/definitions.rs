@@ -48,6 +48,9 @@ pub struct Group {
     pub source: Box<Stage>,
     pub keys: Vec<NameExprPair>,
     pub aggregations: Vec<AccumulatorExpr>,
+    /// Whether the single key is the _id itself rather than a field of an
+    /// _id document.
+    pub scalar_id: bool,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -62,6 +65,8 @@ pub struct AccumulatorExpr {
     pub function: AggregationFunction,
     pub distinct: bool,
     pub arg: Box<Expression>,
+    /// The separator of a GroupConcat, which defaults to "," when None.
+    pub separator: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -72,6 +77,7 @@ pub enum AggregationFunction {
     Avg,
     Count,
     First,
+    GroupConcat,
     Last,
     Max,
     MergeDocuments,
@@ -79,6 +85,8 @@ pub enum AggregationFunction {
     StddevPop,
     StddevSamp,
     Sum,
+    VarPop,
+    VarSamp,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -249,6 +257,11 @@ pub enum MQLOperator {
     Multiply,
     Divide,
 
+    // Bitwise operators
+    BitAnd,
+    BitOr,
+    BitXor,
+
     // Comparison operators
     Lt,
     Lte,
@@ -266,6 +279,9 @@ pub enum MQLOperator {
     // Array scalar functions
     Slice,
     Size,
+    Range,
+    SetUnion,
+    SortArray,
     ElemAt,
     In,
     First,
@@ -281,6 +297,7 @@ pub enum MQLOperator {
     Cos,
     DegreesToRadians,
     Floor,
+    Trunc,
     Log,
     Mod,
     Pow,
@@ -320,6 +337,9 @@ pub enum MQLOperator {
     DateAdd,
     DateDiff,
     DateTrunc,
+    DateToString,
+    DateFromString,
+    ToDate,
 
     // MergeObjects merges an array of objects
     MergeObjects,
@@ -377,6 +397,10 @@ pub enum SQLOperator {
     ToUpper,
     ToLower,
     Split,
+    Left,
+    Right,
+    LPad,
+    RPad,
 
     // Extended Operators
     ComputedFieldAccess,
@@ -663,6 +687,7 @@ pub enum DatePart {
 pub enum DateFunction {
     Add,
     Diff,
+    Sub,
     Trunc,
 }
 
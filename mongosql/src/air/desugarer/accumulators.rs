@@ -4,7 +4,7 @@ use crate::{
         visitor::Visitor,
         AccumulatorExpr, AggregationFunction, Expression,
         Expression::*,
-        Group, LiteralValue, MQLOperator, MQLSemanticOperator, Project, ProjectItem, Stage,
+        Group, LiteralValue, MQLOperator, MQLSemanticOperator, Project, ProjectItem, Reduce, Stage,
         Stage::*,
     },
     make_cond_expr, map,
@@ -38,6 +38,9 @@ macro_rules! make_single_expr_count_conditional {
 /// expressions and/or stages. Specifically, aggregations with distinct: true
 /// are replaced in the Group stage with AddToSet and the Group is followed
 /// by a Project that performs the actual target aggregation operation.
+/// Variance aggregations are computed as standard deviations in the Group
+/// and squared in the following Project, and GroupConcat aggregations collect
+/// their values in the Group and concatenate them in the following Project.
 pub struct AccumulatorsDesugarerPass;
 
 impl Pass for AccumulatorsDesugarerPass {
@@ -115,6 +118,7 @@ impl AccumulatorsDesugarerVisitor {
             function,
             distinct: false,
             arg,
+            separator: None,
         }
     }
 
@@ -153,6 +157,7 @@ impl AccumulatorsDesugarerVisitor {
             function: agg_func,
             distinct: false,
             arg: make_single_expr_count_conditional!(arg.clone(), then, r#else),
+            separator: None,
         }
     }
 
@@ -165,6 +170,7 @@ impl AccumulatorsDesugarerVisitor {
             function: AggregationFunction::AddToSet,
             distinct: false,
             arg: acc_expr.arg.clone(),
+            separator: None,
         };
 
         let project_item = ProjectItem::Assignment(MQLSemanticOperator(MQLSemanticOperator {
@@ -174,6 +180,7 @@ impl AccumulatorsDesugarerVisitor {
                 AggregationFunction::Avg => MQLOperator::Avg,
                 AggregationFunction::Count => unreachable!(),
                 AggregationFunction::First => MQLOperator::First,
+                AggregationFunction::GroupConcat => unreachable!(),
                 AggregationFunction::Last => MQLOperator::Last,
                 AggregationFunction::Max => MQLOperator::Max,
                 AggregationFunction::MergeDocuments => MQLOperator::MergeObjects,
@@ -181,12 +188,95 @@ impl AccumulatorsDesugarerVisitor {
                 AggregationFunction::StddevPop => MQLOperator::StddevPop,
                 AggregationFunction::StddevSamp => MQLOperator::StddevSamp,
                 AggregationFunction::Sum => MQLOperator::Sum,
+                AggregationFunction::VarPop | AggregationFunction::VarSamp => unreachable!(),
             },
             args: vec![FieldRef(acc_expr.alias.clone().into())],
         }));
 
         (new_acc_expr, project_item)
     }
+
+    /// Rewrites variance accumulators, which have no MQL equivalent, into the
+    /// corresponding standard deviation accumulator and a project item that
+    /// squares the standard deviation. Distinct variances are rewritten like
+    /// any other distinct accumulator before squaring.
+    fn rewrite_variance(acc_expr: &AccumulatorExpr) -> (AccumulatorExpr, ProjectItem) {
+        let stddev_acc_expr = AccumulatorExpr {
+            function: match acc_expr.function {
+                AggregationFunction::VarPop => AggregationFunction::StddevPop,
+                AggregationFunction::VarSamp => AggregationFunction::StddevSamp,
+                _ => unreachable!(),
+            },
+            ..acc_expr.clone()
+        };
+        let (new_acc_expr, stddev) = if acc_expr.distinct {
+            match Self::rewrite_distinct_non_count(&stddev_acc_expr) {
+                (new_acc_expr, ProjectItem::Assignment(stddev)) => (new_acc_expr, stddev),
+                _ => unreachable!(),
+            }
+        } else {
+            (stddev_acc_expr, FieldRef(acc_expr.alias.clone().into()))
+        };
+
+        let project_item = ProjectItem::Assignment(MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Pow,
+            args: vec![stddev, Literal(LiteralValue::Integer(2))],
+        }));
+
+        (new_acc_expr, project_item)
+    }
+
+    /// Rewrites GroupConcat accumulators, which have no MQL equivalent, into
+    /// an AddToArray accumulator (AddToSet when distinct) of the values and a
+    /// project item that joins the collected values with the separator via
+    /// $reduce. Null values are skipped, and the result is null when there
+    /// are no non-null values to join.
+    fn rewrite_group_concat(acc_expr: &AccumulatorExpr) -> (AccumulatorExpr, ProjectItem) {
+        let separator = Literal(LiteralValue::String(
+            acc_expr
+                .separator
+                .clone()
+                .unwrap_or_else(|| ",".to_string()),
+        ));
+        let new_acc_expr = AccumulatorExpr {
+            alias: acc_expr.alias.clone(),
+            function: if acc_expr.distinct {
+                AggregationFunction::AddToSet
+            } else {
+                AggregationFunction::AddToArray
+            },
+            distinct: false,
+            arg: acc_expr.arg.clone(),
+            separator: None,
+        };
+
+        let this = Variable("this".to_string().into());
+        let joined = Variable("value".to_string().into());
+        let is_null = |e: &Expression| {
+            MQLSemanticOperator(MQLSemanticOperator {
+                op: MQLOperator::Lte,
+                args: vec![e.clone(), Literal(LiteralValue::Null)],
+            })
+        };
+        let project_item = ProjectItem::Assignment(Reduce(Reduce {
+            input: Box::new(FieldRef(acc_expr.alias.clone().into())),
+            init_value: Box::new(Literal(LiteralValue::Null)),
+            inside: Box::new(make_cond_expr!(
+                is_null(&this),
+                joined.clone(),
+                make_cond_expr!(
+                    is_null(&joined),
+                    this.clone(),
+                    MQLSemanticOperator(MQLSemanticOperator {
+                        op: MQLOperator::Concat,
+                        args: vec![joined, separator, this],
+                    })
+                )
+            )),
+        }));
+
+        (new_acc_expr, project_item)
+    }
 }
 
 impl Visitor for AccumulatorsDesugarerVisitor {
@@ -200,10 +290,20 @@ impl Visitor for AccumulatorsDesugarerVisitor {
                 };
                 let mut needs_project = false;
                 for acc_expr in group.aggregations.iter() {
-                    needs_project = needs_project || acc_expr.distinct;
+                    let is_variance = matches!(
+                        acc_expr.function,
+                        AggregationFunction::VarPop | AggregationFunction::VarSamp
+                    );
+                    let is_group_concat = acc_expr.function == AggregationFunction::GroupConcat;
+                    needs_project =
+                        needs_project || acc_expr.distinct || is_variance || is_group_concat;
                     let (new_acc_expr, project_item) =
                         if acc_expr.function == AggregationFunction::Count {
                             Self::rewrite_count(acc_expr)
+                        } else if is_variance {
+                            Self::rewrite_variance(acc_expr)
+                        } else if is_group_concat {
+                            Self::rewrite_group_concat(acc_expr)
                         } else if acc_expr.distinct {
                             Self::rewrite_distinct_non_count(acc_expr)
                         } else {
@@ -218,6 +318,7 @@ impl Visitor for AccumulatorsDesugarerVisitor {
                     source: group.source,
                     keys: group.keys,
                     aggregations: new_accumulators,
+                    scalar_id: group.scalar_id,
                 });
 
                 if needs_project {
@@ -250,7 +351,7 @@ impl Visitor for AccumulatorExpressionConverter {
                 alias: _,
                 function: AggregationFunction::AddToArray,
                 distinct: true,
-                arg: _
+                ..
             }
         ) {
             AccumulatorExpr {
@@ -258,6 +359,7 @@ impl Visitor for AccumulatorExpressionConverter {
                 function: AggregationFunction::AddToSet,
                 distinct: false,
                 arg: node.arg,
+                separator: node.separator,
             }
         } else {
             node
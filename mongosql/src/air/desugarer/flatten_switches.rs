@@ -0,0 +1,49 @@
+use crate::air::{
+    self,
+    desugarer::{Pass, Result},
+    visitor::Visitor,
+    Expression, Switch,
+};
+
+/// Flattens a $switch whose default is another $switch into a single
+/// $switch. The branches of the inner $switch are only reached when none of
+/// the outer branches match, so appending them to the outer branches and
+/// taking the inner default preserves the order in which cases are tried.
+#[derive(Default)]
+pub struct FlattenSwitchesDesugarerPass;
+
+impl Pass for FlattenSwitchesDesugarerPass {
+    fn apply(&self, pipeline: air::Stage) -> Result<air::Stage> {
+        let mut visitor = FlattenSwitchesDesugarerVisitor;
+        Ok(pipeline.walk(&mut visitor))
+    }
+}
+
+struct FlattenSwitchesDesugarerVisitor;
+
+impl Visitor for FlattenSwitchesDesugarerVisitor {
+    fn visit_expression(&mut self, node: Expression) -> Expression {
+        // Walking first flattens the default, so a single level of merging
+        // here also flattens chains of more than two $switches.
+        let node = node.walk(self);
+        match node {
+            Expression::Switch(Switch {
+                mut branches,
+                default,
+            }) => match *default {
+                Expression::Switch(inner) => {
+                    branches.extend(inner.branches);
+                    Expression::Switch(Switch {
+                        branches,
+                        default: inner.default,
+                    })
+                }
+                default => Expression::Switch(Switch {
+                    branches,
+                    default: Box::new(default),
+                }),
+            },
+            _ => node,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::air::{
+    self,
+    desugarer::{Pass, Result},
+    visitor::Visitor,
+    Expression, Let, LetVariable, MQLOperator, MQLSemanticOperator, Project, ProjectItem,
+    SQLOperator, SQLSemanticOperator, Stage, Variable,
+};
+use linked_hash_map::LinkedHashMap;
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
+    mem::discriminant,
+};
+
+/// Binds operator applications that are repeated within a single Project
+/// assignment to $let variables, so that each is evaluated once. Only
+/// applications that are always evaluated and that do not reference any
+/// variables are hoisted: the operands of $cond, $ifNull, $and, $or, and
+/// their SQL counterparts may not be evaluated at all, and a variable
+/// reference may be bound inside the expression itself.
+///
+/// This pass is only applied by desugar_pipeline when the
+/// hoist_common_subexpressions option is set.
+#[derive(Default)]
+pub struct HoistCommonSubexpressionsDesugarerPass;
+
+impl Pass for HoistCommonSubexpressionsDesugarerPass {
+    fn apply(&self, pipeline: air::Stage) -> Result<air::Stage> {
+        let mut visitor = HoistCommonSubexpressionsDesugarerVisitor;
+        Ok(visitor.visit_stage(pipeline))
+    }
+}
+
+struct HoistCommonSubexpressionsDesugarerVisitor;
+
+/// An operator application that may be hoisted, along with a structural
+/// fingerprint used to find candidates that may be equal without comparing
+/// every pair of candidates.
+struct Candidate<'a> {
+    expr: &'a Expression,
+    fingerprint: u64,
+    size: usize,
+    has_variables: bool,
+}
+
+#[derive(PartialEq, Eq)]
+enum NodeKind {
+    Candidate,
+    Conditional,
+    Container,
+}
+
+/// What collect_candidates learns about an expression from its children.
+struct Summary {
+    fingerprint: u64,
+    size: usize,
+    has_variables: bool,
+}
+
+impl HoistCommonSubexpressionsDesugarerVisitor {
+    /// Collects the always-evaluated, variable-free operator applications in
+    /// expr, in pre-order. Fingerprints, sizes, and variable usage are
+    /// computed bottom-up, so each node is only visited once per call.
+    fn collect_candidates<'a>(
+        expr: &'a Expression,
+        candidates: &mut Vec<Candidate<'a>>,
+    ) -> Summary {
+        let mut hasher = DefaultHasher::new();
+        let (children, kind): (Vec<(Option<&String>, &Expression)>, NodeKind) = match expr {
+            Expression::MQLSemanticOperator(MQLSemanticOperator { op, args }) => {
+                0u8.hash(&mut hasher);
+                discriminant(op).hash(&mut hasher);
+                let kind = match op {
+                    MQLOperator::Cond
+                    | MQLOperator::IfNull
+                    | MQLOperator::And
+                    | MQLOperator::Or => NodeKind::Conditional,
+                    _ => NodeKind::Candidate,
+                };
+                (args.iter().map(|arg| (None, arg)).collect(), kind)
+            }
+            Expression::SQLSemanticOperator(SQLSemanticOperator { op, args }) => {
+                1u8.hash(&mut hasher);
+                discriminant(op).hash(&mut hasher);
+                let kind = match op {
+                    SQLOperator::Coalesce | SQLOperator::And | SQLOperator::Or => {
+                        NodeKind::Conditional
+                    }
+                    _ => NodeKind::Candidate,
+                };
+                (args.iter().map(|arg| (None, arg)).collect(), kind)
+            }
+            Expression::Array(array) => {
+                2u8.hash(&mut hasher);
+                (
+                    array.iter().map(|e| (None, e)).collect(),
+                    NodeKind::Container,
+                )
+            }
+            Expression::Document(document) => {
+                3u8.hash(&mut hasher);
+                (
+                    document.iter().map(|(k, e)| (Some(k), e)).collect(),
+                    NodeKind::Container,
+                )
+            }
+            _ => {
+                format!("{expr:?}").hash(&mut hasher);
+                return Summary {
+                    fingerprint: hasher.finish(),
+                    size: ExpressionSize::of(expr),
+                    has_variables: !VariableNames::of(expr).is_empty(),
+                };
+            }
+        };
+
+        // Reserve this node's place so that candidates stay in pre-order.
+        let index = candidates.len();
+        if kind == NodeKind::Candidate {
+            candidates.push(Candidate {
+                expr,
+                fingerprint: 0,
+                size: 0,
+                has_variables: false,
+            });
+        }
+        // The operands of a conditional operator are summarized, since they
+        // are part of any enclosing candidate, but are not candidates.
+        let mut skipped = Vec::new();
+        let mut summary = Summary {
+            fingerprint: 0,
+            size: 1,
+            has_variables: false,
+        };
+        for (key, child) in children {
+            let child_summary = if kind == NodeKind::Conditional {
+                Self::collect_candidates(child, &mut skipped)
+            } else {
+                Self::collect_candidates(child, candidates)
+            };
+            key.hash(&mut hasher);
+            child_summary.fingerprint.hash(&mut hasher);
+            summary.size += child_summary.size;
+            summary.has_variables |= child_summary.has_variables;
+        }
+        summary.fingerprint = hasher.finish();
+        if kind == NodeKind::Candidate {
+            candidates[index].fingerprint = summary.fingerprint;
+            candidates[index].size = summary.size;
+            candidates[index].has_variables = summary.has_variables;
+        }
+        summary
+    }
+
+    /// Returns the largest candidate that appears more than once, preferring
+    /// the earliest one among candidates of the same size. Candidates are
+    /// only compared with candidates that share their fingerprint.
+    fn find_repeated(candidates: &[Candidate]) -> Option<Expression> {
+        let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+        candidates
+            .iter()
+            .enumerate()
+            .for_each(|(i, c)| buckets.entry(c.fingerprint).or_default().push(i));
+        candidates
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| {
+                buckets[&c.fingerprint]
+                    .iter()
+                    .any(|j| j != i && candidates[*j].expr == c.expr)
+            })
+            .fold(None, |largest: Option<&Candidate>, (_, c)| match largest {
+                Some(largest) if largest.size >= c.size => Some(largest),
+                _ => Some(c),
+            })
+            .map(|c| c.expr.clone())
+    }
+
+    fn hoist(expr: Expression) -> Expression {
+        let mut names = VariableNames::of(&expr);
+        let mut vars = Vec::new();
+        let mut inside = expr;
+        loop {
+            let mut candidates = Vec::new();
+            Self::collect_candidates(&inside, &mut candidates);
+            candidates.retain(|c| !c.has_variables);
+            let repeated = match Self::find_repeated(&candidates) {
+                Some(repeated) => repeated,
+                None => break,
+            };
+            let name = (0..)
+                .map(|i| format!("cse{i}"))
+                .find(|name| !names.contains(name))
+                .unwrap();
+            names.insert(name.clone());
+            inside = SubexpressionReplacer {
+                target: &repeated,
+                name: &name,
+            }
+            .visit_expression(inside);
+            vars.push(LetVariable {
+                name,
+                expr: Box::new(repeated),
+            });
+        }
+        if vars.is_empty() {
+            inside
+        } else {
+            Expression::Let(Let {
+                vars,
+                inside: Box::new(inside),
+            })
+        }
+    }
+}
+
+impl Visitor for HoistCommonSubexpressionsDesugarerVisitor {
+    fn visit_stage(&mut self, node: Stage) -> Stage {
+        let node = node.walk(self);
+        match node {
+            Stage::Project(Project {
+                source,
+                specifications,
+            }) => Stage::Project(Project {
+                source,
+                specifications: specifications
+                    .into_iter()
+                    .map(|(key, item)| match item {
+                        ProjectItem::Assignment(e) => {
+                            (key, ProjectItem::Assignment(Self::hoist(e)))
+                        }
+                        item => (key, item),
+                    })
+                    .collect::<LinkedHashMap<_, _>>()
+                    .into(),
+            }),
+            _ => node,
+        }
+    }
+}
+
+/// Replaces every occurrence of target with a reference to the named
+/// variable. Subquery pipelines are left alone, since field references in
+/// them refer to the documents of the subquery.
+struct SubexpressionReplacer<'a> {
+    target: &'a Expression,
+    name: &'a str,
+}
+
+impl Visitor for SubexpressionReplacer<'_> {
+    fn visit_expression(&mut self, node: Expression) -> Expression {
+        match node {
+            Expression::Subquery(_)
+            | Expression::SubqueryComparison(_)
+            | Expression::SubqueryExists(_) => node,
+            node if node == *self.target => Expression::Variable(self.name.to_string().into()),
+            node => node.walk(self),
+        }
+    }
+}
+
+/// Collects the names of all variables referenced or bound in an expression.
+#[derive(Default)]
+struct VariableNames(BTreeSet<String>);
+
+impl VariableNames {
+    fn of(expr: &Expression) -> BTreeSet<String> {
+        let mut visitor = VariableNames::default();
+        visitor.visit_expression(expr.clone());
+        visitor.0
+    }
+}
+
+impl Visitor for VariableNames {
+    fn visit_variable(&mut self, node: Variable) -> Variable {
+        self.0.insert(node.name.clone());
+        node.walk(self)
+    }
+
+    fn visit_let_variable(&mut self, node: LetVariable) -> LetVariable {
+        self.0.insert(node.name.clone());
+        node.walk(self)
+    }
+}
+
+/// Counts the expression nodes in an expression.
+#[derive(Default)]
+struct ExpressionSize(usize);
+
+impl ExpressionSize {
+    fn of(expr: &Expression) -> usize {
+        let mut visitor = ExpressionSize::default();
+        visitor.visit_expression(expr.clone());
+        visitor.0
+    }
+}
+
+impl Visitor for ExpressionSize {
+    fn visit_expression(&mut self, node: Expression) -> Expression {
+        self.0 += 1;
+        node.walk(self)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::air::{
+    self,
+    desugarer::{Pass, Result},
+    visitor::Visitor,
+    ExprLanguage, Expression, MQLOperator, MQLSemanticOperator, Match, MatchLanguage, MatchQuery,
+    Stage,
+};
+
+/// Merges consecutive Match stages into a single Match stage whose predicate
+/// is the conjunction of both predicates. Only Matches written in the same
+/// language are merged; an $expr Match and a match language Match stay
+/// separate.
+#[derive(Default)]
+pub struct MergeMatchesDesugarerPass;
+
+impl Pass for MergeMatchesDesugarerPass {
+    fn apply(&self, pipeline: air::Stage) -> Result<air::Stage> {
+        let mut visitor = MergeMatchesDesugarerVisitor;
+        Ok(visitor.visit_stage(pipeline))
+    }
+}
+
+struct MergeMatchesDesugarerVisitor;
+
+impl MergeMatchesDesugarerVisitor {
+    /// Combines two $expr predicates with $and. The source predicate comes
+    /// first so that $and short-circuits in the same order the separate
+    /// stages would have been evaluated.
+    fn and_exprs(source_expr: Expression, expr: Expression) -> Expression {
+        let args = [source_expr, expr]
+            .into_iter()
+            .flat_map(|e| match e {
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::And,
+                    args,
+                }) => args,
+                e => vec![e],
+            })
+            .collect();
+        Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::And,
+            args,
+        })
+    }
+
+    /// Combines two match language predicates with $and.
+    fn and_match_queries(source_query: MatchQuery, query: MatchQuery) -> MatchQuery {
+        MatchQuery::And(
+            [source_query, query]
+                .into_iter()
+                .flat_map(|q| match q {
+                    MatchQuery::And(queries) => queries,
+                    q => vec![q],
+                })
+                .collect(),
+        )
+    }
+
+    fn merge(m: Match) -> Match {
+        match m {
+            Match::ExprLanguage(ExprLanguage { source, expr }) => match *source {
+                Stage::Match(Match::ExprLanguage(source_match)) => {
+                    Match::ExprLanguage(ExprLanguage {
+                        source: source_match.source,
+                        expr: Box::new(Self::and_exprs(*source_match.expr, *expr)),
+                    })
+                }
+                source => Match::ExprLanguage(ExprLanguage {
+                    source: Box::new(source),
+                    expr,
+                }),
+            },
+            Match::MatchLanguage(MatchLanguage { source, expr }) => match *source {
+                Stage::Match(Match::MatchLanguage(source_match)) => {
+                    Match::MatchLanguage(MatchLanguage {
+                        source: source_match.source,
+                        expr: Box::new(Self::and_match_queries(*source_match.expr, *expr)),
+                    })
+                }
+                source => Match::MatchLanguage(MatchLanguage {
+                    source: Box::new(source),
+                    expr,
+                }),
+            },
+        }
+    }
+}
+
+impl Visitor for MergeMatchesDesugarerVisitor {
+    fn visit_stage(&mut self, node: Stage) -> Stage {
+        let node = node.walk(self);
+        match node {
+            Stage::Match(m) => Stage::Match(Self::merge(m)),
+            _ => node,
+        }
+    }
+}
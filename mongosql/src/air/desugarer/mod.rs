@@ -1,4 +1,4 @@
-use crate::air;
+use crate::{air, options::SqlOptions};
 use thiserror::Error;
 
 mod accumulators;
@@ -15,8 +15,18 @@ mod subquery;
 use crate::air::desugarer::subquery::SubqueryExprDesugarerPass;
 mod unsupported_operators;
 use crate::air::desugarer::unsupported_operators::UnsupportedOperatorsDesugarerPass;
+mod merge_matches;
+use crate::air::desugarer::merge_matches::MergeMatchesDesugarerPass;
+mod push_down_limits;
+use crate::air::desugarer::push_down_limits::PushDownLimitsDesugarerPass;
+mod redundant_projects;
+use crate::air::desugarer::redundant_projects::RedundantProjectsDesugarerPass;
 mod remove_id;
 use crate::air::desugarer::remove_id::RemoveIdDesugarerPass;
+mod flatten_switches;
+use crate::air::desugarer::flatten_switches::FlattenSwitchesDesugarerPass;
+mod hoist_common_subexpressions;
+use crate::air::desugarer::hoist_common_subexpressions::HoistCommonSubexpressionsDesugarerPass;
 
 #[cfg(test)]
 mod test;
@@ -31,6 +41,8 @@ pub enum Error {
     InvalidLikePattern,
     #[error("could not statically evaluate constant $convert to Type {0:?}, due to improper constant input value")]
     InvalidConstantConvert(air::Type),
+    #[error("desugared pipeline is not ready for codegen: {0}")]
+    IncompleteDesugaring(String),
 }
 
 /// A fallible transformation that can be applied to a pipeline
@@ -39,11 +51,18 @@ pub trait Pass {
 }
 
 /// Desugar the provided pipeline by applying desugarer passes.
-pub fn desugar_pipeline(pipeline: air::Stage) -> Result<air::Stage> {
+pub fn desugar_pipeline(pipeline: air::Stage, sql_options: SqlOptions) -> Result<air::Stage> {
     // The order of these passes matters. Specifically, SQL null semantic
     // operators must be desugared after any passes that create SQL null
-    // semantic operators.
-    let passes: Vec<&dyn Pass> = vec![
+    // semantic operators. $switches are flattened after the unsupported
+    // operators that desugar to $switch have been rewritten. Limits are
+    // pushed down before redundant Projects are removed, since pushing a
+    // Limit may leave two Projects adjacent.
+    // Redundant Projects must be removed before _id is
+    // excluded from the last Project, since that exclusion makes the last
+    // Project differ from its source. Common subexpressions are hoisted
+    // last, once every operator that will be emitted is in place.
+    let mut passes: Vec<&dyn Pass> = vec![
         &RootReferenceDesugarerPass,
         &JoinDesugarerPass,
         &AccumulatorsDesugarerPass,
@@ -51,8 +70,15 @@ pub fn desugar_pipeline(pipeline: air::Stage) -> Result<air::Stage> {
         &UnsupportedOperatorsDesugarerPass,
         &SQLNullSemanticsOperatorsDesugarerPass,
         &FoldConvertsDesugarerPass,
+        &FlattenSwitchesDesugarerPass,
+        &MergeMatchesDesugarerPass,
+        &PushDownLimitsDesugarerPass,
+        &RedundantProjectsDesugarerPass,
         &RemoveIdDesugarerPass,
     ];
+    if sql_options.hoist_common_subexpressions {
+        passes.push(&HoistCommonSubexpressionsDesugarerPass);
+    }
 
     let mut desugared = pipeline;
     for pass in passes {
@@ -0,0 +1,59 @@
+use crate::air::{
+    self,
+    desugarer::{Pass, Result},
+    visitor::Visitor,
+    Expression, Limit, Project, ProjectItem, Stage,
+};
+
+/// Moves Limit stages before Project stages that only reshape documents, so
+/// that documents are discarded before they are projected. A Project only
+/// reshapes documents when its items are inclusions, exclusions, or
+/// assignments of field references, variables, or literals; such a Project
+/// emits one document per input document and evaluates nothing that could
+/// fail. Limits are never pushed below any other kind of stage.
+#[derive(Default)]
+pub struct PushDownLimitsDesugarerPass;
+
+impl Pass for PushDownLimitsDesugarerPass {
+    fn apply(&self, pipeline: air::Stage) -> Result<air::Stage> {
+        let mut visitor = PushDownLimitsDesugarerVisitor;
+        Ok(visitor.visit_stage(pipeline))
+    }
+}
+
+struct PushDownLimitsDesugarerVisitor;
+
+impl PushDownLimitsDesugarerVisitor {
+    fn is_reshaping(project: &Project) -> bool {
+        project.specifications.iter().all(|(_, item)| match item {
+            ProjectItem::Exclusion | ProjectItem::Inclusion => true,
+            ProjectItem::Assignment(e) => matches!(
+                e,
+                Expression::FieldRef(_) | Expression::Variable(_) | Expression::Literal(_)
+            ),
+        })
+    }
+
+    fn push_down(source: Stage, limit: i64) -> Stage {
+        match source {
+            Stage::Project(project) if Self::is_reshaping(&project) => Stage::Project(Project {
+                source: Box::new(Self::push_down(*project.source, limit)),
+                specifications: project.specifications,
+            }),
+            source => Stage::Limit(Limit {
+                source: Box::new(source),
+                limit,
+            }),
+        }
+    }
+}
+
+impl Visitor for PushDownLimitsDesugarerVisitor {
+    fn visit_stage(&mut self, node: Stage) -> Stage {
+        let node = node.walk(self);
+        match node {
+            Stage::Limit(Limit { source, limit }) => Self::push_down(*source, limit),
+            _ => node,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::air::{
+    self,
+    desugarer::{Pass, Result},
+    visitor::Visitor,
+    Expression, FieldRef, Project, ProjectItem, Stage,
+};
+
+/// Removes Project stages that re-select exactly the fields produced by the
+/// Project stage immediately before them.
+#[derive(Default)]
+pub struct RedundantProjectsDesugarerPass;
+
+impl Pass for RedundantProjectsDesugarerPass {
+    fn apply(&self, pipeline: air::Stage) -> Result<air::Stage> {
+        let mut visitor = RedundantProjectsDesugarerVisitor;
+        Ok(visitor.visit_stage(pipeline))
+    }
+}
+
+struct RedundantProjectsDesugarerVisitor;
+
+impl RedundantProjectsDesugarerVisitor {
+    /// A Project is an identity over its source Project when it specifies the
+    /// same keys in the same order, excludes exactly the keys the source
+    /// excludes, and otherwise only includes or assigns each field to itself.
+    fn is_identity_over(project: &Project, source: &Project) -> bool {
+        project
+            .specifications
+            .keys()
+            .eq(source.specifications.keys())
+            && project
+                .specifications
+                .iter()
+                .zip(source.specifications.iter())
+                .all(
+                    |((key, item), (_, source_item))| match (item, source_item) {
+                        (ProjectItem::Exclusion, ProjectItem::Exclusion) => true,
+                        (ProjectItem::Exclusion, _) | (_, ProjectItem::Exclusion) => false,
+                        (ProjectItem::Inclusion, _) => true,
+                        (
+                            ProjectItem::Assignment(Expression::FieldRef(FieldRef {
+                                parent: None,
+                                name,
+                            })),
+                            _,
+                        ) => name == key,
+                        (ProjectItem::Assignment(_), _) => false,
+                    },
+                )
+    }
+}
+
+impl Visitor for RedundantProjectsDesugarerVisitor {
+    fn visit_stage(&mut self, node: Stage) -> Stage {
+        let node = node.walk(self);
+        match node {
+            Stage::Project(project) => {
+                let is_redundant = matches!(
+                    project.source.as_ref(),
+                    Stage::Project(source) if Self::is_identity_over(&project, source)
+                );
+                if is_redundant {
+                    *project.source
+                } else {
+                    Stage::Project(project)
+                }
+            }
+            _ => node,
+        }
+    }
+}
@@ -9,7 +9,7 @@ use chrono::prelude::*;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use serde::Deserialize;
-use std::{fs, io::Read, str::FromStr};
+use std::{collections::BTreeSet, fs, io::Read, str::FromStr};
 use thiserror::Error;
 
 macro_rules! test_desugarer {
@@ -88,6 +88,269 @@ mod accumulators {
         file = "desugar_accumulators.yml",
         desugarer = AccumulatorsDesugarerPass
     );
+
+    /// Returns the output fields of a desugared Group, with `_id` replaced by
+    /// the group keys that it holds. The desugared stage is either the Group
+    /// itself or a Project over it.
+    fn group_output_fields(stage: &Stage) -> BTreeSet<String> {
+        let group = match stage {
+            Stage::Group(group) => group,
+            Stage::Project(air::Project { source, .. }) => match source.as_ref() {
+                Stage::Group(group) => group,
+                s => panic!("expected the desugared Project to be over a Group, found {s:?}"),
+            },
+            s => panic!("expected a Group or a Project over a Group, found {s:?}"),
+        };
+        let accumulator_fields = match stage {
+            Stage::Project(project) => project
+                .specifications
+                .iter()
+                .filter(|(_, item)| !matches!(item, air::ProjectItem::Exclusion))
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>(),
+            _ => group
+                .aggregations
+                .iter()
+                .map(|acc| acc.alias.clone())
+                .collect(),
+        };
+        group
+            .keys
+            .iter()
+            .map(|key| key.name.clone())
+            .chain(
+                accumulator_fields
+                    .into_iter()
+                    .filter(|field| field != "_id"),
+            )
+            .collect()
+    }
+
+    // The translator maps the group keys and aggregation aliases computed by
+    // schema inference for the mir Group onto `_id` and the accumulator
+    // fields, so desugaring must produce exactly the inferred result columns.
+    #[test]
+    fn two_accumulator_group_output_fields_match_inferred_schema() {
+        use crate::{
+            catalog::Catalog,
+            mir::{self, schema::CachedSchema},
+            options::SqlOptions,
+            schema::{Schema, SchemaEnvironment},
+            translator::MqlTranslator,
+            unchecked_unique_linked_hash_map, SchemaCheckingMode,
+        };
+        use mongosql_datastructures::binding_tuple::Key;
+
+        let foo_a = || {
+            mir::Expression::FieldAccess(mir::FieldAccess::new(
+                Box::new(mir::Expression::Reference(Key::named("foo", 0u16).into())),
+                "a".into(),
+            ))
+        };
+        let mir_group = mir::Stage::Group(mir::Group {
+            source: Box::new(mir::Stage::Array(mir::ArraySource {
+                array: vec![mir::Expression::Document(
+                    unchecked_unique_linked_hash_map! {
+                        "a".to_string() => mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    }
+                    .into(),
+                )],
+                alias: "foo".into(),
+                cache: mir::schema::SchemaCache::new(),
+            })),
+            keys: vec![mir::OptionallyAliasedExpr::Aliased(mir::AliasedExpr {
+                alias: "k".into(),
+                expr: foo_a(),
+            })],
+            aggregations: vec![
+                mir::AliasedAggregation {
+                    alias: "total".into(),
+                    agg_expr: mir::AggregationExpr::Function(mir::AggregationFunctionApplication {
+                        function: mir::AggregationFunction::Sum,
+                        distinct: true,
+                        arg: Box::new(foo_a()),
+                        separator: None,
+                    }),
+                },
+                mir::AliasedAggregation {
+                    alias: "n".into(),
+                    agg_expr: mir::AggregationExpr::CountStar(false),
+                },
+            ],
+            cache: mir::schema::SchemaCache::new(),
+            scope: 0,
+        });
+
+        let catalog = Catalog::default();
+        let state = mir::schema::SchemaInferenceState::new(
+            0u16,
+            SchemaEnvironment::default(),
+            &catalog,
+            SchemaCheckingMode::Strict,
+        );
+        let result_set = mir_group.schema(&state).unwrap();
+        let expected = match result_set.schema_env.get(&Key::bot(0u16)) {
+            Some(Schema::Document(d)) => d.keys.keys().cloned().collect::<BTreeSet<_>>(),
+            s => panic!("expected a document schema for the group result, found {s:?}"),
+        };
+
+        let group = match MqlTranslator::new(SqlOptions::default())
+            .translate_stage(mir_group)
+            .unwrap()
+        {
+            Stage::Project(air::Project { source, .. }) => *source,
+            s => panic!("expected the translated Group to be under a Project, found {s:?}"),
+        };
+        let actual = AccumulatorsDesugarerPass.apply(group).unwrap();
+
+        assert!(matches!(actual, Stage::Project(_)));
+        assert_eq!(expected, group_output_fields(&actual));
+    }
+
+    fn group(function: air::AggregationFunction, distinct: bool) -> Box<Stage> {
+        Box::new(air::Stage::Group(air::Group {
+            source: Box::new(air::Stage::Collection(air::Collection {
+                db: "test".into(),
+                collection: "foo".into(),
+            })),
+            keys: vec![],
+            aggregations: vec![air::AccumulatorExpr {
+                alias: "v".into(),
+                function,
+                distinct,
+                arg: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                separator: None,
+            }],
+            scalar_id: false,
+        }))
+    }
+
+    fn squared(expr: air::Expression) -> air::ProjectItem {
+        air::ProjectItem::Assignment(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Pow,
+                args: vec![
+                    expr,
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                ],
+            },
+        ))
+    }
+
+    test_desugar_manual!(
+        name = var_pop_is_squared_stddev_pop,
+        desugarer = AccumulatorsDesugarerPass,
+        input = *group(air::AggregationFunction::VarPop, false),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Project(air::Project {
+            source: group(air::AggregationFunction::StddevPop, false),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "_id".into() => air::ProjectItem::Inclusion,
+                "v".into() => squared(air::Expression::FieldRef("v".to_string().into())),
+            },
+        }))
+    );
+
+    test_desugar_manual!(
+        name = distinct_var_samp_is_squared_stddev_samp_of_set,
+        desugarer = AccumulatorsDesugarerPass,
+        input = *group(air::AggregationFunction::VarSamp, true),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Project(air::Project {
+            source: group(air::AggregationFunction::AddToSet, false),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "_id".into() => air::ProjectItem::Inclusion,
+                "v".into() => squared(air::Expression::MQLSemanticOperator(
+                    air::MQLSemanticOperator {
+                        op: air::MQLOperator::StddevSamp,
+                        args: vec![air::Expression::FieldRef("v".to_string().into())],
+                    }
+                )),
+            },
+        }))
+    );
+
+    fn group_concat(distinct: bool, separator: Option<&str>) -> Stage {
+        let mut group = group(air::AggregationFunction::GroupConcat, distinct);
+        if let Stage::Group(air::Group { aggregations, .. }) = group.as_mut() {
+            aggregations[0].separator = separator.map(String::from);
+        }
+        *group
+    }
+
+    fn joined_with(separator: &str) -> air::ProjectItem {
+        let is_null = |e: air::Expression| {
+            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                op: air::MQLOperator::Lte,
+                args: vec![e, air::Expression::Literal(air::LiteralValue::Null)],
+            })
+        };
+        let cond = |args| {
+            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                op: air::MQLOperator::Cond,
+                args,
+            })
+        };
+        let this = air::Expression::Variable("this".to_string().into());
+        let value = air::Expression::Variable("value".to_string().into());
+        air::ProjectItem::Assignment(air::Expression::Reduce(air::Reduce {
+            input: Box::new(air::Expression::FieldRef("v".to_string().into())),
+            init_value: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+            inside: Box::new(cond(vec![
+                is_null(this.clone()),
+                value.clone(),
+                cond(vec![
+                    is_null(value.clone()),
+                    this.clone(),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Concat,
+                        args: vec![
+                            value,
+                            air::Expression::Literal(air::LiteralValue::String(separator.into())),
+                            this,
+                        ],
+                    }),
+                ]),
+            ])),
+        }))
+    }
+
+    test_desugar_manual!(
+        name = group_concat_pushes_values_and_joins_them,
+        desugarer = AccumulatorsDesugarerPass,
+        input = group_concat(false, Some(";")),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Project(air::Project {
+            source: group(air::AggregationFunction::AddToArray, false),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "_id".into() => air::ProjectItem::Inclusion,
+                "v".into() => joined_with(";"),
+            },
+        }))
+    );
+
+    test_desugar_manual!(
+        name = distinct_group_concat_joins_set_of_values,
+        desugarer = AccumulatorsDesugarerPass,
+        input = group_concat(true, Some(";")),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Project(air::Project {
+            source: group(air::AggregationFunction::AddToSet, false),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "_id".into() => air::ProjectItem::Inclusion,
+                "v".into() => joined_with(";"),
+            },
+        }))
+    );
+
+    test_desugar_manual!(
+        name = group_concat_separator_defaults_to_comma,
+        desugarer = AccumulatorsDesugarerPass,
+        input = group_concat(false, None),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Project(air::Project {
+            source: group(air::AggregationFunction::AddToArray, false),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "_id".into() => air::ProjectItem::Inclusion,
+                "v".into() => joined_with(","),
+            },
+        }))
+    );
 }
 
 mod joins {
@@ -117,6 +380,286 @@ mod sql_null_semantics {
     );
 }
 
+mod merge_matches {
+    use super::*;
+    use crate::air::desugarer::merge_matches::MergeMatchesDesugarerPass;
+
+    fn collection() -> Box<Stage> {
+        Box::new(air::Stage::Collection(air::Collection {
+            db: "test".into(),
+            collection: "default".into(),
+        }))
+    }
+
+    fn comparison(field: &str, function: air::MatchLanguageComparisonOp) -> air::MatchQuery {
+        air::MatchQuery::Comparison(air::MatchLanguageComparison {
+            function,
+            input: Some(field.into()),
+            arg: air::LiteralValue::Integer(1),
+        })
+    }
+
+    test_desugarer!(
+        file = "desugar_merge_matches.yml",
+        desugarer = MergeMatchesDesugarerPass
+    );
+
+    test_desugar_manual!(
+        name = adjacent_match_language_matches_are_merged,
+        desugarer = MergeMatchesDesugarerPass,
+        input = air::Stage::Match(air::Match::MatchLanguage(air::MatchLanguage {
+            source: Box::new(air::Stage::Match(air::Match::MatchLanguage(
+                air::MatchLanguage {
+                    source: collection(),
+                    expr: Box::new(comparison("a", air::MatchLanguageComparisonOp::Eq)),
+                }
+            ))),
+            expr: Box::new(comparison("b", air::MatchLanguageComparisonOp::Gt)),
+        })),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Match(
+            air::Match::MatchLanguage(air::MatchLanguage {
+                source: collection(),
+                expr: Box::new(air::MatchQuery::And(vec![
+                    comparison("a", air::MatchLanguageComparisonOp::Eq),
+                    comparison("b", air::MatchLanguageComparisonOp::Gt),
+                ])),
+            })
+        ))
+    );
+
+    test_desugar_manual!(
+        name = matches_in_different_languages_are_not_merged,
+        desugarer = MergeMatchesDesugarerPass,
+        input = air::Stage::Match(air::Match::ExprLanguage(air::ExprLanguage {
+            source: Box::new(air::Stage::Match(air::Match::MatchLanguage(
+                air::MatchLanguage {
+                    source: collection(),
+                    expr: Box::new(comparison("a", air::MatchLanguageComparisonOp::Eq)),
+                }
+            ))),
+            expr: Box::new(air::Expression::Literal(air::LiteralValue::Boolean(true))),
+        })),
+        expected = Ok::<Stage, desugarer::test::Error>(air::Stage::Match(
+            air::Match::ExprLanguage(air::ExprLanguage {
+                source: Box::new(air::Stage::Match(air::Match::MatchLanguage(
+                    air::MatchLanguage {
+                        source: collection(),
+                        expr: Box::new(comparison("a", air::MatchLanguageComparisonOp::Eq)),
+                    }
+                ))),
+                expr: Box::new(air::Expression::Literal(air::LiteralValue::Boolean(true))),
+            })
+        ))
+    );
+}
+
+mod push_down_limits {
+    use super::*;
+    use crate::air::desugarer::push_down_limits::PushDownLimitsDesugarerPass;
+
+    test_desugarer!(
+        file = "desugar_push_down_limits.yml",
+        desugarer = PushDownLimitsDesugarerPass
+    );
+}
+
+mod redundant_projects {
+    use super::*;
+    use crate::air::desugarer::redundant_projects::RedundantProjectsDesugarerPass;
+
+    test_desugarer!(
+        file = "desugar_redundant_projects.yml",
+        desugarer = RedundantProjectsDesugarerPass
+    );
+
+    fn project(source: Stage, specifications: Vec<(&str, air::Expression)>) -> Stage {
+        air::Stage::Project(air::Project {
+            source: Box::new(source),
+            specifications: specifications
+                .into_iter()
+                .map(|(key, e)| (key.to_string(), air::ProjectItem::Assignment(e)))
+                .collect::<linked_hash_map::LinkedHashMap<_, _>>()
+                .into(),
+        })
+    }
+
+    fn source_project() -> Stage {
+        project(
+            air::Stage::Collection(air::Collection {
+                db: "test".into(),
+                collection: "foo".into(),
+            }),
+            vec![
+                ("foo", air::Expression::Variable("ROOT".to_string().into())),
+                ("bar", air::Expression::FieldRef("a".to_string().into())),
+            ],
+        )
+    }
+
+    // Field order is not expressible in the yml tests, since their Project
+    // keys are parsed into a sorted map.
+    test_desugar_manual!(
+        name = project_that_changes_field_order_is_not_removed,
+        desugarer = RedundantProjectsDesugarerPass,
+        input = project(
+            source_project(),
+            vec![
+                ("bar", air::Expression::FieldRef("bar".to_string().into())),
+                ("foo", air::Expression::FieldRef("foo".to_string().into())),
+            ]
+        ),
+        expected = Ok::<Stage, desugarer::test::Error>(project(
+            source_project(),
+            vec![
+                ("bar", air::Expression::FieldRef("bar".to_string().into())),
+                ("foo", air::Expression::FieldRef("foo".to_string().into())),
+            ]
+        ))
+    );
+}
+
+mod flatten_switches {
+    use super::*;
+    use crate::air::desugarer::flatten_switches::FlattenSwitchesDesugarerPass;
+
+    fn project(expr: air::Expression) -> Stage {
+        air::Stage::Project(air::Project {
+            source: Box::new(air::Stage::Collection(air::Collection {
+                db: "test".into(),
+                collection: "foo".into(),
+            })),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "x".into() => air::ProjectItem::Assignment(expr),
+            },
+        })
+    }
+
+    fn branch(case: &str, then: i32) -> air::SwitchCase {
+        air::SwitchCase {
+            case: Box::new(air::Expression::FieldRef(case.to_string().into())),
+            then: Box::new(air::Expression::Literal(air::LiteralValue::Integer(then))),
+        }
+    }
+
+    test_desugar_manual!(
+        name = nested_switch_in_default_is_flattened,
+        desugarer = FlattenSwitchesDesugarerPass,
+        input = project(air::Expression::Switch(air::Switch {
+            branches: vec![branch("a", 1)],
+            default: Box::new(air::Expression::Switch(air::Switch {
+                branches: vec![branch("b", 2), branch("c", 3)],
+                default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(4))),
+            })),
+        })),
+        expected =
+            Ok::<Stage, desugarer::test::Error>(project(air::Expression::Switch(air::Switch {
+                branches: vec![branch("a", 1), branch("b", 2), branch("c", 3)],
+                default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(4))),
+            })))
+    );
+
+    test_desugar_manual!(
+        name = switch_nested_in_branch_is_not_flattened,
+        desugarer = FlattenSwitchesDesugarerPass,
+        input = project(air::Expression::Switch(air::Switch {
+            branches: vec![air::SwitchCase {
+                case: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                then: Box::new(air::Expression::Switch(air::Switch {
+                    branches: vec![branch("b", 2)],
+                    default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(3))),
+                })),
+            }],
+            default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(4))),
+        })),
+        expected =
+            Ok::<Stage, desugarer::test::Error>(project(air::Expression::Switch(air::Switch {
+                branches: vec![air::SwitchCase {
+                    case: Box::new(air::Expression::FieldRef("a".to_string().into())),
+                    then: Box::new(air::Expression::Switch(air::Switch {
+                        branches: vec![branch("b", 2)],
+                        default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(3))),
+                    })),
+                }],
+                default: Box::new(air::Expression::Literal(air::LiteralValue::Integer(4))),
+            })))
+    );
+}
+
+mod hoist_common_subexpressions {
+    use super::*;
+    use crate::air::desugarer::hoist_common_subexpressions::HoistCommonSubexpressionsDesugarerPass;
+
+    fn project(expr: air::Expression) -> Stage {
+        air::Stage::Project(air::Project {
+            source: Box::new(air::Stage::Collection(air::Collection {
+                db: "test".into(),
+                collection: "foo".into(),
+            })),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "x".into() => air::ProjectItem::Assignment(expr),
+            },
+        })
+    }
+
+    fn op(op: air::MQLOperator, args: Vec<air::Expression>) -> air::Expression {
+        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator { op, args })
+    }
+
+    fn a_plus_b() -> air::Expression {
+        op(
+            air::MQLOperator::Add,
+            vec![
+                air::Expression::FieldRef("a".to_string().into()),
+                air::Expression::FieldRef("b".to_string().into()),
+            ],
+        )
+    }
+
+    test_desugar_manual!(
+        name = thrice_used_subexpression_is_bound_once,
+        desugarer = HoistCommonSubexpressionsDesugarerPass,
+        input = project(op(
+            air::MQLOperator::Multiply,
+            vec![a_plus_b(), a_plus_b(), a_plus_b()]
+        )),
+        expected = Ok::<Stage, desugarer::test::Error>(project(air::Expression::Let(air::Let {
+            vars: vec![air::LetVariable {
+                name: "cse0".to_string(),
+                expr: Box::new(a_plus_b()),
+            }],
+            inside: Box::new(op(
+                air::MQLOperator::Multiply,
+                vec![
+                    air::Expression::Variable("cse0".to_string().into()),
+                    air::Expression::Variable("cse0".to_string().into()),
+                    air::Expression::Variable("cse0".to_string().into()),
+                ]
+            )),
+        })))
+    );
+
+    test_desugar_manual!(
+        name = conditionally_evaluated_subexpressions_are_not_hoisted,
+        desugarer = HoistCommonSubexpressionsDesugarerPass,
+        input = project(op(
+            air::MQLOperator::Cond,
+            vec![
+                air::Expression::FieldRef("c".to_string().into()),
+                a_plus_b(),
+                a_plus_b(),
+            ]
+        )),
+        expected = Ok::<Stage, desugarer::test::Error>(project(op(
+            air::MQLOperator::Cond,
+            vec![
+                air::Expression::FieldRef("c".to_string().into()),
+                a_plus_b(),
+                a_plus_b(),
+            ]
+        )))
+    );
+}
+
 mod fold_converts {
     use super::*;
     use crate::{
@@ -521,13 +1064,35 @@ mod unsupported_operators {
         file = "desugar_unsupported_operators.yml",
         desugarer = UnsupportedOperatorsDesugarerPass
     );
+
+    test_desugar_manual!(
+        name = like_with_non_literal_pattern_is_error,
+        desugarer = UnsupportedOperatorsDesugarerPass,
+        input = air::Stage::Project(air::Project {
+            source: Box::new(air::Stage::Collection(air::Collection {
+                db: "test".into(),
+                collection: "foo".into(),
+            })),
+            specifications: crate::unchecked_unique_linked_hash_map! {
+                "x".into() => air::ProjectItem::Assignment(air::Expression::Like(air::Like {
+                    expr: Box::new(air::Expression::FieldRef("s".to_string().into())),
+                    pattern: Box::new(air::Expression::FieldRef("p".to_string().into())),
+                    escape: None,
+                })),
+            },
+        }),
+        expected = Err::<Stage, Error>(Error::CannotDesugar(desugarer::Error::InvalidLikePattern))
+    );
 }
 
 mod all_desugarer_passes {
     use mongosql_datastructures::unique_linked_hash_map::UniqueLinkedHashMap;
 
     use super::*;
-    use crate::air::{desugarer::desugar_pipeline, Project};
+    use crate::{
+        air::{desugarer::desugar_pipeline, Project},
+        options::SqlOptions,
+    };
 
     struct ProjectKeySortVisitor;
     impl Visitor for ProjectKeySortVisitor {
@@ -562,8 +1127,10 @@ mod all_desugarer_passes {
             let expected_air_pipeline = to_air_pipeline(test.expected);
 
             let mut sorter = ProjectKeySortVisitor;
-            let actual = sorter
-                .visit_stage(desugar_pipeline(input_air_pipeline).map_err(Error::CannotDesugar)?);
+            let actual = sorter.visit_stage(
+                desugar_pipeline(input_air_pipeline, SqlOptions::default())
+                    .map_err(Error::CannotDesugar)?,
+            );
 
             assert_eq!(expected_air_pipeline, actual, "{}", test.name)
         }
@@ -5,7 +5,7 @@ use crate::{
         util::sql_op_to_mql_op,
         visitor::Visitor,
         Expression, Is, Let, LetVariable, Like, LiteralValue, MQLOperator, MQLSemanticOperator,
-        RegexMatch, SQLOperator, SQLSemanticOperator, SqlConvert, SqlDivide, Stage, Switch,
+        Reduce, RegexMatch, SQLOperator, SQLSemanticOperator, SqlConvert, SqlDivide, Stage, Switch,
         SwitchCase, Type,
     },
     make_cond_expr,
@@ -581,6 +581,216 @@ impl UnsupportedOperatorsDesugarerVisitor {
         })
     }
 
+    // Desugars $sqlLeft and $sqlRight into $substrCP. The string and length arguments are
+    // bound to variables named after `op_name` and null-checked before `substr_start` is
+    // evaluated, since $strLenCP errors on null input. Negative lengths are treated as 0.
+    fn desugar_sql_left_or_right(
+        &self,
+        op_name: &str,
+        sql_op: SQLSemanticOperator,
+        substr_start: impl FnOnce(Expression, Expression) -> Expression,
+    ) -> Expression {
+        let input_str_var_name = format!("desugared_{op_name}_input0");
+        let input_str_var_ref = Expression::Variable(input_str_var_name.clone().into());
+        let input_len_var_name = format!("desugared_{op_name}_input1");
+        let input_len_var_ref = Expression::Variable(input_len_var_name.clone().into());
+
+        let non_negative_len = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Max,
+            args: vec![input_len_var_ref.clone(), ZERO_LITERAL],
+        });
+        let substr_expr = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::SubstrCP,
+            args: vec![
+                input_str_var_ref.clone(),
+                substr_start(input_str_var_ref.clone(), non_negative_len.clone()),
+                non_negative_len,
+            ],
+        });
+        let null_check = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Or,
+            args: vec![
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Lte,
+                    args: vec![input_str_var_ref, NULL_LITERAL],
+                }),
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Lte,
+                    args: vec![input_len_var_ref, NULL_LITERAL],
+                }),
+            ],
+        });
+
+        let let_vars = vec![
+            LetVariable {
+                name: input_str_var_name,
+                expr: Box::new(sql_op.args[0].clone()),
+            },
+            LetVariable {
+                name: input_len_var_name,
+                expr: Box::new(sql_op.args[1].clone()),
+            },
+        ];
+        Expression::Let(Let {
+            vars: let_vars,
+            inside: Box::new(make_cond_expr!(null_check, NULL_LITERAL, substr_expr)),
+        })
+    }
+
+    fn desugar_sql_left(&self, left: SQLSemanticOperator) -> Expression {
+        self.desugar_sql_left_or_right("sqlLeft", left, |_, _| ZERO_LITERAL)
+    }
+
+    fn desugar_sql_right(&self, right: SQLSemanticOperator) -> Expression {
+        // The substring starts `len` code points before the end of the string, clamped to the
+        // start of the string when `len` exceeds the string's length.
+        self.desugar_sql_left_or_right("sqlRight", right, |input_str, len| {
+            Expression::MQLSemanticOperator(MQLSemanticOperator {
+                op: MQLOperator::Max,
+                args: vec![
+                    Expression::MQLSemanticOperator(MQLSemanticOperator {
+                        op: MQLOperator::Subtract,
+                        args: vec![
+                            Expression::MQLSemanticOperator(MQLSemanticOperator {
+                                op: MQLOperator::StrLenCP,
+                                args: vec![input_str],
+                            }),
+                            len,
+                        ],
+                    }),
+                    ZERO_LITERAL,
+                ],
+            })
+        })
+    }
+
+    // Desugars $sqlLPad and $sqlRPad. MQL has no native padding operator, so the padding is
+    // built by concatenating the pad string with itself via $reduce over a $range, and then
+    // truncated to the number of code points needed to reach the target length. The input
+    // string is truncated to the target length if it is already longer. As with $sqlLeft
+    // and $sqlRight, all arguments are null-checked first and negative lengths are treated
+    // as 0. An empty pad string results in no padding.
+    fn desugar_sql_pad(
+        &self,
+        op_name: &str,
+        sql_op: SQLSemanticOperator,
+        combine: impl FnOnce(Expression, Expression) -> Vec<Expression>,
+    ) -> Expression {
+        let input_str_var_name = format!("desugared_{op_name}_input0");
+        let input_str_var_ref = Expression::Variable(input_str_var_name.clone().into());
+        let input_len_var_name = format!("desugared_{op_name}_input1");
+        let input_len_var_ref = Expression::Variable(input_len_var_name.clone().into());
+        let input_pad_var_name = format!("desugared_{op_name}_input2");
+        let input_pad_var_ref = Expression::Variable(input_pad_var_name.clone().into());
+
+        let non_negative_len = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Max,
+            args: vec![input_len_var_ref.clone(), ZERO_LITERAL],
+        });
+        let truncated_str = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::SubstrCP,
+            args: vec![
+                input_str_var_ref.clone(),
+                ZERO_LITERAL,
+                non_negative_len.clone(),
+            ],
+        });
+        let pad_len = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Max,
+            args: vec![
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Subtract,
+                    args: vec![
+                        non_negative_len,
+                        Expression::MQLSemanticOperator(MQLSemanticOperator {
+                            op: MQLOperator::StrLenCP,
+                            args: vec![input_str_var_ref.clone()],
+                        }),
+                    ],
+                }),
+                ZERO_LITERAL,
+            ],
+        });
+        // Step by the length of the pad string so that it is only repeated as many times as
+        // needed. The step must be positive, so an empty pad string steps by 1.
+        let pad_step = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Max,
+            args: vec![
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::StrLenCP,
+                    args: vec![input_pad_var_ref.clone()],
+                }),
+                ONE_LITERAL,
+            ],
+        });
+        let repeated_pad = Expression::Reduce(Reduce {
+            input: Box::new(Expression::MQLSemanticOperator(MQLSemanticOperator {
+                op: MQLOperator::Range,
+                args: vec![ZERO_LITERAL, pad_len.clone(), pad_step],
+            })),
+            init_value: Box::new(Expression::Literal(LiteralValue::String("".to_string()))),
+            inside: Box::new(Expression::MQLSemanticOperator(MQLSemanticOperator {
+                op: MQLOperator::Concat,
+                args: vec![
+                    Expression::Variable("value".to_string().into()),
+                    input_pad_var_ref.clone(),
+                ],
+            })),
+        });
+        let padding = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::SubstrCP,
+            args: vec![repeated_pad, ZERO_LITERAL, pad_len],
+        });
+        let padded_str = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Concat,
+            args: combine(truncated_str, padding),
+        });
+        let null_check = Expression::MQLSemanticOperator(MQLSemanticOperator {
+            op: MQLOperator::Or,
+            args: vec![
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Lte,
+                    args: vec![input_str_var_ref, NULL_LITERAL],
+                }),
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Lte,
+                    args: vec![input_len_var_ref, NULL_LITERAL],
+                }),
+                Expression::MQLSemanticOperator(MQLSemanticOperator {
+                    op: MQLOperator::Lte,
+                    args: vec![input_pad_var_ref, NULL_LITERAL],
+                }),
+            ],
+        });
+
+        let let_vars = vec![
+            LetVariable {
+                name: input_str_var_name,
+                expr: Box::new(sql_op.args[0].clone()),
+            },
+            LetVariable {
+                name: input_len_var_name,
+                expr: Box::new(sql_op.args[1].clone()),
+            },
+            LetVariable {
+                name: input_pad_var_name,
+                expr: Box::new(sql_op.args[2].clone()),
+            },
+        ];
+        Expression::Let(Let {
+            vars: let_vars,
+            inside: Box::new(make_cond_expr!(null_check, NULL_LITERAL, padded_str)),
+        })
+    }
+
+    fn desugar_sql_lpad(&self, lpad: SQLSemanticOperator) -> Expression {
+        self.desugar_sql_pad("sqlLPad", lpad, |s, padding| vec![padding, s])
+    }
+
+    fn desugar_sql_rpad(&self, rpad: SQLSemanticOperator) -> Expression {
+        self.desugar_sql_pad("sqlRPad", rpad, |s, padding| vec![s, padding])
+    }
+
     fn desugar_sql_sqrt(&self, sqrt: SQLSemanticOperator) -> Expression {
         let input_var_name = "desugared_sqlSqrt_input".to_string();
         let input_var_ref = Expression::Variable(input_var_name.clone().into());
@@ -634,11 +844,15 @@ impl Visitor for UnsupportedOperatorsDesugarerVisitor {
                 SQLOperator::Cos | SQLOperator::Sin | SQLOperator::Tan => {
                     self.desugar_trig_function(s)
                 }
+                SQLOperator::Left => self.desugar_sql_left(s),
                 SQLOperator::Log => self.desugar_sql_log(s),
+                SQLOperator::LPad => self.desugar_sql_lpad(s),
                 SQLOperator::Mod => self.desugar_sql_mod(s),
                 SQLOperator::Neg => self.desugar_sql_neg(s),
                 SQLOperator::NullIf => self.desugar_sql_nullif(s),
                 SQLOperator::Pos => self.desugar_sql_pos(s),
+                SQLOperator::Right => self.desugar_sql_right(s),
+                SQLOperator::RPad => self.desugar_sql_rpad(s),
                 SQLOperator::Round => self.desugar_sql_round(s),
                 SQLOperator::Slice => self.desugar_sql_slice(s),
                 SQLOperator::Split => self.desugar_sql_split(s),
@@ -3,3 +3,4 @@ pub use definitions::*;
 mod agg_ast;
 pub mod desugarer;
 pub(crate) mod util;
+pub mod validate;
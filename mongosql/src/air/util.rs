@@ -124,7 +124,11 @@ pub fn sql_op_to_mql_op(sql_op: SQLOperator) -> Option<MQLOperator> {
         | SQLOperator::ComputedFieldAccess
         | SQLOperator::CurrentTimestamp
         | SQLOperator::Neg
-        | SQLOperator::Pos => return None,
+        | SQLOperator::Pos
+        | SQLOperator::Left
+        | SQLOperator::Right
+        | SQLOperator::LPad
+        | SQLOperator::RPad => return None,
     };
     Some(mql_op)
 }
@@ -0,0 +1,47 @@
+use crate::air::{visitor_ref::VisitorRef, Expression, SQLOperator, SQLSemanticOperator, Stage};
+
+#[cfg(test)]
+mod test;
+
+/// Checks that a desugared pipeline is ready for codegen. Desugaring must
+/// remove every Subquery, SubqueryComparison, and SubqueryExists expression,
+/// and codegen cannot generate MQL for some SQL operators at all. Every such
+/// expression found in the pipeline, including nested pipelines, is reported.
+pub fn ensure_desugared(pipeline: &Stage) -> Result<(), String> {
+    let mut visitor = EnsureDesugaredVisitor::default();
+    pipeline.walk_ref(&mut visitor);
+    if visitor.problems.is_empty() {
+        Ok(())
+    } else {
+        Err(visitor.problems.join("; "))
+    }
+}
+
+#[derive(Default)]
+struct EnsureDesugaredVisitor {
+    problems: Vec<String>,
+}
+
+impl VisitorRef for EnsureDesugaredVisitor {
+    fn visit_expression(&mut self, node: &Expression) {
+        node.walk_ref(self);
+        match node {
+            Expression::Subquery(_) => self
+                .problems
+                .push("found Subquery expression after desugaring".to_string()),
+            Expression::SubqueryComparison(_) => self
+                .problems
+                .push("found SubqueryComparison expression after desugaring".to_string()),
+            Expression::SubqueryExists(_) => self
+                .problems
+                .push("found SubqueryExists expression after desugaring".to_string()),
+            Expression::SQLSemanticOperator(SQLSemanticOperator {
+                op: op @ SQLOperator::ComputedFieldAccess,
+                ..
+            }) => self
+                .problems
+                .push(format!("cannot generate MQL for {op:?} operator")),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    air::{
+        self, desugarer::desugar_pipeline, validate::ensure_desugared, Expression, LetVariable,
+        ProjectItem, SQLOperator, SQLSemanticOperator, Stage, Subquery,
+    },
+    options::SqlOptions,
+    unchecked_unique_linked_hash_map,
+};
+
+fn collection(name: &str) -> Box<Stage> {
+    Box::new(Stage::Collection(air::Collection {
+        db: "test".to_string(),
+        collection: name.to_string(),
+    }))
+}
+
+fn project(source: Box<Stage>, key: &str, expr: Expression) -> Stage {
+    Stage::Project(air::Project {
+        source,
+        specifications: unchecked_unique_linked_hash_map! {
+            key.to_string() => ProjectItem::Assignment(expr),
+        },
+    })
+}
+
+fn subquery() -> Expression {
+    Expression::Subquery(Subquery {
+        let_bindings: vec![LetVariable {
+            name: "vfoo_0".to_string(),
+            expr: Box::new(Expression::FieldRef("foo".to_string().into())),
+        }],
+        output_path: vec!["a".to_string()],
+        pipeline: Box::new(project(
+            collection("bar"),
+            "a",
+            Expression::FieldRef("a".to_string().into()),
+        )),
+    })
+}
+
+#[test]
+fn fully_desugared_pipeline_is_ok() {
+    let pipeline = project(
+        collection("foo"),
+        "a",
+        Expression::FieldRef("a".to_string().into()),
+    );
+
+    assert_eq!(Ok(()), ensure_desugared(&pipeline));
+}
+
+#[test]
+fn desugared_subquery_is_ok() {
+    let pipeline = desugar_pipeline(
+        project(collection("foo"), "a", subquery()),
+        SqlOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(Ok(()), ensure_desugared(&pipeline));
+}
+
+#[test]
+fn leftover_subquery_is_an_error() {
+    let pipeline = project(collection("foo"), "a", subquery());
+
+    assert_eq!(
+        Err("found Subquery expression after desugaring".to_string()),
+        ensure_desugared(&pipeline)
+    );
+}
+
+#[test]
+fn leftover_subquery_in_nested_pipeline_is_an_error() {
+    let pipeline = Stage::Lookup(air::Lookup {
+        source: collection("foo"),
+        let_vars: None,
+        pipeline: Box::new(project(collection("bar"), "a", subquery())),
+        as_var: "arr".to_string(),
+    });
+
+    assert_eq!(
+        Err("found Subquery expression after desugaring".to_string()),
+        ensure_desugared(&pipeline)
+    );
+}
+
+#[test]
+fn unsupported_operator_is_an_error() {
+    let pipeline = project(
+        collection("foo"),
+        "a",
+        Expression::SQLSemanticOperator(SQLSemanticOperator {
+            op: SQLOperator::ComputedFieldAccess,
+            args: vec![
+                Expression::FieldRef("a".to_string().into()),
+                Expression::FieldRef("b".to_string().into()),
+            ],
+        }),
+    );
+
+    assert_eq!(
+        Err("cannot generate MQL for ComputedFieldAccess operator".to_string()),
+        ensure_desugared(&pipeline)
+    );
+}
@@ -10,8 +10,8 @@ use crate::{
         FieldAccess,
     },
     schema::{
-        self, Satisfaction, SchemaEnvironment, BOOLEAN_OR_NULLISH, INTEGER_LONG_OR_NULLISH,
-        INTEGER_OR_NULLISH, NULLISH, STRING_OR_NULLISH,
+        self, Satisfaction, SchemaEnvironment, ANY_DOCUMENT, BOOLEAN_OR_NULLISH,
+        INTEGER_LONG_OR_NULLISH, INTEGER_OR_NULLISH, NULLISH, STRING_OR_NULLISH,
     },
     util::unique_linked_hash_map::UniqueLinkedHashMap,
     SchemaCheckingMode,
@@ -43,6 +43,7 @@ impl TryFrom<ast::BinaryOp> for mir::ScalarFunction {
             ast::BinaryOp::Comparison(ast::ComparisonOp::Lt) => mir::ScalarFunction::Lt,
             ast::BinaryOp::Comparison(ast::ComparisonOp::Lte) => mir::ScalarFunction::Lte,
             ast::BinaryOp::Comparison(ast::ComparisonOp::Neq) => mir::ScalarFunction::Neq,
+            ast::BinaryOp::Mod => mir::ScalarFunction::Mod,
             ast::BinaryOp::Mul => mir::ScalarFunction::Mul,
             ast::BinaryOp::Or => mir::ScalarFunction::Or,
             ast::BinaryOp::Sub => mir::ScalarFunction::Sub,
@@ -73,9 +74,15 @@ impl TryFrom<ast::FunctionName> for mir::ScalarFunction {
             ast::FunctionName::NullIf => mir::ScalarFunction::NullIf,
             ast::FunctionName::OctetLength => mir::ScalarFunction::OctetLength,
             ast::FunctionName::Position => mir::ScalarFunction::Position,
+            ast::FunctionName::Instr => mir::ScalarFunction::Position,
+            ast::FunctionName::IfNull | ast::FunctionName::IsNull => mir::ScalarFunction::Coalesce,
             ast::FunctionName::Pow => mir::ScalarFunction::Pow,
             ast::FunctionName::Radians => mir::ScalarFunction::Radians,
             ast::FunctionName::Replace => mir::ScalarFunction::Replace,
+            ast::FunctionName::Left => mir::ScalarFunction::Left,
+            ast::FunctionName::Right => mir::ScalarFunction::Right,
+            ast::FunctionName::LPad => mir::ScalarFunction::LPad,
+            ast::FunctionName::RPad => mir::ScalarFunction::RPad,
             ast::FunctionName::Sin => mir::ScalarFunction::Sin,
             ast::FunctionName::Size => mir::ScalarFunction::Size,
             ast::FunctionName::Slice => mir::ScalarFunction::Slice,
@@ -106,13 +113,16 @@ impl TryFrom<ast::FunctionName> for mir::ScalarFunction {
             | ast::FunctionName::Avg
             | ast::FunctionName::Count
             | ast::FunctionName::First
+            | ast::FunctionName::GroupConcat
             | ast::FunctionName::Last
             | ast::FunctionName::Max
             | ast::FunctionName::MergeDocuments
             | ast::FunctionName::Min
             | ast::FunctionName::StddevPop
             | ast::FunctionName::StddevSamp
-            | ast::FunctionName::Sum => {
+            | ast::FunctionName::Sum
+            | ast::FunctionName::VarPop
+            | ast::FunctionName::VarSamp => {
                 return Err(Error::AggregationInPlaceOfScalar(f.pretty_print().unwrap()))
             }
         })
@@ -129,6 +139,7 @@ impl TryFrom<ast::FunctionName> for mir::AggregationFunction {
             ast::FunctionName::Avg => mir::AggregationFunction::Avg,
             ast::FunctionName::Count => mir::AggregationFunction::Count,
             ast::FunctionName::First => mir::AggregationFunction::First,
+            ast::FunctionName::GroupConcat => mir::AggregationFunction::GroupConcat,
             ast::FunctionName::Last => mir::AggregationFunction::Last,
             ast::FunctionName::Max => mir::AggregationFunction::Max,
             ast::FunctionName::MergeDocuments => mir::AggregationFunction::MergeDocuments,
@@ -136,6 +147,8 @@ impl TryFrom<ast::FunctionName> for mir::AggregationFunction {
             ast::FunctionName::StddevPop => mir::AggregationFunction::StddevPop,
             ast::FunctionName::StddevSamp => mir::AggregationFunction::StddevSamp,
             ast::FunctionName::Sum => mir::AggregationFunction::Sum,
+            ast::FunctionName::VarPop => mir::AggregationFunction::VarPop,
+            ast::FunctionName::VarSamp => mir::AggregationFunction::VarSamp,
 
             ast::FunctionName::Abs
             | ast::FunctionName::BitLength
@@ -146,9 +159,14 @@ impl TryFrom<ast::FunctionName> for mir::AggregationFunction {
             | ast::FunctionName::CurrentTimestamp
             | ast::FunctionName::Degrees
             | ast::FunctionName::Floor
+            | ast::FunctionName::IfNull
+            | ast::FunctionName::Instr
+            | ast::FunctionName::IsNull
             | ast::FunctionName::Log
             | ast::FunctionName::Log10
+            | ast::FunctionName::Left
             | ast::FunctionName::Lower
+            | ast::FunctionName::LPad
             | ast::FunctionName::LTrim
             | ast::FunctionName::Mod
             | ast::FunctionName::NullIf
@@ -158,6 +176,8 @@ impl TryFrom<ast::FunctionName> for mir::AggregationFunction {
             | ast::FunctionName::Round
             | ast::FunctionName::Radians
             | ast::FunctionName::Replace
+            | ast::FunctionName::Right
+            | ast::FunctionName::RPad
             | ast::FunctionName::RTrim
             | ast::FunctionName::Sin
             | ast::FunctionName::Size
@@ -366,19 +386,84 @@ impl<'a> Algebrizer<'a> {
 
     pub fn algebrize_set_query(&self, ast_node: ast::SetQuery) -> Result<mir::Stage> {
         match ast_node.op {
-            ast::SetOperator::Union => Err(Error::DistinctUnion),
-            ast::SetOperator::UnionAll => schema_check_return!(
-                self,
-                mir::Stage::Set(mir::Set {
-                    operation: mir::SetOperation::UnionAll,
-                    left: Box::new(self.algebrize_query(*ast_node.left)?),
-                    right: Box::new(self.algebrize_query(*ast_node.right)?),
-                    cache: SchemaCache::new(),
-                })
-            ),
+            // A distinct UNION is a UNION ALL that keeps one of each distinct row.
+            ast::SetOperator::Union => {
+                let union_all = self.algebrize_set_query(ast::SetQuery {
+                    op: ast::SetOperator::UnionAll,
+                    ..ast_node
+                })?;
+                self.algebrize_distinct_rows(union_all)
+            }
+            ast::SetOperator::UnionAll => {
+                let left = self.algebrize_query(*ast_node.left)?;
+                let right = self.algebrize_query(*ast_node.right)?;
+                // Relaxed schema checking accepts branches whose columns may be incompatible,
+                // as it does for other type mismatches.
+                if let SchemaCheckingMode::Strict = self.schema_checking_mode {
+                    let state = self.schema_inference_state();
+                    let (left_schema, right_schema) = (left.schema(&state)?, right.schema(&state)?);
+                    left_schema
+                        .is_union_compatible(&right_schema)
+                        .map_err(Error::IncompatibleUnionBranches)?;
+                }
+                schema_check_return!(
+                    self,
+                    mir::Stage::Set(mir::Set {
+                        operation: mir::SetOperation::UnionAll,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                        cache: SchemaCache::new(),
+                    })
+                )
+            }
         }
     }
 
+    /// Groups source by every field of every datasource it produces, so that
+    /// one of each distinct row remains. The keys are unaliased field accesses,
+    /// which the Group binds back under their original datasources, so the
+    /// result has the same shape as source. This requires every field of every
+    /// datasource to be known.
+    fn algebrize_distinct_rows(&self, source: mir::Stage) -> Result<mir::Stage> {
+        fn has_known_fields(schema: &schema::Schema) -> bool {
+            match schema {
+                schema::Schema::Document(d) => !d.additional_properties,
+                schema::Schema::AnyOf(schemas) => schemas.iter().all(has_known_fields),
+                _ => false,
+            }
+        }
+
+        let schema_env = source.schema(&self.schema_inference_state())?.schema_env;
+        let mut keys = Vec::new();
+        for (datasource, datasource_schema) in schema_env.iter() {
+            if !has_known_fields(datasource_schema) {
+                return Err(Error::DistinctUnion);
+            }
+            let fields = datasource_schema
+                .keys()
+                .into_iter()
+                .collect::<BTreeSet<_>>();
+            keys.extend(fields.into_iter().map(|field| {
+                mir::OptionallyAliasedExpr::Unaliased(mir::Expression::FieldAccess(
+                    FieldAccess::new(
+                        Box::new(mir::Expression::Reference(datasource.clone().into())),
+                        field,
+                    ),
+                ))
+            }));
+        }
+        schema_check_return!(
+            self,
+            mir::Stage::Group(mir::Group {
+                source: Box::new(source),
+                keys,
+                aggregations: vec![],
+                cache: SchemaCache::new(),
+                scope: self.scope_level,
+            })
+        )
+    }
+
     fn algebrize_select_values_body(
         &self,
         exprs: Vec<ast::SelectValuesExpression>,
@@ -415,11 +500,18 @@ impl<'a> Algebrizer<'a> {
                             Some(d)
                         }
                     }
-                    // If select values are not a document, an error will ultimately be thrown. Algebrize
-                    // for now, and depending on the rest of the select query, a DuplicateKey or SchemaChecking
-                    // error will occur downstream.
+                    // Any other expression is mapped to Bottom as a whole, so it must evaluate to a
+                    // document. Depending on the rest of the select query, a DuplicateKey error may
+                    // still occur below.
                     _ => {
                         let e = expression_algebrizer.algebrize_expression(e, false)?;
+                        let e_schema = e.schema(&expression_algebrizer.schema_inference_state())?;
+                        if !expression_algebrizer
+                            .schema_inference_state()
+                            .check_satisfies(&e_schema, &ANY_DOCUMENT)
+                        {
+                            return Err(Error::NonDocumentSelectValue(e_schema));
+                        }
                         let bot = Key::bot(expression_algebrizer.scope_level);
                         datasources
                             .insert(bot.clone())
@@ -974,7 +1066,8 @@ impl<'a> Algebrizer<'a> {
                 return self.algebrize_order_by_clause(order_by_node, select);
             }
         };
-        let ordered = self.algebrize_order_by_clause(order_by_node, select)?;
+        // The project_body also drops any datasource holding computed sort keys.
+        let (ordered, _) = self.algebrize_sort(order_by_node, select)?;
         Ok(mir::Stage::Project(mir::Project {
             source: Box::new(ordered),
             expression: project_body,
@@ -988,50 +1081,107 @@ impl<'a> Algebrizer<'a> {
         ast_node: Option<ast::OrderByClause>,
         source: mir::Stage,
     ) -> Result<mir::Stage> {
+        let (ordered, sort_keys_datasource) = self.algebrize_sort(ast_node, source)?;
+        let sort_keys_datasource = match sort_keys_datasource {
+            None => return Ok(ordered),
+            Some(key) => key,
+        };
+        // Project away the datasource holding the computed sort keys, keeping every other
+        // datasource at this scope.
+        let expression = ordered
+            .schema(&self.schema_inference_state())?
+            .schema_env
+            .into_iter()
+            .filter(|(k, _)| k.scope == self.scope_level && k != &sort_keys_datasource)
+            .map(|(k, _)| (k.clone(), mir::Expression::Reference(k.into())))
+            .collect();
+        schema_check_return!(
+            self,
+            mir::Stage::Project(mir::Project {
+                source: Box::new(ordered),
+                expression,
+                is_add_fields: false,
+                cache: SchemaCache::new(),
+            }),
+        )
+    }
+
+    /// Algebrizes the ORDER BY clause into a Sort stage over the source. A
+    /// sort key that is not a pure field path is computed before the Sort by
+    /// an AddFields Project, which binds a document of all such sort keys to
+    /// a temporary datasource. That datasource's key is returned alongside
+    /// the Sort so the caller can project it away.
+    fn algebrize_sort(
+        &self,
+        ast_node: Option<ast::OrderByClause>,
+        source: mir::Stage,
+    ) -> Result<(mir::Stage, Option<Key>)> {
         *self.clause_type.borrow_mut() = ClauseType::OrderBy;
+        let source_schema_env = source.schema(&self.schema_inference_state())?.schema_env;
         let expression_algebrizer = self
             .clone()
-            .with_merged_mappings(source.schema(&self.schema_inference_state())?.schema_env)?;
-        let ordered = match ast_node {
-            None => source,
-            Some(o) => {
-                let sort_specs = o
-                    .sort_specs
-                    .into_iter()
-                    .map(|s| {
-                        let sort_key = match s.key {
-                            ast::SortKey::Simple(expr) => {
-                                expression_algebrizer.algebrize_expression(expr, false)
-                            }
-                            ast::SortKey::Positional(_) => panic!(
-                                "positional sort keys should have been rewritten to references"
-                            ),
-                        }?;
-                        match s.direction {
-                            ast::SortDirection::Asc => Ok(mir::SortSpecification::Asc(
-                                sort_key
-                                    .clone()
-                                    .try_into()
-                                    .map_err(|_| Error::InvalidSortKey(sort_key))?,
-                            )),
-                            ast::SortDirection::Desc => Ok(mir::SortSpecification::Desc(
-                                sort_key
-                                    .clone()
-                                    .try_into()
-                                    .map_err(|_| Error::InvalidSortKey(sort_key))?,
-                            )),
-                        }
-                    })
-                    .collect::<Result<Vec<mir::SortSpecification>>>()?;
-                mir::Stage::Sort(mir::Sort {
-                    source: Box::new(source),
-                    specs: sort_specs,
-                    cache: SchemaCache::new(),
-                })
-            }
+            .with_merged_mappings(source_schema_env.clone())?;
+        let o = match ast_node {
+            None => return Ok((source, None)),
+            Some(o) => o,
         };
+        let mut datasource_name = "__sortKeys".to_string();
+        while source_schema_env.contains_key(&(datasource_name.clone(), self.scope_level).into()) {
+            datasource_name.insert(0, '_');
+        }
+        let sort_keys_datasource: Key = (datasource_name, self.scope_level).into();
+        let mut computed_sort_keys = UniqueLinkedHashMap::new();
+        let mut sort_specs = Vec::new();
+        for (i, s) in o.sort_specs.into_iter().enumerate() {
+            let sort_key = match s.key {
+                ast::SortKey::Simple(expr) => {
+                    expression_algebrizer.algebrize_expression(expr, false)?
+                }
+                ast::SortKey::Positional(_) => {
+                    panic!("positional sort keys should have been rewritten to references")
+                }
+            };
+            let field_path = match mir::FieldPath::try_from(&sort_key) {
+                Ok(field_path) => field_path,
+                Err(_) => {
+                    let field = format!("key{i}");
+                    let field_path = mir::FieldPath {
+                        key: sort_keys_datasource.clone(),
+                        fields: vec![field.clone()],
+                        is_nullable: sort_key.is_nullable(),
+                    };
+                    computed_sort_keys
+                        .insert(field, sort_key)
+                        .map_err(|e| Error::DuplicateDocumentKey(e.get_key_name()))?;
+                    field_path
+                }
+            };
+            sort_specs.push(match s.direction {
+                ast::SortDirection::Asc => mir::SortSpecification::Asc(field_path),
+                ast::SortDirection::Desc => mir::SortSpecification::Desc(field_path),
+            });
+        }
+        let (source, sort_keys_datasource) = if computed_sort_keys.is_empty() {
+            (source, None)
+        } else {
+            let source = mir::Stage::Project(mir::Project {
+                source: Box::new(source),
+                expression: map! {
+                    sort_keys_datasource.clone() =>
+                        mir::Expression::Document(computed_sort_keys.into()),
+                },
+                is_add_fields: true,
+                cache: SchemaCache::new(),
+            });
+            (source, Some(sort_keys_datasource))
+        };
+        let ordered = mir::Stage::Sort(mir::Sort {
+            source: Box::new(source),
+            specs: sort_specs,
+            cache: SchemaCache::new(),
+        });
         ordered.schema(&self.schema_inference_state())?;
-        Ok(ordered)
+        Ok((ordered, sort_keys_datasource))
     }
 
     pub fn algebrize_group_by_clause(
@@ -1114,20 +1264,28 @@ impl<'a> Algebrizer<'a> {
         let mir_node = match f.args {
             ast::FunctionArguments::Star => {
                 if f.function == ast::FunctionName::Count {
+                    if distinct {
+                        return Err(Error::DistinctCountStar);
+                    }
                     return Ok(mir::AggregationExpr::CountStar(distinct));
                 }
                 return Err(Error::StarInNonCount);
             }
             ast::FunctionArguments::Args(ve) => {
+                let function = mir::AggregationFunction::try_from(function)?;
+                let (arg, separator) = if function == mir::AggregationFunction::GroupConcat {
+                    self.algebrize_group_concat_args(ve)?
+                } else {
+                    if ve.len() != 1 {
+                        return Err(Error::AggregationFunctionMustHaveOneArgument);
+                    }
+                    (self.algebrize_expression(ve[0].clone(), false)?, None)
+                };
                 mir::AggregationExpr::Function(mir::AggregationFunctionApplication {
-                    function: mir::AggregationFunction::try_from(function)?,
-                    arg: Box::new({
-                        if ve.len() != 1 {
-                            return Err(Error::AggregationFunctionMustHaveOneArgument);
-                        }
-                        self.algebrize_expression(ve[0].clone(), false)?
-                    }),
+                    function,
+                    arg: Box::new(arg),
                     distinct,
+                    separator,
                 })
             }
         };
@@ -1135,13 +1293,32 @@ impl<'a> Algebrizer<'a> {
         Ok(mir_node)
     }
 
+    /// GROUP_CONCAT takes the value to concatenate and an optional string
+    /// literal separator, which defaults to "," when omitted.
+    fn algebrize_group_concat_args(
+        &self,
+        args: Vec<ast::Expression>,
+    ) -> Result<(mir::Expression, Option<String>)> {
+        let mut args = args.into_iter();
+        let (value, separator) = match (args.next(), args.next(), args.next()) {
+            (Some(value), None, None) => (value, None),
+            (Some(value), Some(ast::Expression::StringConstructor(separator)), None) => {
+                (value, Some(separator))
+            }
+            _ => return Err(Error::InvalidGroupConcatArguments),
+        };
+        Ok((self.algebrize_expression(value, false)?, separator))
+    }
+
     pub fn algebrize_expression(
         &self,
         ast_node: ast::Expression,
         in_implicit_type_conversion_context: bool,
     ) -> Result<mir::Expression> {
         match ast_node {
-            ast::Expression::Literal(l) => Ok(mir::Expression::Literal(self.algebrize_literal(l))),
+            ast::Expression::Literal(l) | ast::Expression::DefaultArgument(l) => {
+                Ok(mir::Expression::Literal(self.algebrize_literal(l)))
+            }
             ast::Expression::StringConstructor(s) => {
                 Ok(self.algebrize_string_constructor(s, in_implicit_type_conversion_context))
             }
@@ -1173,16 +1350,21 @@ impl<'a> Algebrizer<'a> {
             ast::Expression::Trim(t) => self.algebrize_trim(t),
             ast::Expression::DateFunction(d) => self.algebrize_date_function(d),
             ast::Expression::Extract(e) => self.algebrize_extract(e),
+            // Intervals are only valid as operands of date arithmetic, which
+            // algebrize_binary_expr handles before reaching this point.
+            ast::Expression::Interval(_) => Err(Error::MisplacedInterval),
             ast::Expression::Access(a) => self.algebrize_access(a),
             ast::Expression::Case(c) => self.algebrize_case(c),
             ast::Expression::Cast(c) => self.algebrize_cast(c),
             ast::Expression::TypeAssertion(t) => self.algebrize_type_assertion(t),
             ast::Expression::Is(i) => self.algebrize_is(i),
+            ast::Expression::IsTruthValue(i) => self.algebrize_is_truth_value(i),
             ast::Expression::Like(l) => self.algebrize_like(l),
             // Tuples should all be rewritten away.
             ast::Expression::Tuple(_) => panic!("tuples cannot be algebrized"),
             ast::Expression::Subquery(s) => self.algebrize_subquery(*s),
             ast::Expression::SubqueryComparison(s) => self.algebrize_subquery_comparison(s),
+            ast::Expression::QuantifiedComparison(q) => self.algebrize_quantified_comparison(q),
             ast::Expression::Exists(e) => self.algebrize_exists(*e),
         }
     }
@@ -1360,6 +1542,8 @@ impl<'a> Algebrizer<'a> {
             (ast::FunctionName::Abs, _)
             | (ast::FunctionName::Ceil, _)
             | (ast::FunctionName::Coalesce, _)
+            | (ast::FunctionName::IfNull, 2)
+            | (ast::FunctionName::IsNull, 2)
             | (ast::FunctionName::Cos, _)
             | (ast::FunctionName::CurrentTimestamp, _)
             | (ast::FunctionName::Degrees, _)
@@ -1392,12 +1576,91 @@ impl<'a> Algebrizer<'a> {
                 let [string, start, length]: [ast::Expression; 3] = args
                     .try_into()
                     .expect("Could not unpack args for ast Substring function");
+                // A negative length is only rejected when it is written as a literal; dynamic
+                // lengths are left to runtime. The -1 length the optional parameters rewrite
+                // supplies for an omitted length is a DefaultArgument, so it is allowed.
+                if let Some(len) = Self::integer_literal(&length).filter(|len| *len < 0) {
+                    return Err(Error::NegativeSubstringLength(len));
+                }
                 vec![
                     self.algebrize_expression(string, false)?,
                     self.algebrize_expression(start, true)?,
                     self.algebrize_expression(length, true)?,
                 ]
             }
+            (ast::FunctionName::Left, 2) | (ast::FunctionName::Right, 2) => {
+                let [string, length]: [ast::Expression; 2] = args
+                    .try_into()
+                    .expect("Could not unpack args for ast Left or Right function");
+                vec![
+                    self.algebrize_expression(string, false)?,
+                    self.algebrize_expression(length, true)?,
+                ]
+            }
+            (ast::FunctionName::LPad, 3) | (ast::FunctionName::RPad, 3) => {
+                let [string, length, pad]: [ast::Expression; 3] = args
+                    .try_into()
+                    .expect("Could not unpack args for ast LPad or RPad function");
+                vec![
+                    self.algebrize_expression(string, false)?,
+                    self.algebrize_expression(length, true)?,
+                    self.algebrize_expression(pad, false)?,
+                ]
+            }
+            // POSITION's optional start position is 1-indexed like its result, while the start
+            // of the Position function is 0-indexed, as it is for INSTR. As for SUBSTRING's
+            // length, only a literal start below 1 is rejected; dynamic starts are left to runtime.
+            (ast::FunctionName::Position, 3) => {
+                let [substring, string, start]: [ast::Expression; 3] = args
+                    .try_into()
+                    .expect("Could not unpack args for ast Position function");
+                if let Some(start) = Self::integer_literal(&start).filter(|start| *start < 1) {
+                    return Err(Error::InvalidPositionStart(start));
+                }
+                let start = self.algebrize_expression(start, true)?;
+                vec![
+                    self.algebrize_expression(substring, false)?,
+                    self.algebrize_expression(string, false)?,
+                    mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                        function: mir::ScalarFunction::Sub,
+                        is_nullable: start.is_nullable(),
+                        args: vec![
+                            start,
+                            mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                        ],
+                    }),
+                ]
+            }
+            // INSTR(string, substring[, start]) is algebrized as POSITION(substring IN string)
+            // with the optional start offset as a trailing argument.
+            (ast::FunctionName::Instr, 2) | (ast::FunctionName::Instr, 3) => {
+                let mut args = args.into_iter();
+                let string = args
+                    .next()
+                    .expect("Could not unpack args for ast Instr function");
+                let substring = args
+                    .next()
+                    .expect("Could not unpack args for ast Instr function");
+                let mut algebrized = vec![
+                    self.algebrize_expression(substring, false)?,
+                    self.algebrize_expression(string, false)?,
+                ];
+                if let Some(start) = args.next() {
+                    algebrized.push(self.algebrize_expression(start, true)?);
+                }
+                algebrized
+            }
+            // IFNULL, NVL, and ISNULL are the two-argument forms of COALESCE, so unlike
+            // COALESCE their argument count is checked here rather than during schema checking.
+            (ast::FunctionName::IfNull, found) | (ast::FunctionName::IsNull, found) => {
+                return Err(Error::SchemaChecking(
+                    mir::schema::Error::IncorrectArgumentCount {
+                        name: f.function.as_str(),
+                        required: 2,
+                        found,
+                    },
+                ))
+            }
             (ast::FunctionName::NullIf, 2) => {
                 let [v1, v2]: [ast::Expression; 2] = args
                     .try_into()
@@ -1407,6 +1670,11 @@ impl<'a> Algebrizer<'a> {
             }
             (ast::FunctionName::Split, _)
             | (ast::FunctionName::Substring, _)
+            | (ast::FunctionName::Left, _)
+            | (ast::FunctionName::Right, _)
+            | (ast::FunctionName::LPad, _)
+            | (ast::FunctionName::RPad, _)
+            | (ast::FunctionName::Instr, _)
             | (ast::FunctionName::NullIf, _)
             | (ast::FunctionName::BitLength, _)
             | (ast::FunctionName::CharLength, _)
@@ -1425,13 +1693,16 @@ impl<'a> Algebrizer<'a> {
             | (ast::FunctionName::Avg, _)
             | (ast::FunctionName::Count, _)
             | (ast::FunctionName::First, _)
+            | (ast::FunctionName::GroupConcat, _)
             | (ast::FunctionName::Last, _)
             | (ast::FunctionName::Max, _)
             | (ast::FunctionName::MergeDocuments, _)
             | (ast::FunctionName::Min, _)
             | (ast::FunctionName::StddevPop, _)
             | (ast::FunctionName::StddevSamp, _)
-            | (ast::FunctionName::Sum, _) => {
+            | (ast::FunctionName::Sum, _)
+            | (ast::FunctionName::VarPop, _)
+            | (ast::FunctionName::VarSamp, _) => {
                 return Err(Error::AggregationInPlaceOfScalar(f.pretty_print().unwrap()))
             }
             (ast::FunctionName::LTrim, _)
@@ -1455,16 +1726,63 @@ impl<'a> Algebrizer<'a> {
 
         let function = mir::ScalarFunction::try_from(f.function)?;
         let is_nullable = Self::determine_scalar_function_nullability(function, &args);
+        let is_position_from = f.function == ast::FunctionName::Position && args.len() == 3;
+
+        // GREATEST and LEAST widen mixed numeric arguments to the highest-priority numeric
+        // type among them, but $max and $min keep the type of the argument they select.
+        let cast_result = match function {
+            mir::ScalarFunction::Greatest | mir::ScalarFunction::Least => {
+                let mut arg_schemas = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    arg_schemas.push(arg.schema(&self.schema_inference_state())?);
+                }
+                match mir::schema::widened_numeric_schema(&arg_schemas) {
+                    Some(schema::Schema::Atomic(schema::Atomic::Decimal)) => {
+                        Some(mir::Type::Decimal128)
+                    }
+                    Some(schema::Schema::Atomic(schema::Atomic::Double)) => Some(mir::Type::Double),
+                    Some(schema::Schema::Atomic(schema::Atomic::Long)) => Some(mir::Type::Int64),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
 
         // here we don't use the new constructor because we're setting the
         // proper nullability
-        Ok(mir::Expression::ScalarFunction(
-            mir::ScalarFunctionApplication {
+        let scalar_function_expr =
+            mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
                 function,
                 is_nullable,
                 args,
-            },
-        ))
+            });
+
+        // POSITION with a start position is 1-indexed and evaluates to 0 when the substring does
+        // not occur, but the Position function is 0-indexed and evaluates to -1, as INSTR does.
+        if is_position_from {
+            return Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Add,
+                    is_nullable,
+                    args: vec![
+                        scalar_function_expr,
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    ],
+                },
+            ));
+        }
+
+        if let Some(target_type) = cast_result {
+            Ok(mir::Expression::Cast(mir::CastExpr {
+                expr: Box::new(scalar_function_expr),
+                to: target_type,
+                on_null: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
+                on_error: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
+                is_nullable,
+            }))
+        } else {
+            Ok(scalar_function_expr)
+        }
     }
 
     fn algebrize_unary_expr(&self, u: ast::UnaryExpr) -> Result<mir::Expression> {
@@ -1479,6 +1797,20 @@ impl<'a> Algebrizer<'a> {
         ))
     }
 
+    /// Returns the value of an integral literal, such as `2` or `-1`, or None for any other
+    /// expression.
+    fn integer_literal(expr: &ast::Expression) -> Option<i64> {
+        match expr {
+            ast::Expression::Literal(ast::Literal::Integer(i)) => Some(i64::from(*i)),
+            ast::Expression::Literal(ast::Literal::Long(l)) => Some(*l),
+            ast::Expression::Unary(ast::UnaryExpr {
+                op: ast::UnaryOp::Neg,
+                expr,
+            }) => Self::integer_literal(expr).and_then(i64::checked_neg),
+            _ => None,
+        }
+    }
+
     fn convert_literal_to_bool(expr: mir::Expression) -> mir::Expression {
         match expr {
             mir::Expression::Literal(mir::LiteralValue::Integer(i)) => match i {
@@ -1493,16 +1825,31 @@ impl<'a> Algebrizer<'a> {
     fn algebrize_binary_expr(&self, b: ast::BinaryExpr) -> Result<mir::Expression> {
         use crate::ast::BinaryOp::*;
 
+        // Adding or subtracting an INTERVAL is date arithmetic, which is a
+        // DateFunction rather than a ScalarFunction. An INTERVAL may be added
+        // on either side, but may only be subtracted from a date.
+        let b = match (b.op, *b.left, *b.right) {
+            (op @ (Add | Sub), date, ast::Expression::Interval(interval))
+            | (op @ Add, ast::Expression::Interval(interval), date) => {
+                return self.algebrize_date_arithmetic(op, date, interval)
+            }
+            (op, left, right) => ast::BinaryExpr {
+                left: Box::new(left),
+                op,
+                right: Box::new(right),
+            },
+        };
+
         // First, we must determine if the left and right operands each need to
         // be algebrized in an implicit type conversion context (this is done
         // by toggling the bool argument to aglebrize_expression). The different
         // cases are detailed below.
         let (mut left, mut right) = match b.op {
-            // Add, And, Div, Mul, Or, and Sub do not expect String operands,
+            // Add, And, Div, Mod, Mul, Or, and Sub do not expect String operands,
             // therefore we algebrize their left and right operands with true.
             // This means we _should_ attempt to implicitly convert any
             // StringConstructors into different literal types.
-            Add | And | Div | Mul | Or | Sub => (
+            Add | And | Div | Mod | Mul | Or | Sub => (
                 self.algebrize_expression(*b.left, true)?,
                 self.algebrize_expression(*b.right, true)?,
             ),
@@ -1605,6 +1952,23 @@ impl<'a> Algebrizer<'a> {
         }))
     }
 
+    fn algebrize_is_truth_value(&self, ast_node: ast::IsTruthValueExpr) -> Result<mir::Expression> {
+        let function = match ast_node.value {
+            ast::TruthValue::True => mir::ScalarFunction::IsTrue,
+            ast::TruthValue::False => mir::ScalarFunction::IsFalse,
+            ast::TruthValue::Unknown => mir::ScalarFunction::IsUnknown,
+        };
+        // here we don't use the new constructor because truth value tests
+        // are never nullable
+        Ok(mir::Expression::ScalarFunction(
+            mir::ScalarFunctionApplication {
+                function,
+                is_nullable: false,
+                args: vec![self.algebrize_expression(*ast_node.expr, true)?],
+            },
+        ))
+    }
+
     fn algebrize_like(&self, ast_node: ast::LikeExpr) -> Result<mir::Expression> {
         Ok(mir::Expression::Like(mir::LikeExpr {
             expr: Box::new(self.algebrize_expression(*ast_node.expr, false)?),
@@ -1671,18 +2035,42 @@ impl<'a> Algebrizer<'a> {
         // _do_ expect any StringConstructors to be String values, so we set
         // in_implicit_type_conversion_context to false.
         let in_implicit_type_conversion_context = !non_literals_are_nullable_strings;
-        let args = vec![
-            arg.unwrap_or(
-                self.algebrize_string_constructor(arg_lit, in_implicit_type_conversion_context),
-            ),
-            min.unwrap_or(
-                self.algebrize_string_constructor(min_lit, in_implicit_type_conversion_context),
-            ),
-            max.unwrap_or(
-                self.algebrize_string_constructor(max_lit, in_implicit_type_conversion_context),
-            ),
-        ];
+        let arg = arg.unwrap_or(
+            self.algebrize_string_constructor(arg_lit, in_implicit_type_conversion_context),
+        );
+        let min = min.unwrap_or(
+            self.algebrize_string_constructor(min_lit, in_implicit_type_conversion_context),
+        );
+        let max = max.unwrap_or(
+            self.algebrize_string_constructor(max_lit, in_implicit_type_conversion_context),
+        );
+
+        // BETWEEN SYMMETRIC does not care about the order of its bounds, so it is
+        // desugared to (arg >= min AND arg <= max) OR (arg >= max AND arg <= min).
+        if b.symmetric {
+            let apply = |function, args: Vec<mir::Expression>| {
+                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                    function,
+                    is_nullable: Self::args_are_nullable(&args),
+                    args,
+                })
+            };
+            let range = |lower: &mir::Expression, upper: &mir::Expression| {
+                apply(
+                    mir::ScalarFunction::And,
+                    vec![
+                        apply(mir::ScalarFunction::Gte, vec![arg.clone(), lower.clone()]),
+                        apply(mir::ScalarFunction::Lte, vec![arg.clone(), upper.clone()]),
+                    ],
+                )
+            };
+            return Ok(apply(
+                mir::ScalarFunction::Or,
+                vec![range(&min, &max), range(&max, &min)],
+            ));
+        }
 
+        let args = vec![arg, min, max];
         let function = mir::ScalarFunction::Between;
         let is_nullable = Self::args_are_nullable(&args);
         Ok(mir::Expression::ScalarFunction(
@@ -1700,10 +2088,12 @@ impl<'a> Algebrizer<'a> {
             ast::TrimSpec::Trailing => mir::ScalarFunction::RTrim,
             ast::TrimSpec::Both => mir::ScalarFunction::BTrim,
         };
-        let args = vec![
-            self.algebrize_expression(*t.trim_chars, false)?,
-            self.algebrize_expression(*t.arg, false)?,
-        ];
+        // When no characters are specified, spaces are trimmed.
+        let trim_chars = match t.trim_chars {
+            Some(trim_chars) => self.algebrize_expression(*trim_chars, false)?,
+            None => mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
+        };
+        let args = vec![trim_chars, self.algebrize_expression(*t.arg, false)?];
         Ok(mir::Expression::ScalarFunction(
             mir::ScalarFunctionApplication {
                 function,
@@ -1728,6 +2118,7 @@ impl<'a> Algebrizer<'a> {
             DayOfWeek => mir::ScalarFunction::DayOfWeek,
             IsoWeek => mir::ScalarFunction::IsoWeek,
             IsoWeekday => mir::ScalarFunction::IsoWeekday,
+            Epoch => mir::ScalarFunction::UnixTimestamp,
             Quarter => panic!("'Quarter' is not a supported date part for EXTRACT"),
         };
         let args = vec![self.algebrize_expression(*e.arg, true)?];
@@ -1741,14 +2132,9 @@ impl<'a> Algebrizer<'a> {
         ))
     }
 
-    fn algebrize_date_function(&self, d: ast::DateFunctionExpr) -> Result<mir::Expression> {
-        use crate::ast::{DateFunctionName::*, DatePart::*};
-        let function = match d.function {
-            Add => mir::DateFunction::Add,
-            Diff => mir::DateFunction::Diff,
-            Trunc => mir::DateFunction::Trunc,
-        };
-        let date_part = match d.date_part {
+    fn algebrize_date_part(date_part: ast::DatePart) -> mir::DatePart {
+        use crate::ast::DatePart::*;
+        match date_part {
             Year => mir::DatePart::Year,
             Month => mir::DatePart::Month,
             Day => mir::DatePart::Day,
@@ -1758,13 +2144,23 @@ impl<'a> Algebrizer<'a> {
             Millisecond => mir::DatePart::Millisecond,
             Week => mir::DatePart::Week,
             Quarter => mir::DatePart::Quarter,
-            IsoWeek | IsoWeekday | DayOfYear | DayOfWeek => {
+            IsoWeek | IsoWeekday | DayOfYear | DayOfWeek | Epoch => {
                 panic!(
-                    "'{0:?}' is not a supported date part for DATEADD, DATEDIFF, and DATETRUNC",
-                    d.date_part
+                    "'{0:?}' is not a supported date part for DATEADD, DATEDIFF, DATETRUNC, and INTERVAL",
+                    date_part
                 )
             }
+        }
+    }
+
+    fn algebrize_date_function(&self, d: ast::DateFunctionExpr) -> Result<mir::Expression> {
+        use crate::ast::DateFunctionName::*;
+        let function = match d.function {
+            Add => mir::DateFunction::Add,
+            Diff => mir::DateFunction::Diff,
+            Trunc => mir::DateFunction::Trunc,
         };
+        let date_part = Self::algebrize_date_part(d.date_part);
 
         let args = d
             .args
@@ -1785,6 +2181,34 @@ impl<'a> Algebrizer<'a> {
         ))
     }
 
+    fn algebrize_date_arithmetic(
+        &self,
+        op: ast::BinaryOp,
+        date: ast::Expression,
+        interval: ast::IntervalExpr,
+    ) -> Result<mir::Expression> {
+        let function = match op {
+            ast::BinaryOp::Sub => mir::DateFunction::Sub,
+            _ => mir::DateFunction::Add,
+        };
+
+        // The arguments are ordered to match DATEADD: the amount, then the date.
+        let args = vec![
+            self.algebrize_expression(*interval.amount, true)?,
+            self.algebrize_expression(date, true)?,
+        ];
+        let is_nullable = Self::args_are_nullable(&args);
+
+        Ok(mir::Expression::DateFunction(
+            mir::DateFunctionApplication {
+                function,
+                is_nullable,
+                date_part: Self::algebrize_date_part(interval.date_part),
+                args,
+            },
+        ))
+    }
+
     fn algebrize_access(&self, a: ast::AccessExpr) -> Result<mir::Expression> {
         let expr = self.algebrize_expression(*a.expr, true)?;
         Ok(match *a.subfield {
@@ -1909,6 +2333,13 @@ impl<'a> Algebrizer<'a> {
             }};
         }
 
+        if let Some(format) = c.format {
+            return match c.to {
+                Date => self.algebrize_formatted_date_cast(*c.expr, format, c.on_null, c.on_error),
+                _ => Err(Error::InvalidCastFormat(c.to)),
+            };
+        }
+
         match c.to {
             BinData | DbPointer | Javascript | JavascriptWithScope | MaxKey | MinKey
             | RegularExpression | Symbol | Timestamp | Undefined | Date | Time => {
@@ -1934,6 +2365,34 @@ impl<'a> Algebrizer<'a> {
         }
     }
 
+    /// Algebrizes a CAST to DATE with a FORMAT into ParseDate, which evaluates
+    /// to null both when the string is null or missing and when it does not
+    /// match the format. If ON NULL or ON ERROR is given, both are passed on
+    /// to ParseDate so that the two cases can be told apart.
+    fn algebrize_formatted_date_cast(
+        &self,
+        expr: ast::Expression,
+        format: String,
+        on_null: Option<Box<ast::Expression>>,
+        on_error: Option<Box<ast::Expression>>,
+    ) -> Result<mir::Expression> {
+        let mut args = vec![
+            self.algebrize_expression(expr, true)?,
+            mir::Expression::Literal(mir::LiteralValue::String(format)),
+        ];
+        if on_null.is_some() || on_error.is_some() {
+            for arg in [on_null, on_error] {
+                args.push(match arg {
+                    Some(arg) => self.algebrize_expression(*arg, false)?,
+                    None => mir::Expression::Literal(mir::LiteralValue::Null),
+                });
+            }
+        }
+        Ok(mir::Expression::ScalarFunction(
+            mir::ScalarFunctionApplication::new(mir::ScalarFunction::ParseDate, args),
+        ))
+    }
+
     pub fn algebrize_subquery_expr(
         &self,
         ast_node: ast::Query,
@@ -2010,6 +2469,40 @@ impl<'a> Algebrizer<'a> {
         ))
     }
 
+    /// Lowers a quantified comparison over a list of values into a conjunction (for ALL)
+    /// or disjunction (for ANY) of comparisons between the argument and each value. Since
+    /// AND and OR follow SQL's three-valued logic, this yields the same NULL semantics as a
+    /// quantified subquery comparison: e.g. `x > ALL (1, NULL)` is FALSE if `x <= 1`, and
+    /// NULL otherwise.
+    pub fn algebrize_quantified_comparison(
+        &self,
+        q: ast::QuantifiedComparisonExpr,
+    ) -> Result<mir::Expression> {
+        let combinator = match q.quantifier {
+            ast::SubqueryQuantifier::All => ast::BinaryOp::And,
+            ast::SubqueryQuantifier::Any => ast::BinaryOp::Or,
+        };
+        let lowered = q
+            .values
+            .into_iter()
+            .map(|value| {
+                ast::Expression::Binary(ast::BinaryExpr {
+                    left: q.expr.clone(),
+                    op: ast::BinaryOp::Comparison(q.op),
+                    right: Box::new(value),
+                })
+            })
+            .reduce(|acc, comparison| {
+                ast::Expression::Binary(ast::BinaryExpr {
+                    left: Box::new(acc),
+                    op: combinator,
+                    right: Box::new(comparison),
+                })
+            })
+            .expect("quantified comparison value lists are never empty");
+        self.algebrize_expression(lowered, false)
+    }
+
     pub fn algebrize_exists(&self, ast_node: ast::Query) -> Result<mir::Expression> {
         let exists = self.subquery_algebrizer().algebrize_query(ast_node)?;
         Ok(mir::Expression::Exists(Box::new(exists).into()))
@@ -2032,14 +2525,14 @@ impl<'a> Algebrizer<'a> {
 
     fn algebrize_possibly_qualified_field_access(
         &self,
-        q: String,
+        q: ast::Identifier,
         field: String,
     ) -> Result<mir::Expression> {
         // clone the field here so that we only have to clone once.
         // The borrow checker still isn't perfect.
         let cloned_field = field.clone();
         // First we check if q is a qualifier
-        let possible_datasource = DatasourceName::from(q.clone());
+        let possible_datasource = DatasourceName::from(q.name.clone());
         // If there is a nearest_scope for `q`, then it must be a datasource, meaning this is a
         // qualified field access
         self.schema_env
@@ -2069,7 +2562,8 @@ impl<'a> Algebrizer<'a> {
             )
     }
 
-    fn algebrize_unqualified_identifier(&self, i: String) -> Result<mir::Expression> {
+    fn algebrize_unqualified_identifier(&self, i: ast::Identifier) -> Result<mir::Expression> {
+        let ast::Identifier { name: i, span } = i;
         // Attempt to find a datasource for this unqualified reference
         // at _any_ scope level.
         // If we find exactly one datasource that May or Must contain
@@ -2092,18 +2586,13 @@ impl<'a> Algebrizer<'a> {
                 .flat_map(|(_, s)| s.keys())
                 .collect::<Vec<_>>();
 
-            let err = if all_keys.is_empty() {
-                Error::FieldNotFound(i, None, *self.clause_type.borrow(), self.scope_level)
-            } else {
-                Error::FieldNotFound(
-                    i,
-                    Some(all_keys),
-                    *self.clause_type.borrow(),
-                    self.scope_level,
-                )
-            };
-
-            return Err(err);
+            return Err(Error::FieldNotFound {
+                field: i,
+                found_fields: (!all_keys.is_empty()).then_some(all_keys),
+                clause_type: *self.clause_type.borrow(),
+                scope_level: self.scope_level,
+                span,
+            });
         }
         // If there is exactly one possible datasource that May or Must
         // contain our reference, we use it.
@@ -2116,12 +2605,33 @@ impl<'a> Algebrizer<'a> {
 
         // Otherwise, we check datasources per scope, starting at the current scope,
         // to find the best datasource from multiple possible datasources.
-        self.algebrize_unqualified_identifier_by_scope(i, self.scope_level)
+        self.algebrize_unqualified_identifier_by_scope(i, span, self.scope_level)
+    }
+
+    /// Returns the datasources that a reference to field `i` at `scope` could
+    /// resolve to. A datasource that only May contain `i` does not shadow the
+    /// outer scopes, so when `include_outer_scopes` is set, the datasources at
+    /// outer scopes that may contain `i` are candidates too.
+    fn ambiguous_field_candidates(
+        &self,
+        i: &str,
+        scope: u16,
+        include_outer_scopes: bool,
+    ) -> Vec<DatasourceName> {
+        self.schema_env
+            .iter()
+            .filter(|(key, schema)| {
+                (key.scope == scope || (include_outer_scopes && key.scope < scope))
+                    && schema.contains_field(i) != Satisfaction::Not
+            })
+            .map(|(key, _)| key.datasource.clone())
+            .collect()
     }
 
     fn algebrize_unqualified_identifier_by_scope(
         &self,
         i: String,
+        span: Option<ast::Span>,
         scope_level: u16,
     ) -> Result<mir::Expression> {
         // When checking variables by scope, if a variable may exist, we treat that as ambiguous,
@@ -2190,18 +2700,22 @@ impl<'a> Algebrizer<'a> {
                     );
                 }
                 // if we have two May/Must datasources, and neither is bot, we return an ambiguous error
-                return Err(Error::AmbiguousField(
-                    i,
-                    *self.clause_type.borrow(),
-                    current_scope,
-                ));
+                return Err(Error::AmbiguousField {
+                    datasources: self.ambiguous_field_candidates(&i, current_scope, mays > 0),
+                    field: i,
+                    clause_type: *self.clause_type.borrow(),
+                    scope_level: current_scope,
+                    span,
+                });
             }
             if mays > 0 || musts > 1 {
-                return Err(Error::AmbiguousField(
-                    i,
-                    *self.clause_type.borrow(),
-                    current_scope,
-                ));
+                return Err(Error::AmbiguousField {
+                    datasources: self.ambiguous_field_candidates(&i, current_scope, mays > 0),
+                    field: i,
+                    clause_type: *self.clause_type.borrow(),
+                    scope_level: current_scope,
+                    span,
+                });
             }
 
             // Otherwise, the field does not exist in datasource of the current_scope.
@@ -17,8 +17,20 @@ pub enum Error {
     DistinctSelect,
     DistinctUnion,
     NoSuchDatasource(DatasourceName),
-    FieldNotFound(String, Option<Vec<String>>, ClauseType, u16),
-    AmbiguousField(String, ClauseType, u16),
+    FieldNotFound {
+        field: String,
+        found_fields: Option<Vec<String>>,
+        clause_type: ClauseType,
+        scope_level: u16,
+        span: Option<ast::Span>,
+    },
+    AmbiguousField {
+        field: String,
+        datasources: Vec<DatasourceName>,
+        clause_type: ClauseType,
+        scope_level: u16,
+        span: Option<ast::Span>,
+    },
     StarInNonCount,
     AggregationInPlaceOfScalar(String),
     ScalarInPlaceOfAggregation(String),
@@ -43,7 +55,14 @@ pub enum Error {
     NoUnwindPath,
     InvalidUnwindPath,
     InvalidCast(ast::Type),
-    InvalidSortKey(mir::Expression),
+    DistinctCountStar,
+    NegativeSubstringLength(i64),
+    NonDocumentSelectValue(crate::schema::Schema),
+    IncompatibleUnionBranches(String),
+    MisplacedInterval,
+    InvalidGroupConcatArguments,
+    InvalidCastFormat(ast::Type),
+    InvalidPositionStart(i64),
 }
 
 impl From<mir::schema::Error> for Error {
@@ -60,6 +79,16 @@ impl From<mir::Error> for Error {
     }
 }
 
+impl Error {
+    /// Returns the span of the query text this error refers to, if known.
+    pub fn span(&self) -> Option<ast::Span> {
+        match self {
+            Error::FieldNotFound { span, .. } | Error::AmbiguousField { span, .. } => *span,
+            _ => None,
+        }
+    }
+}
+
 impl UserError for Error {
     fn code(&self) -> u32 {
         match self {
@@ -68,8 +97,8 @@ impl UserError for Error {
             Error::DistinctSelect => 3005,
             Error::DistinctUnion => 3006,
             Error::NoSuchDatasource(_) => 3007,
-            Error::FieldNotFound(_, _, _, _) => 3008,
-            Error::AmbiguousField(_, _, _) => 3009,
+            Error::FieldNotFound { .. } => 3008,
+            Error::AmbiguousField { .. } => 3009,
             Error::StarInNonCount => 3010,
             Error::AggregationInPlaceOfScalar(_) => 3011,
             Error::ScalarInPlaceOfAggregation(_) => 3012,
@@ -89,7 +118,14 @@ impl UserError for Error {
             Error::NoUnwindPath => 3028,
             Error::InvalidUnwindPath => 3029,
             Error::InvalidCast(_) => 3030,
-            Error::InvalidSortKey(_) => 3034,
+            Error::DistinctCountStar => 3035,
+            Error::NegativeSubstringLength(_) => 3036,
+            Error::NonDocumentSelectValue(_) => 3037,
+            Error::IncompatibleUnionBranches(_) => 3038,
+            Error::MisplacedInterval => 3039,
+            Error::InvalidGroupConcatArguments => 3040,
+            Error::InvalidCastFormat(_) => 3041,
+            Error::InvalidPositionStart(_) => 3042,
         }
     }
 
@@ -100,7 +136,13 @@ impl UserError for Error {
             Error::DistinctSelect => None,
             Error::DistinctUnion => None,
             Error::NoSuchDatasource(_) => None,
-            Error::FieldNotFound(field, found_fields, clause_type, scope_level) => {
+            Error::FieldNotFound {
+                field,
+                found_fields,
+                clause_type,
+                scope_level,
+                ..
+            } => {
                 if let Some(possible_fields) = found_fields {
                     let suggestions = generate_suggestion(field, possible_fields);
                     match suggestions {
@@ -133,10 +175,35 @@ impl UserError for Error {
                     ))
                 }
             }
-            Error::AmbiguousField(field, clause_type, scope_level) => Some(format!(
-                "Field `{}` in the `{}` clause at the {} scope level exists in multiple datasources and is ambiguous. Please qualify.",
-                field, clause_type, scope_level
-            )),
+            Error::AmbiguousField {
+                field,
+                datasources,
+                clause_type,
+                scope_level,
+                ..
+            } => {
+                let qualified_fields = datasources
+                    .iter()
+                    .filter_map(|datasource| match datasource {
+                        DatasourceName::Named(name) => Some(format!("`{name}.{field}`")),
+                        DatasourceName::Bottom => None,
+                    })
+                    .collect::<Vec<_>>();
+                if qualified_fields.is_empty() {
+                    Some(format!(
+                        "Field `{}` in the `{}` clause at the {} scope level exists in multiple datasources and is ambiguous. Please qualify.",
+                        field, clause_type, scope_level
+                    ))
+                } else {
+                    Some(format!(
+                        "Field `{}` in the `{}` clause at the {} scope level exists in multiple datasources and is ambiguous. Please qualify as one of: {}.",
+                        field,
+                        clause_type,
+                        scope_level,
+                        qualified_fields.join(", ")
+                    ))
+                }
+            }
             Error::StarInNonCount => None,
             Error::AggregationInPlaceOfScalar(_) => None,
             Error::ScalarInPlaceOfAggregation(_) => None,
@@ -176,9 +243,16 @@ impl UserError for Error {
             Error::NoUnwindPath => None,
             Error::InvalidUnwindPath => None,
             Error::InvalidCast(_) => None,
-            Error::InvalidSortKey(_) => {
-                Some("expressions are not allowed in sort key field paths".to_string())
-            }
+            Error::DistinctCountStar => None,
+            Error::NegativeSubstringLength(_) => None,
+            Error::NonDocumentSelectValue(_) => Some(
+                "SELECT VALUE expressions must be documents. Wrap scalar values in a document, e.g. SELECT VALUE {'a': a}".to_string(),
+            ),
+            Error::IncompatibleUnionBranches(_) => None,
+            Error::MisplacedInterval => None,
+            Error::InvalidGroupConcatArguments => None,
+            Error::InvalidCastFormat(_) => None,
+            Error::InvalidPositionStart(_) => None,
         }
     }
 
@@ -187,10 +261,10 @@ impl UserError for Error {
             Error::NonStarStandardSelectBody => "standard SELECT expressions can only contain *".to_string(),
             Error::ArrayDatasourceMustBeLiteral => "array datasource must be constant".to_string(),
             Error::DistinctSelect => "SELECT DISTINCT not allowed".to_string(),
-            Error::DistinctUnion => "UNION DISTINCT not allowed".to_string(),
+            Error::DistinctUnion => "UNION DISTINCT requires every field of every datasource to be known".to_string(),
             Error::NoSuchDatasource(datasource_name) => format!("no such datasource: {0:?}", datasource_name),
-            Error::FieldNotFound(field, _, clause_type, scope_level) => format!("field `{}` in the `{}` clause at the {} scope level cannot be resolved to any datasource", field, clause_type, scope_level),
-            Error::AmbiguousField(field, clause_type, scope_level) => format!("ambiguous field `{}` in the `{}` clause at the {} scope level", field, clause_type, scope_level),
+            Error::FieldNotFound { field, clause_type, scope_level, .. } => format!("field `{}` in the `{}` clause at the {} scope level cannot be resolved to any datasource", field, clause_type, scope_level),
+            Error::AmbiguousField { field, datasources, clause_type, scope_level, .. } => format!("ambiguous field `{}` in the `{}` clause at the {} scope level, found in datasources {:?}", field, clause_type, scope_level, datasources),
             Error::StarInNonCount => "* argument only valid in COUNT function".to_string(),
             Error::AggregationInPlaceOfScalar(func) => format!("aggregation function {0} used in scalar position", func),
             Error::ScalarInPlaceOfAggregation(func) => format!("scalar function {0} used in aggregation position", func),
@@ -210,10 +284,14 @@ impl UserError for Error {
             Error::NoUnwindPath => "UNWIND must specify a PATH option".to_string(),
             Error::InvalidUnwindPath => "UNWIND PATH option must be an identifier".to_string(),
             Error::InvalidCast(ast_type) => format!("invalid CAST target type '{0:?}'", ast_type),
-            Error::InvalidSortKey(e) =>
-                format!("sort key field path must be a pure field path with no expressions in this context. found {0:?}",
-                    e
-                ),
+            Error::DistinctCountStar => "DISTINCT is not allowed with the * argument to COUNT".to_string(),
+            Error::NegativeSubstringLength(len) => format!("SUBSTRING length must not be negative, found {len}"),
+            Error::NonDocumentSelectValue(schema) => format!("SELECT VALUE expressions must be documents, found {0:?}", schema),
+            Error::IncompatibleUnionBranches(mismatches) => format!("UNION ALL branches are not compatible: {0}", mismatches),
+            Error::MisplacedInterval => "INTERVAL may only be added to or subtracted from a date".to_string(),
+            Error::InvalidGroupConcatArguments => "GROUP_CONCAT must have a value argument and an optional string literal separator".to_string(),
+            Error::InvalidCastFormat(ast_type) => format!("FORMAT is only supported in a CAST to DATE, found CAST target type '{0:?}'", ast_type),
+            Error::InvalidPositionStart(start) => format!("POSITION start must be at least 1, found {start}"),
         }
     }
 }
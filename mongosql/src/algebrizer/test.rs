@@ -84,7 +84,10 @@ macro_rules! test_user_error_messages {
         #[test]
         fn $func_name() {
             #[allow(unused_imports)]
-            use crate::{algebrizer::ClauseType, algebrizer::Error, usererror::UserError};
+            use crate::{
+                algebrizer::ClauseType, algebrizer::Error, mir::binding_tuple::DatasourceName,
+                usererror::UserError,
+            };
 
             let user_message = $input.user_message();
 
@@ -181,7 +184,10 @@ lazy_static! {
 mod expression {
     use crate::{
         ast, map,
-        mir::{self, binding_tuple::Key},
+        mir::{
+            self,
+            binding_tuple::{DatasourceName, Key},
+        },
         multimap,
         schema::{
             Atomic, Document, Schema, BOOLEAN_OR_NULLISH, DATE_OR_NULLISH, NUMERIC_OR_NULLISH,
@@ -784,11 +790,16 @@ mod expression {
             unqualified_ref_must_exist_in_two_non_bot_sources,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Err(Error::AmbiguousField(
-                "a".into(),
-                ClauseType::Unintialized,
-                1u16
-            )),
+            expected = Err(Error::AmbiguousField {
+                field: "a".into(),
+                datasources: vec![
+                    DatasourceName::Named("bar".into()),
+                    DatasourceName::Named("foo".into())
+                ],
+                clause_type: ClauseType::Unintialized,
+                scope_level: 1u16,
+                span: None,
+            }),
             expected_error_code = 3009,
             input = ast::Expression::Identifier("a".into()),
             env = map! {
@@ -811,6 +822,50 @@ mod expression {
             },
         );
 
+        test_algebrize!(
+            unqualified_ref_may_exist_in_one_of_two_non_bot_sources,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::AmbiguousField {
+                field: "a".into(),
+                datasources: vec![
+                    DatasourceName::Named("bar".into()),
+                    DatasourceName::Named("foo".into())
+                ],
+                clause_type: ClauseType::Unintialized,
+                scope_level: 1u16,
+                span: None,
+            }),
+            expected_error_code = 3009,
+            input = ast::Expression::Identifier("a".into()),
+            env = map! {
+                ("foo", 1u16).into() => Schema::Document( Document {
+                    keys: map! {
+                        "a".into() => Schema::Atomic(Atomic::Integer),
+                    },
+                    required: set!{"a".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+                ("bar", 1u16).into() => Schema::Document( Document {
+                    keys: map! {
+                        "a".into() => Schema::Atomic(Atomic::Integer),
+                    },
+                    required: set!{},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+                ("baz", 1u16).into() => Schema::Document( Document {
+                    keys: map! {
+                        "b".into() => Schema::Atomic(Atomic::Integer),
+                    },
+                    required: set!{"b".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
+        );
+
         test_algebrize!(
             unqualified_subpath_in_current_and_super_must_exist_in_current,
             method = algebrize_expression,
@@ -862,11 +917,16 @@ mod expression {
             unqualified_subpath_in_current_and_super_may_exist_is_ambiguous,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Err(Error::AmbiguousField(
-                "a".into(),
-                ClauseType::Unintialized,
-                1u16
-            )),
+            expected = Err(Error::AmbiguousField {
+                field: "a".into(),
+                datasources: vec![
+                    DatasourceName::Named("super_test".into()),
+                    DatasourceName::Named("test".into())
+                ],
+                clause_type: ClauseType::Unintialized,
+                scope_level: 1u16,
+                span: None,
+            }),
             expected_error_code = 3009,
             input = ast::Expression::Subpath(ast::SubpathExpr {
                 expr: Box::new(ast::Expression::Identifier("a".into())),
@@ -1067,11 +1127,16 @@ mod expression {
             unqualified_reference_and_may_contain_sub_and_must_contain_outer_is_ambiguous,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Err(Error::AmbiguousField(
-                "a".into(),
-                ClauseType::Unintialized,
-                1u16
-            )),
+            expected = Err(Error::AmbiguousField {
+                field: "a".into(),
+                datasources: vec![
+                    DatasourceName::Named("super_test".into()),
+                    DatasourceName::Named("test".into())
+                ],
+                clause_type: ClauseType::Unintialized,
+                scope_level: 1u16,
+                span: None,
+            }),
             expected_error_code = 3009,
             input = ast::Expression::Subpath(ast::SubpathExpr {
                 expr: Box::new(ast::Expression::Identifier("a".into())),
@@ -1111,12 +1176,13 @@ mod expression {
             ref_does_not_exist,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Err(Error::FieldNotFound(
-                "bar".into(),
-                None,
-                ClauseType::Unintialized,
-                0u16
-            )),
+            expected = Err(Error::FieldNotFound {
+                field: "bar".into(),
+                found_fields: None,
+                clause_type: ClauseType::Unintialized,
+                scope_level: 0u16,
+                span: None,
+            }),
             expected_error_code = 3008,
             input = ast::Expression::Identifier("bar".into()),
         );
@@ -1146,6 +1212,69 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            add_field_references,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Add,
+                    args: vec![
+                        mir::Expression::FieldAccess(mir::FieldAccess {
+                            expr: Box::new(mir::Expression::Reference(("foo", 1u16).into())),
+                            field: "a".into(),
+                            is_nullable: false,
+                        }),
+                        mir::Expression::FieldAccess(mir::FieldAccess {
+                            expr: Box::new(mir::Expression::Reference(("foo", 1u16).into())),
+                            field: "b".into(),
+                            is_nullable: false,
+                        }),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Identifier("a".into())),
+                op: ast::BinaryOp::Add,
+                right: Box::new(ast::Expression::Identifier("b".into())),
+            }),
+            env = map! {
+                ("foo", 1u16).into() => Schema::Document(Document {
+                    keys: map! {
+                        "a".into() => Schema::Atomic(Atomic::Integer),
+                        "b".into() => Schema::Atomic(Atomic::Double),
+                    },
+                    required: set! {"a".into(), "b".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            add_field_references_schema,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(Schema::Atomic(Atomic::Double)),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Identifier("a".into())),
+                op: ast::BinaryOp::Add,
+                right: Box::new(ast::Expression::Identifier("b".into())),
+            }),
+            env = map! {
+                ("foo", 1u16).into() => Schema::Document(Document {
+                    keys: map! {
+                        "a".into() => Schema::Atomic(Atomic::Integer),
+                        "b".into() => Schema::Atomic(Atomic::Double),
+                    },
+                    required: set! {"a".into(), "b".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
+        );
+
         test_algebrize_expr_and_schema_check!(
             add_wrong_types,
             method = algebrize_expression,
@@ -1295,6 +1424,7 @@ mod expression {
                     "{\"$numberInt\": \"42\"}".to_string()
                 )),
                 to: ast::Type::String,
+                format: None,
                 on_null: Some(Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 ))),
@@ -1321,6 +1451,27 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            mod_bin_op,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Mod,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Integer(42)),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(5)),
+                    ],
+                    is_nullable: true,
+                }
+            )),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
+                op: ast::BinaryOp::Mod,
+                right: Box::new(ast::Expression::Literal(ast::Literal::Integer(5))),
+            }),
+        );
+
         test_algebrize!(
             mul_bin_op,
             method = algebrize_expression,
@@ -1712,7 +1863,7 @@ mod expression {
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
                 op: ast::BinaryOp::Comparison(ast::ComparisonOp::Gt),
-                right: Box::new(ast::Expression::Identifier("a".to_string())),
+                right: Box::new(ast::Expression::Identifier("a".into())),
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -1751,7 +1902,7 @@ mod expression {
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
                 op: ast::BinaryOp::Comparison(ast::ComparisonOp::Lt),
-                right: Box::new(ast::Expression::Identifier("a".to_string())),
+                right: Box::new(ast::Expression::Identifier("a".into())),
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -1784,7 +1935,7 @@ mod expression {
                 }
             )),
             input = ast::Expression::Binary(ast::BinaryExpr {
-                left: Box::new(ast::Expression::Identifier("a".to_string())),
+                left: Box::new(ast::Expression::Identifier("a".into())),
                 op: ast::BinaryOp::Comparison(ast::ComparisonOp::Gte),
                 right: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
@@ -1823,7 +1974,7 @@ mod expression {
                 }
             )),
             input = ast::Expression::Binary(ast::BinaryExpr {
-                left: Box::new(ast::Expression::Identifier("a".to_string())),
+                left: Box::new(ast::Expression::Identifier("a".into())),
                 op: ast::BinaryOp::Comparison(ast::ComparisonOp::Lte),
                 right: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
@@ -1953,6 +2104,7 @@ mod expression {
                 arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
                 min: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
                 max: Box::new(ast::Expression::Literal(ast::Literal::Integer(3))),
+                symmetric: false,
             }),
         );
 
@@ -1987,6 +2139,7 @@ mod expression {
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
+                symmetric: false,
             }),
         );
 
@@ -2016,7 +2169,8 @@ mod expression {
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
-                max: Box::new(ast::Expression::Identifier("a".to_string())),
+                max: Box::new(ast::Expression::Identifier("a".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2060,7 +2214,8 @@ mod expression {
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
-                max: Box::new(ast::Expression::Identifier("a".to_string())),
+                max: Box::new(ast::Expression::Identifier("a".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2097,10 +2252,11 @@ mod expression {
                 arg: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                min: Box::new(ast::Expression::Identifier("a".to_string())),
+                min: Box::new(ast::Expression::Identifier("a".into())),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2141,10 +2297,11 @@ mod expression {
                 arg: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                min: Box::new(ast::Expression::Identifier("a".to_string())),
+                min: Box::new(ast::Expression::Identifier("a".into())),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2178,13 +2335,14 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2222,13 +2380,14 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"2\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2269,8 +2428,9 @@ mod expression {
                 arg: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                min: Box::new(ast::Expression::Identifier("a".to_string())),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                min: Box::new(ast::Expression::Identifier("a".into())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2312,8 +2472,9 @@ mod expression {
                 arg: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                min: Box::new(ast::Expression::Identifier("a".to_string())),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                min: Box::new(ast::Expression::Identifier("a".into())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2357,8 +2518,9 @@ mod expression {
                 arg: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                min: Box::new(ast::Expression::Identifier("a".to_string())),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                min: Box::new(ast::Expression::Identifier("a".into())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2397,11 +2559,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2440,11 +2603,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2485,11 +2649,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
                 min: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
-                max: Box::new(ast::Expression::Identifier("b".to_string())),
+                max: Box::new(ast::Expression::Identifier("b".into())),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2528,11 +2693,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
-                min: Box::new(ast::Expression::Identifier("b".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
+                min: Box::new(ast::Expression::Identifier("b".into())),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2571,11 +2737,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
-                min: Box::new(ast::Expression::Identifier("b".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
+                min: Box::new(ast::Expression::Identifier("b".into())),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2616,11 +2783,12 @@ mod expression {
                 }
             )),
             input = ast::Expression::Between(ast::BetweenExpr {
-                arg: Box::new(ast::Expression::Identifier("a".to_string())),
-                min: Box::new(ast::Expression::Identifier("b".to_string())),
+                arg: Box::new(ast::Expression::Identifier("a".into())),
+                min: Box::new(ast::Expression::Identifier("b".into())),
                 max: Box::new(ast::Expression::StringConstructor(
                     "{\"$numberInt\": \"1\"}".to_string()
                 )),
+                symmetric: false,
             }),
             env = map! {
                 ("foo", 1u16).into() => Schema::Document( Document {
@@ -2636,90 +2804,388 @@ mod expression {
         );
     }
 
-    mod scalar_function {
+    mod between_symmetric {
         use super::*;
 
         test_algebrize!(
-            standard_scalar_function,
+            symmetric_desugars_to_disjunction_of_both_orderings,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Lower,
-                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
-                        "hello".into()
-                    )),],
+                    function: mir::ScalarFunction::Or,
+                    args: vec![
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::And,
+                            args: vec![
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Gte,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Lte,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                            ],
+                            is_nullable: false,
+                        }),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::And,
+                            args: vec![
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Gte,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Lte,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                            ],
+                            is_nullable: false,
+                        }),
+                    ],
                     is_nullable: false,
                 }
             )),
-            input = ast::Expression::Function(ast::FunctionExpr {
-                function: ast::FunctionName::Lower,
-                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
-                    "hello".into()
-                )]),
-                set_quantifier: Some(ast::SetQuantifier::All),
+            input = ast::Expression::Between(ast::BetweenExpr {
+                arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                min: Box::new(ast::Expression::Literal(ast::Literal::Integer(3))),
+                max: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
+                symmetric: true,
             }),
         );
 
-        test_algebrize!(
-            lower_scalar_function_does_not_implicit_convert_ext_json,
+        test_algebrize_expr_and_schema_check!(
+            symmetric_incomparable_bound_is_error,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Ok(mir::Expression::ScalarFunction(
-                mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Lower,
-                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
-                        "{\"$numberInt\": \"1\"}".into()
-                    )),],
-                    is_nullable: false,
-                }
+            expected = Err(Error::SchemaChecking(
+                mir::schema::Error::InvalidComparison(
+                    "Lte",
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::String),
+                )
             )),
-            input = ast::Expression::Function(ast::FunctionExpr {
-                function: ast::FunctionName::Lower,
-                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
-                    "{\"$numberInt\": \"1\"}".into()
-                )]),
-                set_quantifier: None,
+            input = ast::Expression::Between(ast::BetweenExpr {
+                arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                min: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                max: Box::new(ast::Expression::StringConstructor("abc".to_string())),
+                symmetric: true,
             }),
         );
 
+        #[test]
+        fn symmetric_and_non_symmetric_algebrize_differently() {
+            use crate::{
+                algebrizer::{Algebrizer, ClauseType},
+                catalog::Catalog,
+                SchemaCheckingMode,
+            };
+
+            let catalog = Catalog::default();
+            let algebrizer = Algebrizer::new(
+                "test",
+                &catalog,
+                0u16,
+                SchemaCheckingMode::Strict,
+                false,
+                ClauseType::Unintialized,
+            );
+            let between = |symmetric| {
+                ast::Expression::Between(ast::BetweenExpr {
+                    arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                    min: Box::new(ast::Expression::Literal(ast::Literal::Integer(3))),
+                    max: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
+                    symmetric,
+                })
+            };
+            assert_ne!(
+                algebrizer.algebrize_expression(between(false), false),
+                algebrizer.algebrize_expression(between(true), false),
+            );
+        }
+    }
+
+    mod quantified_comparison {
+        use super::*;
+
         test_algebrize!(
-            replace,
+            gt_all_list_lowers_to_conjunction,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Replace,
+                    function: mir::ScalarFunction::And,
                     args: vec![
-                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String("wo".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String("wowow".into())),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::And,
+                            args: vec![
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Gt,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Gt,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                            ],
+                            is_nullable: false,
+                        }),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Gt,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                            ],
+                            is_nullable: false,
+                        }),
                     ],
                     is_nullable: false,
                 }
             )),
-            input = ast::Expression::Function(ast::FunctionExpr {
-                function: ast::FunctionName::Replace,
-                args: ast::FunctionArguments::Args(vec![
-                    ast::Expression::StringConstructor(" hello world ".to_string()),
-                    ast::Expression::StringConstructor("wo".to_string()),
-                    ast::Expression::StringConstructor("wowow".to_string()),
-                ]),
-                set_quantifier: None,
+            input = ast::Expression::QuantifiedComparison(ast::QuantifiedComparisonExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                op: ast::ComparisonOp::Gt,
+                quantifier: ast::SubqueryQuantifier::All,
+                values: vec![
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Literal(ast::Literal::Integer(2)),
+                    ast::Expression::Literal(ast::Literal::Integer(3)),
+                ],
             }),
         );
 
         test_algebrize!(
-            replace_null_one,
+            eq_any_list_lowers_to_disjunction,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Replace,
+                    function: mir::ScalarFunction::Or,
                     args: vec![
-                        mir::Expression::Literal(mir::LiteralValue::Null),
-                        mir::Expression::Literal(mir::LiteralValue::String("wo".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String("wowow".into())),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Eq,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                            ],
+                            is_nullable: false,
+                        }),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Eq,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                            ],
+                            is_nullable: false,
+                        }),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::QuantifiedComparison(ast::QuantifiedComparisonExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                op: ast::ComparisonOp::Eq,
+                quantifier: ast::SubqueryQuantifier::Any,
+                values: vec![
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Literal(ast::Literal::Integer(4)),
+                ],
+            }),
+        );
+
+        test_algebrize!(
+            any_list_with_null_value_is_nullable,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Or,
+                    args: vec![
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Lt,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                            ],
+                            is_nullable: false,
+                        }),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Lt,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                                mir::Expression::Literal(mir::LiteralValue::Null),
+                            ],
+                            is_nullable: true,
+                        }),
+                    ],
+                    is_nullable: true,
+                }
+            )),
+            input = ast::Expression::QuantifiedComparison(ast::QuantifiedComparisonExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                op: ast::ComparisonOp::Lt,
+                quantifier: ast::SubqueryQuantifier::Any,
+                values: vec![
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Literal(ast::Literal::Null),
+                ],
+            }),
+        );
+
+        test_algebrize!(
+            single_value_list_lowers_to_comparison,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Neq,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Integer(4)),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::QuantifiedComparison(ast::QuantifiedComparisonExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                op: ast::ComparisonOp::Neq,
+                quantifier: ast::SubqueryQuantifier::All,
+                values: vec![ast::Expression::Literal(ast::Literal::Integer(1))],
+            }),
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            incomparable_value_in_list_is_error,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::SchemaChecking(
+                mir::schema::Error::InvalidComparison(
+                    "Gt",
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::String),
+                )
+            )),
+            input = ast::Expression::QuantifiedComparison(ast::QuantifiedComparisonExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(4))),
+                op: ast::ComparisonOp::Gt,
+                quantifier: ast::SubqueryQuantifier::All,
+                values: vec![
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::StringConstructor("abc".to_string()),
+                ],
+            }),
+        );
+    }
+
+    mod scalar_function {
+        use super::*;
+
+        test_algebrize!(
+            standard_scalar_function,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Lower,
+                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
+                        "hello".into()
+                    )),],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Lower,
+                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
+                    "hello".into()
+                )]),
+                set_quantifier: Some(ast::SetQuantifier::All),
+            }),
+        );
+
+        test_algebrize!(
+            lower_scalar_function_does_not_implicit_convert_ext_json,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Lower,
+                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
+                        "{\"$numberInt\": \"1\"}".into()
+                    )),],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Lower,
+                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
+                    "{\"$numberInt\": \"1\"}".into()
+                )]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            replace,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Replace,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("wo".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("wowow".into())),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Replace,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor(" hello world ".to_string()),
+                    ast::Expression::StringConstructor("wo".to_string()),
+                    ast::Expression::StringConstructor("wowow".to_string()),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            replace_null_one,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Replace,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Null),
+                        mir::Expression::Literal(mir::LiteralValue::String("wo".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("wowow".into())),
                     ],
                     is_nullable: true,
                 }
@@ -3448,6 +3914,137 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            instr_swaps_string_args_and_implicit_converts_start,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Position,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("b".to_string())),
+                        mir::Expression::Literal(mir::LiteralValue::String(
+                            "{\"$numberInt\": \"1\"}".to_string()
+                        )),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Instr,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("{\"$numberInt\": \"1\"}".to_string()),
+                    ast::Expression::StringConstructor("b".to_string()),
+                    ast::Expression::StringConstructor("{\"$numberInt\": \"1\"}".to_string()),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            substring_literal_negative_length_is_error,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::NegativeSubstringLength(-1)),
+            expected_error_code = 3036,
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Substring,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("hello".to_string()),
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Unary(ast::UnaryExpr {
+                        op: ast::UnaryOp::Neg,
+                        expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                    }),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            substring_bare_negative_literal_length_is_error,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::NegativeSubstringLength(-1)),
+            expected_error_code = 3036,
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Substring,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("hello".to_string()),
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Literal(ast::Literal::Integer(-1)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            substring_default_length_is_allowed,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Substring,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("hello".to_string())),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(-1)),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Substring,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("hello".to_string()),
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::DefaultArgument(ast::Literal::Integer(-1)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            substring_dynamic_length_is_allowed,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Substring,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("hello".to_string())),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                        mir::Expression::FieldAccess(mir::FieldAccess {
+                            expr: Box::new(mir::Expression::Reference(("foo", 1u16).into())),
+                            field: "a".into(),
+                            is_nullable: false,
+                        }),
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Substring,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("hello".to_string()),
+                    ast::Expression::Literal(ast::Literal::Integer(1)),
+                    ast::Expression::Identifier("a".into()),
+                ]),
+                set_quantifier: None,
+            }),
+            env = map! {
+                ("foo", 1u16).into() => Schema::Document( Document {
+                    keys: map! {
+                        "a".into() => Schema::Atomic(Atomic::Integer),
+                    },
+                    required: set!{"a".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
+        );
+
         test_algebrize!(
             nullif_two_strings_does_not_implicit_convert_ext_json,
             method = algebrize_expression,
@@ -3570,6 +4167,96 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            ifnull_is_coalesce,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication::new(
+                    mir::ScalarFunction::Coalesce,
+                    vec![
+                        mir::Expression::Literal(mir::LiteralValue::Null),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                    ],
+                )
+            ),),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::IfNull,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::Literal(ast::Literal::Null),
+                    ast::Expression::Literal(ast::Literal::Integer(2)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            isnull_is_coalesce,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication::new(
+                    mir::ScalarFunction::Coalesce,
+                    vec![
+                        mir::Expression::Literal(mir::LiteralValue::Null),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                    ],
+                )
+            ),),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::IsNull,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::Literal(ast::Literal::Null),
+                    ast::Expression::Literal(ast::Literal::Integer(2)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            ifnull_requires_two_args,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::SchemaChecking(
+                mir::schema::Error::IncorrectArgumentCount {
+                    name: "IFNULL",
+                    required: 2,
+                    found: 3,
+                }
+            )),
+            expected_error_code = 1001,
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::IfNull,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::Literal(ast::Literal::Null),
+                    ast::Expression::Literal(ast::Literal::Integer(2)),
+                    ast::Expression::Literal(ast::Literal::Integer(3)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            isnull_requires_two_args,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::SchemaChecking(
+                mir::schema::Error::IncorrectArgumentCount {
+                    name: "ISNULL",
+                    required: 2,
+                    found: 1,
+                }
+            )),
+            expected_error_code = 1001,
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::IsNull,
+                args: ast::FunctionArguments::Args(vec![ast::Expression::Literal(
+                    ast::Literal::Null
+                )]),
+                set_quantifier: None,
+            }),
+        );
+
         test_algebrize!(
             size_unary_op,
             method = algebrize_expression,
@@ -3888,115 +4575,315 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            position_start_is_one_indexed,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Add,
+                    args: vec![
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::Position,
+                            args: vec![
+                                mir::Expression::Literal(mir::LiteralValue::String("b".into())),
+                                mir::Expression::Literal(mir::LiteralValue::String("abc".into())),
+                                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                                    function: mir::ScalarFunction::Sub,
+                                    args: vec![
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                    ],
+                                    is_nullable: false,
+                                }),
+                            ],
+                            is_nullable: false,
+                        }),
+                        mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    ],
+                    is_nullable: false
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Position,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("b".into()),
+                    ast::Expression::StringConstructor("abc".into()),
+                    ast::Expression::Literal(ast::Literal::Integer(2)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            position_start_below_one_is_error,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::InvalidPositionStart(0)),
+            expected_error_code = 3042,
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Position,
+                args: ast::FunctionArguments::Args(vec![
+                    ast::Expression::StringConstructor("b".into()),
+                    ast::Expression::StringConstructor("abc".into()),
+                    ast::Expression::Literal(ast::Literal::Integer(0)),
+                ]),
+                set_quantifier: None,
+            }),
+        );
+
+        #[test]
+        fn position_in_and_comma_forms_algebrize_identically() {
+            use crate::{
+                algebrizer::{Algebrizer, ClauseType},
+                catalog::Catalog,
+                parser::parse_expression,
+                SchemaCheckingMode,
+            };
+
+            let catalog = Catalog::default();
+            let algebrizer = Algebrizer::new(
+                "test",
+                &catalog,
+                0u16,
+                SchemaCheckingMode::Strict,
+                false,
+                ClauseType::Unintialized,
+            );
+            let in_form = algebrizer
+                .algebrize_expression(parse_expression("position('b' IN 'abc')").unwrap(), false)
+                .unwrap();
+            let comma_form = algebrizer
+                .algebrize_expression(parse_expression("position('b', 'abc')").unwrap(), false)
+                .unwrap();
+            assert_eq!(in_form, comma_form);
+            assert_eq!(
+                in_form.schema(&algebrizer.schema_inference_state()),
+                comma_form.schema(&algebrizer.schema_inference_state()),
+            );
+        }
+
         test_algebrize!(
             upper_unary_op,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Upper,
-                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
-                        "hello".to_string()
-                    )),],
-                    is_nullable: false
+                    function: mir::ScalarFunction::Upper,
+                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
+                        "hello".to_string()
+                    )),],
+                    is_nullable: false
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Upper,
+                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
+                    "hello".to_string()
+                ),]),
+                set_quantifier: None,
+            }),
+        );
+
+        test_algebrize!(
+            upper_unary_op_does_not_implicit_convert_ext_json,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::Upper,
+                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
+                        "{\"$numberInt\":\"1\"}".to_string()
+                    )),],
+                    is_nullable: false
+                }
+            )),
+            input = ast::Expression::Function(ast::FunctionExpr {
+                function: ast::FunctionName::Upper,
+                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
+                    "{\"$numberInt\":\"1\"}".to_string()
+                ),]),
+                set_quantifier: None,
+            }),
+        );
+    }
+
+    mod trim {
+        use super::*;
+
+        test_algebrize!(
+            ltrim,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::LTrim,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("hello".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("hello world".into()))
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Trim(ast::TrimExpr {
+                trim_spec: ast::TrimSpec::Leading,
+                trim_chars: Some(Box::new(ast::Expression::StringConstructor("hello".into()))),
+                arg: Box::new(ast::Expression::StringConstructor("hello world".into())),
+            }),
+        );
+
+        test_algebrize!(
+            rtrim,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::RTrim,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("world".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("hello world".into()))
+                    ],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::Trim(ast::TrimExpr {
+                trim_spec: ast::TrimSpec::Trailing,
+                trim_chars: Some(Box::new(ast::Expression::StringConstructor("world".into()))),
+                arg: Box::new(ast::Expression::StringConstructor("hello world".into())),
+            }),
+        );
+
+        test_algebrize!(
+            btrim,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::BTrim,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into()))
+                    ],
+                    is_nullable: false,
                 }
             )),
-            input = ast::Expression::Function(ast::FunctionExpr {
-                function: ast::FunctionName::Upper,
-                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
-                    "hello".to_string()
-                ),]),
-                set_quantifier: None,
+            input = ast::Expression::Trim(ast::TrimExpr {
+                trim_spec: ast::TrimSpec::Both,
+                trim_chars: Some(Box::new(ast::Expression::StringConstructor(" ".into()))),
+                arg: Box::new(ast::Expression::StringConstructor(" hello world ".into())),
             }),
         );
 
         test_algebrize!(
-            upper_unary_op_does_not_implicit_convert_ext_json,
+            trim_without_chars_defaults_to_space,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
-                    function: mir::ScalarFunction::Upper,
-                    args: vec![mir::Expression::Literal(mir::LiteralValue::String(
-                        "{\"$numberInt\":\"1\"}".to_string()
-                    )),],
-                    is_nullable: false
+                    function: mir::ScalarFunction::BTrim,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into()))
+                    ],
+                    is_nullable: false,
                 }
             )),
-            input = ast::Expression::Function(ast::FunctionExpr {
-                function: ast::FunctionName::Upper,
-                args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
-                    "{\"$numberInt\":\"1\"}".to_string()
-                ),]),
-                set_quantifier: None,
+            input = ast::Expression::Trim(ast::TrimExpr {
+                trim_spec: ast::TrimSpec::Both,
+                trim_chars: None,
+                arg: Box::new(ast::Expression::StringConstructor(" hello world ".into())),
             }),
         );
-    }
-
-    mod trim {
-        use super::*;
 
         test_algebrize!(
-            ltrim,
+            leading_trim_without_chars_defaults_to_space,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
                     function: mir::ScalarFunction::LTrim,
                     args: vec![
-                        mir::Expression::Literal(mir::LiteralValue::String("hello".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String("hello world".into()))
+                        mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into()))
                     ],
                     is_nullable: false,
                 }
             )),
             input = ast::Expression::Trim(ast::TrimExpr {
                 trim_spec: ast::TrimSpec::Leading,
-                trim_chars: Box::new(ast::Expression::StringConstructor("hello".into())),
-                arg: Box::new(ast::Expression::StringConstructor("hello world".into())),
+                trim_chars: None,
+                arg: Box::new(ast::Expression::StringConstructor(" hello world ".into())),
             }),
         );
 
         test_algebrize!(
-            rtrim,
+            trailing_trim_without_chars_defaults_to_space,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
                     function: mir::ScalarFunction::RTrim,
                     args: vec![
-                        mir::Expression::Literal(mir::LiteralValue::String("world".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String("hello world".into()))
+                        mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into()))
                     ],
                     is_nullable: false,
                 }
             )),
             input = ast::Expression::Trim(ast::TrimExpr {
                 trim_spec: ast::TrimSpec::Trailing,
-                trim_chars: Box::new(ast::Expression::StringConstructor("world".into())),
-                arg: Box::new(ast::Expression::StringConstructor("hello world".into())),
+                trim_chars: None,
+                arg: Box::new(ast::Expression::StringConstructor(" hello world ".into())),
             }),
         );
 
         test_algebrize!(
-            btrim,
+            trim_with_custom_chars,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
             expected = Ok(mir::Expression::ScalarFunction(
                 mir::ScalarFunctionApplication {
                     function: mir::ScalarFunction::BTrim,
                     args: vec![
-                        mir::Expression::Literal(mir::LiteralValue::String(" ".into())),
-                        mir::Expression::Literal(mir::LiteralValue::String(" hello world ".into()))
+                        mir::Expression::Literal(mir::LiteralValue::String("ab".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("abhelloba".into()))
                     ],
                     is_nullable: false,
                 }
             )),
             input = ast::Expression::Trim(ast::TrimExpr {
                 trim_spec: ast::TrimSpec::Both,
-                trim_chars: Box::new(ast::Expression::StringConstructor(" ".into())),
-                arg: Box::new(ast::Expression::StringConstructor(" hello world ".into())),
+                trim_chars: Some(Box::new(ast::Expression::StringConstructor("ab".into()))),
+                arg: Box::new(ast::Expression::StringConstructor("abhelloba".into())),
+            }),
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            trim_without_chars_may_be_null,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(Schema::AnyOf(set![
+                Schema::Atomic(Atomic::String),
+                Schema::Atomic(Atomic::Null),
+            ])),
+            input = ast::Expression::Trim(ast::TrimExpr {
+                trim_spec: ast::TrimSpec::Both,
+                trim_chars: None,
+                arg: Box::new(ast::Expression::Identifier("a".into())),
             }),
+            env = map! {
+                ("foo", 1u16).into() => Schema::Document( Document {
+                    keys: map! {
+                        "a".into() => Schema::AnyOf(set![
+                            Schema::Atomic(Atomic::String),
+                            Schema::Atomic(Atomic::Null),
+                        ]),
+                    },
+                    required: set!{"a".into()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
         );
 
         test_algebrize_expr_and_schema_check!(
@@ -4011,7 +4898,7 @@ mod expression {
             expected_error_code = 1002,
             input = ast::Expression::Trim(ast::TrimExpr {
                 trim_spec: ast::TrimSpec::Both,
-                trim_chars: Box::new(ast::Expression::StringConstructor(" ".into())),
+                trim_chars: Some(Box::new(ast::Expression::StringConstructor(" ".into()))),
                 arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
             }),
         );
@@ -4028,7 +4915,9 @@ mod expression {
             expected_error_code = 1002,
             input = ast::Expression::Trim(ast::TrimExpr {
                 trim_spec: ast::TrimSpec::Both,
-                trim_chars: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
+                trim_chars: Some(Box::new(ast::Expression::Literal(ast::Literal::Integer(
+                    42
+                )))),
                 arg: Box::new(ast::Expression::StringConstructor(" ".into())),
             }),
         );
@@ -4350,6 +5239,37 @@ mod expression {
             }),
         );
 
+        test_algebrize_expr_and_schema_check!(
+            extract_epoch_is_long,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(Schema::Atomic(Atomic::Long)),
+            input = ast::Expression::Extract(ast::ExtractExpr {
+                extract_spec: ast::DatePart::Epoch,
+                arg: Box::new(ast::Expression::Function(ast::FunctionExpr {
+                    function: ast::FunctionName::CurrentTimestamp,
+                    args: ast::FunctionArguments::Args(vec![]),
+                    set_quantifier: Some(ast::SetQuantifier::All)
+                })),
+            }),
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            extract_epoch_must_be_date,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::SchemaChecking(mir::schema::Error::SchemaChecking {
+                name: "UnixTimestamp",
+                required: DATE_OR_NULLISH.clone(),
+                found: Schema::Atomic(Atomic::Integer),
+            })),
+            expected_error_code = 1002,
+            input = ast::Expression::Extract(ast::ExtractExpr {
+                extract_spec: ast::DatePart::Epoch,
+                arg: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
+            }),
+        );
+
         test_algebrize!(
             extract_implicit_converts_ext_json,
             method = algebrize_expression,
@@ -4411,6 +5331,137 @@ mod expression {
             }),
         );
 
+        test_algebrize!(
+            add_interval_of_days,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::DateFunction(
+                mir::DateFunctionApplication {
+                    function: mir::DateFunction::Add,
+                    is_nullable: false,
+                    date_part: mir::DatePart::Day,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::CurrentTimestamp,
+                            args: vec![],
+                            is_nullable: false,
+                        }),
+                    ],
+                }
+            )),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Function(ast::FunctionExpr {
+                    function: ast::FunctionName::CurrentTimestamp,
+                    args: ast::FunctionArguments::Args(vec![]),
+                    set_quantifier: Some(ast::SetQuantifier::All)
+                })),
+                op: ast::BinaryOp::Add,
+                right: Box::new(ast::Expression::Interval(ast::IntervalExpr {
+                    amount: Box::new(ast::Expression::Literal(ast::Literal::Integer(3))),
+                    date_part: ast::DatePart::Day,
+                })),
+            }),
+        );
+
+        test_algebrize!(
+            add_date_to_interval,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::DateFunction(
+                mir::DateFunctionApplication {
+                    function: mir::DateFunction::Add,
+                    is_nullable: false,
+                    date_part: mir::DatePart::Hour,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::CurrentTimestamp,
+                            args: vec![],
+                            is_nullable: false,
+                        }),
+                    ],
+                }
+            )),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Interval(ast::IntervalExpr {
+                    amount: Box::new(ast::Expression::Literal(ast::Literal::Integer(3))),
+                    date_part: ast::DatePart::Hour,
+                })),
+                op: ast::BinaryOp::Add,
+                right: Box::new(ast::Expression::Function(ast::FunctionExpr {
+                    function: ast::FunctionName::CurrentTimestamp,
+                    args: ast::FunctionArguments::Args(vec![]),
+                    set_quantifier: Some(ast::SetQuantifier::All)
+                })),
+            }),
+        );
+
+        test_algebrize!(
+            sub_interval_of_months,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::DateFunction(
+                mir::DateFunctionApplication {
+                    function: mir::DateFunction::Sub,
+                    is_nullable: false,
+                    date_part: mir::DatePart::Month,
+                    args: vec![
+                        mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+                            function: mir::ScalarFunction::CurrentTimestamp,
+                            args: vec![],
+                            is_nullable: false,
+                        }),
+                    ],
+                }
+            )),
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Function(ast::FunctionExpr {
+                    function: ast::FunctionName::CurrentTimestamp,
+                    args: ast::FunctionArguments::Args(vec![]),
+                    set_quantifier: Some(ast::SetQuantifier::All)
+                })),
+                op: ast::BinaryOp::Sub,
+                right: Box::new(ast::Expression::Interval(ast::IntervalExpr {
+                    amount: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
+                    date_part: ast::DatePart::Month,
+                })),
+            }),
+        );
+
+        test_algebrize!(
+            sub_date_from_interval_fails,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::MisplacedInterval),
+            expected_error_code = 3039,
+            input = ast::Expression::Binary(ast::BinaryExpr {
+                left: Box::new(ast::Expression::Interval(ast::IntervalExpr {
+                    amount: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
+                    date_part: ast::DatePart::Month,
+                })),
+                op: ast::BinaryOp::Sub,
+                right: Box::new(ast::Expression::Function(ast::FunctionExpr {
+                    function: ast::FunctionName::CurrentTimestamp,
+                    args: ast::FunctionArguments::Args(vec![]),
+                    set_quantifier: Some(ast::SetQuantifier::All)
+                })),
+            }),
+        );
+
+        test_algebrize!(
+            bare_interval_fails,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::MisplacedInterval),
+            expected_error_code = 3039,
+            input = ast::Expression::Interval(ast::IntervalExpr {
+                amount: Box::new(ast::Expression::Literal(ast::Literal::Integer(2))),
+                date_part: ast::DatePart::Month,
+            }),
+        );
+
         test_algebrize!(
             datediff,
             method = algebrize_expression,
@@ -4918,11 +5969,80 @@ mod expression {
                 is_nullable: false,
             })),
             input = ast::Expression::Cast(ast::CastExpr {
-                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
-                to: ast::Type::String,
-                on_null: Some(Box::new(ast::Expression::StringConstructor(
-                    "was_null".into()
-                ))),
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
+                to: ast::Type::String,
+                format: None,
+                on_null: Some(Box::new(ast::Expression::StringConstructor(
+                    "was_null".into()
+                ))),
+                on_error: Some(Box::new(ast::Expression::StringConstructor(
+                    "was_error".into()
+                ))),
+            }),
+        );
+
+        test_algebrize!(
+            cast_simple,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::Cast(mir::CastExpr {
+                expr: Box::new(mir::Expression::Literal(mir::LiteralValue::Integer(42))),
+                to: mir::Type::String,
+                on_null: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
+                on_error: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
+                is_nullable: true,
+            })),
+            input = ast::Expression::Cast(ast::CastExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
+                to: ast::Type::String,
+                format: None,
+                on_null: None,
+                on_error: None,
+            }),
+        );
+
+        test_algebrize!(
+            cast_to_date_with_format,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication::new(
+                    mir::ScalarFunction::ParseDate,
+                    vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("2024-03-01".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("%Y-%m-%d".into())),
+                    ],
+                )
+            )),
+            input = ast::Expression::Cast(ast::CastExpr {
+                expr: Box::new(ast::Expression::StringConstructor("2024-03-01".into())),
+                to: ast::Type::Date,
+                format: Some("%Y-%m-%d".into()),
+                on_null: None,
+                on_error: None,
+            }),
+        );
+
+        test_algebrize!(
+            cast_to_date_with_format_and_on_error,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication::new(
+                    mir::ScalarFunction::ParseDate,
+                    vec![
+                        mir::Expression::Literal(mir::LiteralValue::String("2024-03-01".into())),
+                        mir::Expression::Literal(mir::LiteralValue::String("%Y-%m-%d".into())),
+                        mir::Expression::Literal(mir::LiteralValue::Null),
+                        mir::Expression::Literal(mir::LiteralValue::String("was_error".into())),
+                    ],
+                )
+            )),
+            input = ast::Expression::Cast(ast::CastExpr {
+                expr: Box::new(ast::Expression::StringConstructor("2024-03-01".into())),
+                to: ast::Type::Date,
+                format: Some("%Y-%m-%d".into()),
+                on_null: None,
                 on_error: Some(Box::new(ast::Expression::StringConstructor(
                     "was_error".into()
                 ))),
@@ -4930,19 +6050,15 @@ mod expression {
         );
 
         test_algebrize!(
-            cast_simple,
+            cast_with_format_to_non_date_is_error,
             method = algebrize_expression,
             in_implicit_type_conversion_context = false,
-            expected = Ok(mir::Expression::Cast(mir::CastExpr {
-                expr: Box::new(mir::Expression::Literal(mir::LiteralValue::Integer(42))),
-                to: mir::Type::String,
-                on_null: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
-                on_error: Box::new(mir::Expression::Literal(mir::LiteralValue::Null)),
-                is_nullable: true,
-            })),
+            expected = Err(Error::InvalidCastFormat(ast::Type::Datetime)),
+            expected_error_code = 3041,
             input = ast::Expression::Cast(ast::CastExpr {
-                expr: Box::new(ast::Expression::Literal(ast::Literal::Integer(42))),
-                to: ast::Type::String,
+                expr: Box::new(ast::Expression::StringConstructor("2024-03-01".into())),
+                to: ast::Type::Datetime,
+                format: Some("%Y-%m-%d".into()),
                 on_null: None,
                 on_error: None,
             }),
@@ -5061,6 +6177,50 @@ mod expression {
                 target_type: ast::TypeOrMissing::Type(ast::Type::Int32),
             }),
         );
+
+        test_algebrize!(
+            is_true_of_nullable_is_not_nullable,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::ScalarFunction(
+                mir::ScalarFunctionApplication {
+                    function: mir::ScalarFunction::IsTrue,
+                    args: vec![mir::Expression::Literal(mir::LiteralValue::Null)],
+                    is_nullable: false,
+                }
+            )),
+            input = ast::Expression::IsTruthValue(ast::IsTruthValueExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Null)),
+                value: ast::TruthValue::True,
+            }),
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            is_true_of_nullable_is_boolean,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(Schema::Atomic(Atomic::Boolean)),
+            input = ast::Expression::IsTruthValue(ast::IsTruthValueExpr {
+                expr: Box::new(ast::Expression::Literal(ast::Literal::Null)),
+                value: ast::TruthValue::True,
+            }),
+        );
+
+        test_algebrize_expr_and_schema_check!(
+            is_unknown_requires_boolean,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Err(Error::SchemaChecking(mir::schema::Error::SchemaChecking {
+                name: "IsUnknown",
+                required: BOOLEAN_OR_NULLISH.clone(),
+                found: Schema::Atomic(Atomic::String)
+            })),
+            expected_error_code = 1002,
+            input = ast::Expression::IsTruthValue(ast::IsTruthValueExpr {
+                expr: Box::new(ast::Expression::StringConstructor("a".into())),
+                value: ast::TruthValue::Unknown,
+            }),
+        );
     }
 
     mod like {
@@ -5139,14 +6299,30 @@ mod expression {
                 escape: Some(' '),
             }),
         );
+
+        test_algebrize!(
+            ilike_with_escape_algebrizes_as_like,
+            method = algebrize_expression,
+            in_implicit_type_conversion_context = false,
+            expected = Ok(mir::Expression::Like(mir::LikeExpr {
+                expr: Box::new(mir::Expression::Literal(mir::LiteralValue::String(
+                    "42".into()
+                ))),
+                pattern: Box::new(mir::Expression::Literal(mir::LiteralValue::String(
+                    "4!_".into()
+                ))),
+                escape: Some('!'),
+            })),
+            input = crate::parser::parse_expression("'42' ILIKE '4!_' ESCAPE '!'").unwrap(),
+        );
     }
 }
 
 mod aggregation {
     use crate::{
         ast, map, mir, multimap,
-        schema::{Atomic, Schema, ANY_DOCUMENT, NUMERIC_OR_NULLISH},
-        unchecked_unique_linked_hash_map,
+        schema::{Atomic, Document, Schema, ANY_DOCUMENT, NUMERIC_OR_NULLISH},
+        set, unchecked_unique_linked_hash_map,
         usererror::UserError,
     };
     test_algebrize!(
@@ -5160,9 +6336,10 @@ mod aggregation {
         },
     );
     test_algebrize!(
-        count_distinct_star,
+        count_distinct_star_is_error,
         method = algebrize_aggregation,
-        expected = Ok(mir::AggregationExpr::CountStar(true)),
+        expected = Err(Error::DistinctCountStar),
+        expected_error_code = 3035,
         input = ast::FunctionExpr {
             function: ast::FunctionName::Count,
             args: ast::FunctionArguments::Star,
@@ -5177,6 +6354,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Count,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5195,6 +6373,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Count,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5205,6 +6384,57 @@ mod aggregation {
             set_quantifier: Some(ast::SetQuantifier::Distinct),
         },
     );
+    test_algebrize!(
+        count_distinct_field_reference,
+        method = algebrize_aggregation,
+        expected = Ok(mir::AggregationExpr::Function(
+            mir::AggregationFunctionApplication {
+                function: mir::AggregationFunction::Count,
+                distinct: true,
+                arg: mir::Expression::FieldAccess(mir::FieldAccess {
+                    expr: mir::Expression::Reference(("foo", 1u16).into()).into(),
+                    field: "a".into(),
+                    is_nullable: false,
+                })
+                .into(),
+                separator: None,
+            }
+        )),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::Count,
+            args: ast::FunctionArguments::Args(vec![ast::Expression::Identifier("a".into())]),
+            set_quantifier: Some(ast::SetQuantifier::Distinct),
+        },
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document(Document {
+                keys: map! {"a".into() => Schema::Atomic(Atomic::Integer)},
+                required: set! {"a".into()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+    );
+    test_algebrize_expr_and_schema_check!(
+        count_distinct_field_reference_is_integer,
+        method = algebrize_aggregation,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Long)
+        ])),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::Count,
+            args: ast::FunctionArguments::Args(vec![ast::Expression::Identifier("a".into())]),
+            set_quantifier: Some(ast::SetQuantifier::Distinct),
+        },
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document(Document {
+                keys: map! {"a".into() => Schema::Atomic(Atomic::Integer)},
+                required: set! {"a".into()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+    );
     test_algebrize_expr_and_schema_check!(
         count_distinct_expr_argument_not_self_comparable_is_error,
         method = algebrize_aggregation,
@@ -5243,6 +6473,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Sum,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5261,6 +6492,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Sum,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5297,6 +6529,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Avg,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5315,6 +6548,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Avg,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5352,6 +6586,7 @@ mod aggregation {
                 function: mir::AggregationFunction::StddevPop,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5370,6 +6605,7 @@ mod aggregation {
                 function: mir::AggregationFunction::StddevPop,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5406,6 +6642,7 @@ mod aggregation {
                 function: mir::AggregationFunction::StddevSamp,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5424,6 +6661,7 @@ mod aggregation {
                 function: mir::AggregationFunction::StddevSamp,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5460,6 +6698,7 @@ mod aggregation {
                 function: mir::AggregationFunction::AddToArray,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5478,6 +6717,7 @@ mod aggregation {
                 function: mir::AggregationFunction::AddToArray,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5497,6 +6737,7 @@ mod aggregation {
                 function: mir::AggregationFunction::AddToArray,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5515,6 +6756,7 @@ mod aggregation {
                 function: mir::AggregationFunction::AddToArray,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5534,6 +6776,7 @@ mod aggregation {
                 function: mir::AggregationFunction::First,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5552,6 +6795,7 @@ mod aggregation {
                 function: mir::AggregationFunction::First,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5571,6 +6815,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Last,
                 distinct: false,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5589,6 +6834,7 @@ mod aggregation {
                 function: mir::AggregationFunction::Last,
                 distinct: true,
                 arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5613,7 +6859,8 @@ mod aggregation {
                         "b".into() => mir::Expression::Literal(mir::LiteralValue::Integer(42)),
                     }
                     .into()
-                ))
+                )),
+                separator: None,
             }
         )),
         input = ast::FunctionExpr {
@@ -5642,6 +6889,149 @@ mod aggregation {
             set_quantifier: Some(ast::SetQuantifier::All),
         },
     );
+
+    test_algebrize!(
+        varpop_expr,
+        method = algebrize_aggregation,
+        expected = Ok(mir::AggregationExpr::Function(
+            mir::AggregationFunctionApplication {
+                function: mir::AggregationFunction::VarPop,
+                distinct: false,
+                arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
+            }
+        )),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::VarPop,
+            args: ast::FunctionArguments::Args(vec![ast::Expression::Literal(
+                ast::Literal::Integer(42)
+            )]),
+            set_quantifier: Some(ast::SetQuantifier::All),
+        },
+    );
+    test_algebrize!(
+        varsamp_distinct_expr,
+        method = algebrize_aggregation,
+        expected = Ok(mir::AggregationExpr::Function(
+            mir::AggregationFunctionApplication {
+                function: mir::AggregationFunction::VarSamp,
+                distinct: true,
+                arg: mir::Expression::Literal(mir::LiteralValue::Integer(42)).into(),
+                separator: None,
+            }
+        )),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::VarSamp,
+            args: ast::FunctionArguments::Args(vec![ast::Expression::Literal(
+                ast::Literal::Integer(42)
+            )]),
+            set_quantifier: Some(ast::SetQuantifier::Distinct),
+        },
+    );
+    test_algebrize!(
+        varpop_with_two_arguments_is_error,
+        method = algebrize_aggregation,
+        expected = Err(Error::AggregationFunctionMustHaveOneArgument),
+        expected_error_code = 3014,
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::VarPop,
+            args: ast::FunctionArguments::Args(vec![
+                ast::Expression::Literal(ast::Literal::Integer(42)),
+                ast::Expression::Literal(ast::Literal::Integer(43)),
+            ]),
+            set_quantifier: Some(ast::SetQuantifier::All),
+        },
+    );
+    test_algebrize!(
+        group_concat_with_separator,
+        method = algebrize_aggregation,
+        expected = Ok(mir::AggregationExpr::Function(
+            mir::AggregationFunctionApplication {
+                function: mir::AggregationFunction::GroupConcat,
+                distinct: false,
+                arg: mir::Expression::FieldAccess(mir::FieldAccess {
+                    expr: mir::Expression::Reference(("foo", 1u16).into()).into(),
+                    field: "name".into(),
+                    is_nullable: false,
+                })
+                .into(),
+                separator: Some(";".into()),
+            }
+        )),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::GroupConcat,
+            args: ast::FunctionArguments::Args(vec![
+                ast::Expression::Identifier("name".into()),
+                ast::Expression::StringConstructor(";".into()),
+            ]),
+            set_quantifier: Some(ast::SetQuantifier::All),
+        },
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document(Document {
+                keys: map! {"name".into() => Schema::Atomic(Atomic::String)},
+                required: set! {"name".into()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+    );
+    test_algebrize!(
+        group_concat_separator_is_optional,
+        method = algebrize_aggregation,
+        expected = Ok(mir::AggregationExpr::Function(
+            mir::AggregationFunctionApplication {
+                function: mir::AggregationFunction::GroupConcat,
+                distinct: true,
+                arg: mir::Expression::Literal(mir::LiteralValue::String("a".into())).into(),
+                separator: None,
+            }
+        )),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::GroupConcat,
+            args: ast::FunctionArguments::Args(vec![ast::Expression::StringConstructor(
+                "a".into()
+            )]),
+            set_quantifier: Some(ast::SetQuantifier::Distinct),
+        },
+    );
+    test_algebrize!(
+        group_concat_non_literal_separator_is_error,
+        method = algebrize_aggregation,
+        expected = Err(Error::InvalidGroupConcatArguments),
+        expected_error_code = 3040,
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::GroupConcat,
+            args: ast::FunctionArguments::Args(vec![
+                ast::Expression::StringConstructor("a".into()),
+                ast::Expression::Identifier("sep".into()),
+            ]),
+            set_quantifier: Some(ast::SetQuantifier::All),
+        },
+    );
+    test_algebrize_expr_and_schema_check!(
+        group_concat_of_nullable_string_is_string_or_null,
+        method = algebrize_aggregation,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = ast::FunctionExpr {
+            function: ast::FunctionName::GroupConcat,
+            args: ast::FunctionArguments::Args(vec![
+                ast::Expression::Identifier("name".into()),
+                ast::Expression::StringConstructor(";".into()),
+            ]),
+            set_quantifier: Some(ast::SetQuantifier::All),
+        },
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document(Document {
+                keys: map! {"name".into() => Schema::Atomic(Atomic::String)},
+                required: set! {},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+    );
 }
 
 mod select_clause {
@@ -5650,8 +7040,8 @@ mod select_clause {
         ast, map,
         mir::{self, binding_tuple::Key, schema::SchemaCache},
         multimap,
-        schema::ANY_DOCUMENT,
-        unchecked_unique_linked_hash_map,
+        schema::{Document, Schema, ANY_DOCUMENT},
+        set, unchecked_unique_linked_hash_map,
         usererror::UserError,
     };
 
@@ -5753,17 +7143,49 @@ mod select_clause {
         catalog = catalog(vec![("test", "baz")]),
         is_add_fields = false,
     );
+    test_algebrize!(
+        select_value_non_literal_document_expression,
+        method = algebrize_select_clause,
+        expected = Ok(mir::Stage::Project(mir::Project {
+            is_add_fields: false,
+            source: Box::new(source()),
+            expression: map! {
+                Key::bot(1u16) => mir::Expression::FieldAccess(mir::FieldAccess {
+                    expr: Box::new(mir::Expression::Reference(("foo", 1u16).into())),
+                    field: "a".into(),
+                    is_nullable: false,
+                }),
+            },
+            cache: SchemaCache::new(),
+        })),
+        input = ast::SelectClause {
+            set_quantifier: ast::SetQuantifier::All,
+            body: ast::SelectBody::Values(vec![ast::SelectValuesExpression::Expression(
+                ast::Expression::Subpath(ast::SubpathExpr {
+                    expr: Box::new(ast::Expression::Identifier("foo".into())),
+                    subpath: "a".into(),
+                })
+            ),]),
+        },
+        source = source(),
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document(Document {
+                keys: map! {"a".into() => ANY_DOCUMENT.clone()},
+                required: set! {"a".into()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+        catalog = catalog(vec![("test", "baz")]),
+        is_add_fields = false,
+    );
     test_algebrize!(
         select_value_expression_must_be_document,
         method = algebrize_select_clause,
-        expected = Err(Error::SchemaChecking(
-            crate::mir::schema::Error::SchemaChecking {
-                name: "project datasource",
-                required: ANY_DOCUMENT.clone(),
-                found: crate::schema::Schema::Atomic(crate::schema::Atomic::String),
-            }
+        expected = Err(Error::NonDocumentSelectValue(
+            crate::schema::Schema::Atomic(crate::schema::Atomic::String)
         )),
-        expected_error_code = 1002,
+        expected_error_code = 3037,
         input = ast::SelectClause {
             set_quantifier: ast::SetQuantifier::All,
             body: ast::SelectBody::Values(vec![ast::SelectValuesExpression::Expression(
@@ -6040,6 +7462,46 @@ mod from_clause {
             alias: "bar".into(),
         })),
     );
+    test_algebrize!(
+        array_datasource_with_foldable_constant_element,
+        method = algebrize_from_clause,
+        expected = Ok(mir::Stage::Project(mir::Project {
+            is_add_fields: false,
+            source: Box::new(mir::Stage::Array(mir::ArraySource {
+                array: vec![mir::Expression::Document(
+                    unchecked_unique_linked_hash_map! {
+                        "a".into() => mir::Expression::ScalarFunction(
+                            mir::ScalarFunctionApplication {
+                                function: mir::ScalarFunction::Add,
+                                args: vec![
+                                    mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                    mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                                ],
+                                is_nullable: false,
+                            }
+                        ),
+                    }
+                    .into()
+                )],
+                alias: "bar".into(),
+                cache: SchemaCache::new(),
+            })),
+            expression: map! {
+                ("bar", 0u16).into() => mir::Expression::Reference(("bar", 0u16).into()),
+            },
+            cache: SchemaCache::new(),
+        })),
+        input = Some(ast::Datasource::Array(ast::ArraySource {
+            array: vec![ast::Expression::Document(multimap! {
+                "a".into() => ast::Expression::Binary(ast::BinaryExpr {
+                    left: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                    op: ast::BinaryOp::Add,
+                    right: Box::new(ast::Expression::Literal(ast::Literal::Integer(1))),
+                }),
+            },)],
+            alias: "bar".into(),
+        })),
+    );
     test_algebrize!(
         single_document_array,
         method = algebrize_from_clause,
@@ -6272,12 +7734,13 @@ mod from_clause {
     test_algebrize!(
         invalid_join_condition,
         method = algebrize_from_clause,
-        expected = Err(Error::FieldNotFound(
-            "x".into(),
-            Some(vec!["bar".into(), "foo".into()]),
-            ClauseType::From,
-            0u16,
-        )),
+        expected = Err(Error::FieldNotFound {
+            field: "x".into(),
+            found_fields: Some(vec!["bar".into(), "foo".into()]),
+            clause_type: ClauseType::From,
+            scope_level: 0u16,
+            span: None,
+        }),
         expected_error_code = 3008,
         input = Some(ast::Datasource::Join(JoinSource {
             join_type: ast::JoinType::Cross,
@@ -6619,9 +8082,9 @@ mod from_clause {
                 alias: "bar".into(),
             })),
             condition: Some(ast::Expression::Binary(ast::BinaryExpr {
-                left: Box::new(ast::Expression::Identifier("a".to_string())),
+                left: Box::new(ast::Expression::Identifier("a".into())),
                 op: ast::BinaryOp::Comparison(ast::ComparisonOp::Eq),
-                right: Box::new(ast::Expression::Identifier("b".to_string())),
+                right: Box::new(ast::Expression::Identifier("b".into())),
             }))
         })),
     );
@@ -7248,12 +8711,13 @@ mod from_clause {
         test_algebrize!(
             correlated_path_disallowed,
             method = algebrize_from_clause,
-            expected = Err(Error::FieldNotFound(
-                "bar".into(),
-                Some(vec!["arr".into()]),
-                ClauseType::From,
-                1u16,
-            )),
+            expected = Err(Error::FieldNotFound {
+                field: "bar".into(),
+                found_fields: Some(vec!["arr".into()]),
+                clause_type: ClauseType::From,
+                scope_level: 1u16,
+                span: None,
+            }),
             expected_error_code = 3008,
             input = Some(ast::Datasource::Unwind(ast::UnwindSource {
                 datasource: Box::new(AST_SOURCE_FOO.clone()),
@@ -7359,10 +8823,18 @@ mod limit_or_offset_clause {
 
 mod set_query {
     use super::{catalog, mir_source_bar, mir_source_foo, AST_QUERY_BAR, AST_QUERY_FOO};
-    use crate::{ast, mir, mir::schema::SchemaCache, usererror::UserError};
+    use crate::{ast, mir, mir::schema::SchemaCache, parser::parse_query, usererror::UserError};
+
+    fn union_all(left: &str, right: &str) -> ast::SetQuery {
+        ast::SetQuery {
+            left: Box::new(parse_query(left).unwrap()),
+            op: ast::SetOperator::UnionAll,
+            right: Box::new(parse_query(right).unwrap()),
+        }
+    }
 
     test_algebrize!(
-        union_distinct_not_allowed,
+        union_distinct_of_unknown_fields_not_allowed,
         method = algebrize_set_query,
         expected = Err(Error::DistinctUnion),
         expected_error_code = 3006,
@@ -7371,7 +8843,55 @@ mod set_query {
             op: ast::SetOperator::Union,
             right: Box::new(AST_QUERY_BAR.clone()),
         },
+        catalog = catalog(vec![("test", "foo"), ("test", "bar")]),
     );
+
+    #[test]
+    fn union_distinct_groups_union_all_by_every_field() {
+        use crate::{
+            algebrizer::{Algebrizer, ClauseType},
+            catalog::Catalog,
+            mir::{binding_tuple::Key, FieldAccess},
+            SchemaCheckingMode,
+        };
+
+        let catalog = Catalog::default();
+        let algebrizer = Algebrizer::new(
+            "test",
+            &catalog,
+            0u16,
+            SchemaCheckingMode::Strict,
+            false,
+            ClauseType::Unintialized,
+        );
+        let left = "SELECT VALUE {'a': 1, 'b': 'x'} FROM [{'x': 1}] AS arr";
+        let right = "SELECT VALUE {'a': 2, 'b': 'y'} FROM [{'x': 1}] AS arr";
+        let union_all_plan = algebrizer
+            .algebrize_set_query(union_all(left, right))
+            .unwrap();
+        let bot_field = |field: &str| {
+            mir::OptionallyAliasedExpr::Unaliased(mir::Expression::FieldAccess(FieldAccess::new(
+                Box::new(mir::Expression::Reference(Key::bot(0u16).into())),
+                field.into(),
+            )))
+        };
+
+        let actual = algebrizer.algebrize_set_query(ast::SetQuery {
+            op: ast::SetOperator::Union,
+            ..union_all(left, right)
+        });
+
+        assert_eq!(
+            Ok(mir::Stage::Group(mir::Group {
+                source: Box::new(union_all_plan),
+                keys: vec![bot_field("a"), bot_field("b")],
+                aggregations: vec![],
+                cache: SchemaCache::new(),
+                scope: 0u16,
+            })),
+            actual
+        );
+    }
     test_algebrize!(
         basic,
         method = algebrize_set_query,
@@ -7388,6 +8908,71 @@ mod set_query {
         },
         catalog = catalog(vec![("test", "foo"), ("test", "bar")]),
     );
+    test_algebrize!(
+        compatible_columns,
+        method = algebrize_set_query,
+        expected_pat = Ok(mir::Stage::Set(_)),
+        input = union_all(
+            "SELECT VALUE {'a': 1} FROM [{'x': 1}] AS arr",
+            "SELECT VALUE {'a': 2.5} FROM [{'x': 1}] AS arr",
+        ),
+    );
+    test_algebrize!(
+        incompatible_column_types,
+        method = algebrize_set_query,
+        expected = Err(Error::IncompatibleUnionBranches(
+            "column `a` has incompatible types: int and string".to_string()
+        )),
+        expected_error_code = 3038,
+        input = union_all(
+            "SELECT VALUE {'a': 1} FROM [{'x': 1}] AS arr",
+            "SELECT VALUE {'a': 'one'} FROM [{'x': 1}] AS arr",
+        ),
+    );
+    test_algebrize!(
+        incompatible_column_types_allowed_in_relaxed_mode,
+        method = algebrize_set_query,
+        expected_pat = Ok(mir::Stage::Set(_)),
+        input = union_all(
+            "SELECT VALUE {'a': 1} FROM [{'x': 1}] AS arr",
+            "SELECT VALUE {'a': 'one'} FROM [{'x': 1}] AS arr",
+        ),
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
+    test_algebrize!(
+        same_named_datasource_with_compatible_columns,
+        method = algebrize_set_query,
+        expected_pat = Ok(mir::Stage::Set(_)),
+        input = union_all(
+            "SELECT * FROM [{'a': 1}] AS arr",
+            "SELECT * FROM [{'a': 2.5}] AS arr",
+        ),
+    );
+    test_algebrize!(
+        same_named_datasource_with_incompatible_columns,
+        method = algebrize_set_query,
+        expected = Err(Error::IncompatibleUnionBranches(
+            "column `arr.a` has incompatible types: int and string".to_string()
+        )),
+        expected_error_code = 3038,
+        input = union_all(
+            "SELECT * FROM [{'a': 1}] AS arr",
+            "SELECT * FROM [{'a': 'one'}] AS arr",
+        ),
+    );
+    test_algebrize!(
+        missing_columns,
+        method = algebrize_set_query,
+        expected = Err(Error::IncompatibleUnionBranches(
+            "column `a` is missing from the right side; column `b` is missing from the left side"
+                .to_string()
+        )),
+        expected_error_code = 3038,
+        input = union_all(
+            "SELECT VALUE {'a': 1} FROM [{'x': 1}] AS arr",
+            "SELECT VALUE {'b': 1} FROM [{'x': 1}] AS arr",
+        ),
+    );
 }
 
 mod filter_clause {
@@ -7437,9 +9022,10 @@ mod order_by_clause {
     use super::catalog;
     use crate::{
         ast, map, mir,
-        mir::schema::SchemaCache,
+        mir::{binding_tuple::Key, schema::SchemaCache},
         schema::{Atomic, Document, Schema},
         set, unchecked_unique_linked_hash_map,
+        usererror::UserError,
     };
 
     fn source() -> mir::Stage {
@@ -7473,14 +9059,14 @@ mod order_by_clause {
             sort_specs: vec![
                 ast::SortSpec {
                     key: ast::SortKey::Simple(ast::Expression::Subpath(ast::SubpathExpr {
-                        expr: Box::new(ast::Expression::Identifier("foo".to_string())),
+                        expr: Box::new(ast::Expression::Identifier("foo".into())),
                         subpath: "a".to_string()
                     })),
                     direction: ast::SortDirection::Asc
                 },
                 ast::SortSpec {
                     key: ast::SortKey::Simple(ast::Expression::Subpath(ast::SubpathExpr {
-                        expr: Box::new(ast::Expression::Identifier("foo".to_string())),
+                        expr: Box::new(ast::Expression::Identifier("foo".into())),
                         subpath: "b".to_string()
                     })),
                     direction: ast::SortDirection::Desc
@@ -7526,7 +9112,7 @@ mod order_by_clause {
         input = Some(ast::OrderByClause {
             sort_specs: vec![ast::SortSpec {
                 key: ast::SortKey::Simple(ast::Expression::Subpath(ast::SubpathExpr {
-                    expr: Box::new(ast::Expression::Identifier("arr".to_string())),
+                    expr: Box::new(ast::Expression::Identifier("arr".into())),
                     subpath: "a".to_string()
                 })),
                 direction: ast::SortDirection::Asc
@@ -7543,6 +9129,224 @@ mod order_by_clause {
             cache: SchemaCache::new(),
         }),
     );
+
+    fn mir_array_source() -> mir::Stage {
+        mir::Stage::Array(mir::ArraySource {
+            array: vec![mir::Expression::Document(
+                unchecked_unique_linked_hash_map! {
+                    "a".into() => mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                    "b".into() => mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                    "c".into() => mir::Expression::Literal(mir::LiteralValue::Integer(3)),
+                }
+                .into(),
+            )],
+            alias: "arr".into(),
+            cache: SchemaCache::new(),
+        })
+    }
+
+    fn mir_arr_field(field: &str) -> mir::Expression {
+        mir::Expression::FieldAccess(mir::FieldAccess {
+            expr: Box::new(mir::Expression::Reference(("arr", 0u16).into())),
+            field: field.to_string(),
+            is_nullable: false,
+        })
+    }
+
+    // b + c
+    fn ast_b_plus_c() -> ast::Expression {
+        ast::Expression::Binary(ast::BinaryExpr {
+            left: Box::new(ast::Expression::Identifier("b".into())),
+            op: ast::BinaryOp::Add,
+            right: Box::new(ast::Expression::Identifier("c".into())),
+        })
+    }
+
+    fn mir_b_plus_c() -> mir::Expression {
+        mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+            function: mir::ScalarFunction::Add,
+            args: vec![mir_arr_field("b"), mir_arr_field("c")],
+            is_nullable: false,
+        })
+    }
+
+    // Computes the sort keys into the given datasource before sorting by them.
+    fn mir_sort_by_computed_key(source: mir::Stage, datasource: &str) -> mir::Stage {
+        mir::Stage::Sort(mir::Sort {
+            source: Box::new(mir::Stage::Project(mir::Project {
+                source: Box::new(source),
+                expression: map! {
+                    (datasource, 0u16).into() => mir::Expression::Document(
+                        unchecked_unique_linked_hash_map! {
+                            "key0".into() => mir_b_plus_c(),
+                        }
+                        .into(),
+                    ),
+                },
+                is_add_fields: true,
+                cache: SchemaCache::new(),
+            })),
+            specs: vec![mir::SortSpecification::Desc(mir::FieldPath {
+                key: (datasource, 0u16).into(),
+                fields: vec!["key0".to_string()],
+                is_nullable: false,
+            })],
+            cache: SchemaCache::new(),
+        })
+    }
+
+    test_algebrize!(
+        computed_sort_key,
+        method = algebrize_order_by_clause,
+        expected = Ok(mir::Stage::Project(mir::Project {
+            source: Box::new(mir_sort_by_computed_key(mir_array_source(), "__sortKeys")),
+            expression: map! {
+                ("arr", 0u16).into() => mir::Expression::Reference(("arr", 0u16).into()),
+            },
+            is_add_fields: false,
+            cache: SchemaCache::new(),
+        })),
+        input = Some(ast::OrderByClause {
+            sort_specs: vec![ast::SortSpec {
+                key: ast::SortKey::Simple(ast_b_plus_c()),
+                direction: ast::SortDirection::Desc,
+            }],
+        }),
+        source = mir_array_source(),
+    );
+
+    test_algebrize!(
+        computed_sort_key_datasource_does_not_shadow_existing_datasource,
+        method = algebrize_order_by_clause,
+        expected = Ok(mir::Stage::Project(mir::Project {
+            source: Box::new(mir_sort_by_computed_key(
+                mir::Stage::Project(mir::Project {
+                    source: Box::new(mir_array_source()),
+                    expression: map! {
+                        ("__sortKeys", 0u16).into() => mir::Expression::Reference(("arr", 0u16).into()),
+                    },
+                    is_add_fields: true,
+                    cache: SchemaCache::new(),
+                }),
+                "___sortKeys",
+            )),
+            expression: map! {
+                ("arr", 0u16).into() => mir::Expression::Reference(("arr", 0u16).into()),
+                ("__sortKeys", 0u16).into() => mir::Expression::Reference(("__sortKeys", 0u16).into()),
+            },
+            is_add_fields: false,
+            cache: SchemaCache::new(),
+        })),
+        input = Some(ast::OrderByClause {
+            sort_specs: vec![ast::SortSpec {
+                key: ast::SortKey::Simple(ast::Expression::Binary(ast::BinaryExpr {
+                    left: Box::new(ast::Expression::Subpath(ast::SubpathExpr {
+                        expr: Box::new(ast::Expression::Identifier("arr".into())),
+                        subpath: "b".to_string(),
+                    })),
+                    op: ast::BinaryOp::Add,
+                    right: Box::new(ast::Expression::Subpath(ast::SubpathExpr {
+                        expr: Box::new(ast::Expression::Identifier("arr".into())),
+                        subpath: "c".to_string(),
+                    })),
+                })),
+                direction: ast::SortDirection::Desc,
+            }],
+        }),
+        source = mir::Stage::Project(mir::Project {
+            source: Box::new(mir_array_source()),
+            expression: map! {
+                ("__sortKeys", 0u16).into() => mir::Expression::Reference(("arr", 0u16).into()),
+            },
+            is_add_fields: true,
+            cache: SchemaCache::new(),
+        }),
+    );
+
+    // SELECT VALUE {'a': a} FROM [{'a': 1, 'b': 2, 'c': 3}] AS arr ORDER BY b + c DESC
+    #[test]
+    fn computed_sort_key_not_in_select_list() {
+        use crate::{
+            algebrizer::{Algebrizer, ClauseType},
+            catalog::Catalog,
+            SchemaCheckingMode,
+        };
+        let select_body = || {
+            map! {
+                Key::bot(0) => mir::Expression::Document(
+                    unchecked_unique_linked_hash_map! {
+                        "a".into() => mir_arr_field("a"),
+                    }
+                    .into(),
+                ),
+            }
+        };
+        let expected = Ok(mir::Stage::Project(mir::Project {
+            source: Box::new(mir_sort_by_computed_key(
+                mir::Stage::Project(mir::Project {
+                    source: Box::new(mir_array_source()),
+                    expression: select_body(),
+                    is_add_fields: true,
+                    cache: SchemaCache::new(),
+                }),
+                "__sortKeys",
+            )),
+            expression: select_body(),
+            is_add_fields: false,
+            cache: SchemaCache::new(),
+        }));
+
+        let select = ast::SelectClause {
+            set_quantifier: ast::SetQuantifier::All,
+            body: ast::SelectBody::Values(vec![ast::SelectValuesExpression::Expression(
+                ast::Expression::Document(vec![ast::DocumentPair {
+                    key: "a".into(),
+                    value: ast::Expression::Identifier("a".into()),
+                }]),
+            )]),
+        };
+        let order_by = Some(ast::OrderByClause {
+            sort_specs: vec![ast::SortSpec {
+                key: ast::SortKey::Simple(ast_b_plus_c()),
+                direction: ast::SortDirection::Desc,
+            }],
+        });
+        let catalog = Catalog::default();
+        let algebrizer = Algebrizer::new(
+            "test",
+            &catalog,
+            0u16,
+            SchemaCheckingMode::Strict,
+            true,
+            ClauseType::Unintialized,
+        );
+        let res =
+            algebrizer.algebrize_select_and_order_by_clause(select, order_by, mir_array_source());
+        assert_eq!(expected, res);
+    }
+
+    // SELECT DISTINCT is rejected before the ORDER BY is algebrized, whether
+    // or not the sort key is among the selected columns.
+    test_algebrize!(
+        distinct_with_order_by_selected_column_not_allowed,
+        method = algebrize_query,
+        expected = Err(Error::DistinctSelect),
+        expected_error_code = 3005,
+        input = crate::parser::parse_query(
+            "SELECT DISTINCT arr.a FROM [{'a': 1, 'b': 2}] AS arr ORDER BY arr.a"
+        )
+        .unwrap(),
+    );
+    test_algebrize!(
+        distinct_with_order_by_unselected_column_not_allowed,
+        method = algebrize_query,
+        expected = Err(Error::DistinctSelect),
+        expected_error_code = 3005,
+        input = crate::parser::parse_query(
+            "SELECT DISTINCT arr.a FROM [{'a': 1, 'b': 2}] AS arr ORDER BY arr.b"
+        )
+        .unwrap(),
+    );
 }
 
 mod group_by_clause {
@@ -7615,6 +9419,7 @@ mod group_by_clause {
                     is_nullable: false,
                 })),
                 distinct: true,
+                separator: None,
             }),
         }
     }
@@ -7630,7 +9435,7 @@ mod group_by_clause {
         // GROUP BY KEYS
         static ref AST_SUBPATH: ast::OptionallyAliasedExpr = ast::OptionallyAliasedExpr::Aliased(ast::AliasedExpr {
             expr: ast::Expression::Subpath(ast::SubpathExpr {
-                expr: Box::new(ast::Expression::Identifier("arr".to_string())),
+                expr: Box::new(ast::Expression::Identifier("arr".into())),
                 subpath: "a".to_string()
             }),
             alias: "key".to_string(),
@@ -7646,7 +9451,7 @@ mod group_by_clause {
         static ref AST_SUBPATH_COMPLEX_EXPR: ast::OptionallyAliasedExpr = ast::OptionallyAliasedExpr::Aliased(ast::AliasedExpr {
             expr: ast::Expression::Binary(ast::BinaryExpr {
                 left: Box::new(ast::Expression::Subpath(ast::SubpathExpr {
-                    expr: Box::new(ast::Expression::Identifier("arr".to_string())),
+                    expr: Box::new(ast::Expression::Identifier("arr".into())),
                     subpath: "a".to_string()
                 })),
                 op: ast::BinaryOp::Add,
@@ -7663,7 +9468,7 @@ mod group_by_clause {
                 function: ast::FunctionName::Avg,
                 args: ast::FunctionArguments::Args(vec![
                     ast::Expression::Subpath(ast::SubpathExpr {
-                        expr: Box::new(ast::Expression::Identifier("arr".to_string())),
+                        expr: Box::new(ast::Expression::Identifier("arr".into())),
                         subpath: "a".to_string()
                     })
                 ]),
@@ -7827,12 +9632,16 @@ mod subquery {
         ast, map,
         mir::{binding_tuple::DatasourceName, schema::SchemaCache, *},
         multimap,
+        parser::parse_query,
         schema::{Atomic, Document, Schema},
         set, unchecked_unique_linked_hash_map,
         usererror::UserError,
     };
     use lazy_static::lazy_static;
 
+    const MULTI_COLUMN_QUERY: &str =
+        "SELECT VALUE {'a': arr.a, 'b': arr.b} FROM [{'a': 1, 'b': 2}] AS arr";
+
     fn mir_array(scope: u16) -> Stage {
         Stage::Project(Project {
             is_add_fields: false,
@@ -8128,6 +9937,96 @@ mod subquery {
                 })
         },
     );
+    test_algebrize!(
+        doubly_nested_subquery_qualified_reference_to_outermost_datasource,
+        method = algebrize_expression,
+        in_implicit_type_conversion_context = false,
+        expected = Ok(Expression::Subquery(SubqueryExpr {
+            output_expr: Box::new(Expression::FieldAccess(FieldAccess {
+                expr: Box::new(Expression::Reference((DatasourceName::Bottom, 2u16).into())),
+                field: "b_0".to_string(),
+                is_nullable: false,
+            })),
+            subquery: Box::new(Stage::Project(Project {
+                is_add_fields: false,
+                source: Box::new(mir_array(2u16)),
+                expression: map! {
+                    (DatasourceName::Bottom, 2u16).into() => Expression::Document(unchecked_unique_linked_hash_map!{
+                        "b_0".into() => Expression::Subquery(SubqueryExpr {
+                            output_expr: Box::new(Expression::FieldAccess(FieldAccess {
+                                expr: Box::new(Expression::Reference((DatasourceName::Bottom, 3u16).into())),
+                                field: "c_0".to_string(),
+                                is_nullable: false,
+                            })),
+                            subquery: Box::new(Stage::Project(Project {
+                                is_add_fields: false,
+                                source: Box::new(mir_array(3u16)),
+                                expression: map! {
+                                    (DatasourceName::Bottom, 3u16).into() => Expression::Document(unchecked_unique_linked_hash_map!{
+                                        "c_0".into() => Expression::FieldAccess(FieldAccess {
+                                            expr: Box::new(Expression::Reference(("foo", 1u16).into())),
+                                            field: "b".into(),
+                                            is_nullable: false,
+                                        })
+                                    }.into())
+                                },
+                                cache: SchemaCache::new(),
+                            })),
+                            is_nullable: false,
+                        })
+                    }.into())
+                },
+                cache: SchemaCache::new(),
+            })),
+            is_nullable: false,
+        })),
+        input = ast::Expression::Subquery(Box::new(ast::Query::Select(ast::SelectQuery {
+            select_clause: ast::SelectClause {
+                set_quantifier: ast::SetQuantifier::All,
+                body: ast::SelectBody::Values(vec![ast::SelectValuesExpression::Expression(
+                    ast::Expression::Document(multimap! {
+                        "b_0".into() => ast::Expression::Subquery(Box::new(ast::Query::Select(ast::SelectQuery {
+                            select_clause: ast::SelectClause {
+                                set_quantifier: ast::SetQuantifier::All,
+                                body: ast::SelectBody::Values(vec![ast::SelectValuesExpression::Expression(
+                                    ast::Expression::Document(multimap! {
+                                        "c_0".into() => ast::Expression::Subpath(ast::SubpathExpr {
+                                            expr: Box::new(ast::Expression::Identifier("foo".into())),
+                                            subpath: "b".into(),
+                                        })
+                                    })
+                                )])
+                            },
+                            from_clause: Some(AST_ARRAY.clone()),
+                            where_clause: None,
+                            group_by_clause: None,
+                            having_clause: None,
+                            order_by_clause: None,
+                            limit: None,
+                            offset: None,
+                        })))
+                    })
+                )])
+            },
+            from_clause: Some(AST_ARRAY.clone()),
+            where_clause: None,
+            group_by_clause: None,
+            having_clause: None,
+            order_by_clause: None,
+            limit: None,
+            offset: None,
+        },))),
+        env = map! {
+            ("foo", 1u16).into() => Schema::Document( Document {
+                keys: map! {
+                    "b".into() => Schema::Atomic(Atomic::Integer),
+                },
+                required: set!{"b".to_string()},
+                additional_properties: false,
+                ..Default::default()
+                })
+        },
+    );
     test_algebrize!(
         degree_zero_unsat_output,
         method = algebrize_expression,
@@ -8224,6 +10123,23 @@ mod subquery {
             offset: None,
         },))),
     );
+    test_algebrize!(
+        multi_column_subquery_in_scalar_position_fails,
+        method = algebrize_expression,
+        in_implicit_type_conversion_context = false,
+        expected = Err(Error::InvalidSubqueryDegree),
+        expected_error_code = 3022,
+        input = ast::Expression::Subquery(Box::new(parse_query(MULTI_COLUMN_QUERY).unwrap())),
+    );
+    test_algebrize!(
+        multi_column_subquery_as_derived_table_succeeds,
+        method = algebrize_from_clause,
+        expected_pat = Ok(Stage::Derived(_)),
+        input = Some(ast::Datasource::Derived(ast::DerivedSource {
+            query: Box::new(parse_query(MULTI_COLUMN_QUERY).unwrap()),
+            alias: "d".into(),
+        })),
+    );
     test_algebrize!(
         star_degree_eq_1,
         method = algebrize_expression,
@@ -8625,12 +10541,13 @@ mod subquery {
         argument_only_evaluated_in_super_scope,
         method = algebrize_expression,
         in_implicit_type_conversion_context = false,
-        expected = Err(Error::FieldNotFound(
-            "a".into(),
-            None,
-            ClauseType::Unintialized,
-            0u16
-        )),
+        expected = Err(Error::FieldNotFound {
+            field: "a".into(),
+            found_fields: None,
+            clause_type: ClauseType::Unintialized,
+            scope_level: 0u16,
+            span: None,
+        }),
         expected_error_code = 3008,
         input = ast::Expression::SubqueryComparison(ast::SubqueryComparisonExpr {
             expr: Box::new(ast::Expression::Identifier("a".into())),
@@ -8779,25 +10696,25 @@ mod user_error_messages {
     mod field_not_found {
         test_user_error_messages! {
             no_found_fields,
-            input = Error::FieldNotFound("x".into(), None, ClauseType::Select, 1u16),
+            input = Error::FieldNotFound { field: "x".into(), found_fields: None, clause_type: ClauseType::Select, scope_level: 1u16, span: None },
             expected = "Field `x` of the `SELECT` clause at the 1 scope level not found.".to_string()
         }
 
         test_user_error_messages! {
             suggestions,
-            input = Error::FieldNotFound("foo".into(), Some(vec!["feo".to_string(), "fooo".to_string(), "aaa".to_string(), "bbb".to_string()]), ClauseType::Where, 1u16),
+            input = Error::FieldNotFound { field: "foo".into(), found_fields: Some(vec!["feo".to_string(), "fooo".to_string(), "aaa".to_string(), "bbb".to_string()]), clause_type: ClauseType::Where, scope_level: 1u16, span: None },
             expected =  "Field `foo` not found in the `WHERE` clause at the 1 scope level. Did you mean: feo, fooo".to_string()
         }
 
         test_user_error_messages! {
             no_suggestions,
-            input = Error::FieldNotFound("foo".into(), Some(vec!["aaa".to_string(), "bbb".to_string(), "ccc".to_string()]), ClauseType::Having, 16u16),
+            input = Error::FieldNotFound { field: "foo".into(), found_fields: Some(vec!["aaa".to_string(), "bbb".to_string(), "ccc".to_string()]), clause_type: ClauseType::Having, scope_level: 16u16, span: None },
             expected = "Field `foo` in the `HAVING` clause at the 16 scope level not found.".to_string()
         }
 
         test_user_error_messages! {
             exact_match_found,
-            input = Error::FieldNotFound("foo".into(), Some(vec!["foo".to_string()]), ClauseType::GroupBy, 0u16),
+            input = Error::FieldNotFound { field: "foo".into(), found_fields: Some(vec!["foo".to_string()]), clause_type: ClauseType::GroupBy, scope_level: 0u16, span: None },
             expected = "Unexpected edit distance of 0 found with input: foo and expected: [\"foo\"]"
         }
     }
@@ -8850,9 +10767,27 @@ mod user_error_messages {
     mod ambiguous_field {
         test_user_error_messages! {
             ambiguous_field,
-            input = Error::AmbiguousField("foo".into(), ClauseType::Select, 0u16),
+            input = Error::AmbiguousField {
+                field: "foo".into(),
+                datasources: vec![],
+                clause_type: ClauseType::Select,
+                scope_level: 0u16,
+                span: None,
+            },
             expected = "Field `foo` in the `SELECT` clause at the 0 scope level exists in multiple datasources and is ambiguous. Please qualify."
         }
+
+        test_user_error_messages! {
+            ambiguous_field_lists_qualified_candidates,
+            input = Error::AmbiguousField {
+                field: "a".into(),
+                datasources: vec![DatasourceName::Named("bar".into()), DatasourceName::Named("foo".into())],
+                clause_type: ClauseType::Where,
+                scope_level: 0u16,
+                span: None,
+            },
+            expected = "Field `a` in the `WHERE` clause at the 0 scope level exists in multiple datasources and is ambiguous. Please qualify as one of: `bar.a`, `foo.a`."
+        }
     }
 
     mod cannot_enumerate_all_field_paths {
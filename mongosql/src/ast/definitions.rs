@@ -251,18 +251,24 @@ pub enum Expression {
     Trim(TrimExpr),
     DateFunction(DateFunctionExpr),
     Extract(ExtractExpr),
+    Interval(IntervalExpr),
     Cast(CastExpr),
     Array(Vec<Expression>),
     Subquery(Box<Query>),
     Exists(Box<Query>),
     SubqueryComparison(SubqueryComparisonExpr),
+    QuantifiedComparison(QuantifiedComparisonExpr),
     Document(Vec<DocumentPair>),
     Access(AccessExpr),
     Subpath(SubpathExpr),
-    Identifier(String),
+    Identifier(Identifier),
     Is(IsExpr),
+    IsTruthValue(IsTruthValueExpr),
     Like(LikeExpr),
     Literal(Literal),
+    // DefaultArgument is only created by the optional parameters rewrite, for an optional
+    // function argument the query omitted.
+    DefaultArgument(Literal),
     StringConstructor(String),
     Tuple(Vec<Expression>),
     TypeAssertion(TypeAssertionExpr),
@@ -271,12 +277,27 @@ pub enum Expression {
 impl Expression {
     pub fn into_date_part(self) -> Option<DatePart> {
         match self {
-            Expression::Identifier(i) => i.as_str().try_into().ok(),
+            // EPOCH is only a valid date part for EXTRACT.
+            Expression::Identifier(i) => i
+                .name
+                .as_str()
+                .try_into()
+                .ok()
+                .filter(|date_part| *date_part != DatePart::Epoch),
             _ => None
         }
     }
 }
 
+/// A name that refers to a field, datasource, or collection. The span is that of the
+/// identifier in the query text, and is None for identifiers that were not
+/// parsed, such as those created by rewrites.
+#[derive(Debug, Clone)]
+pub struct Identifier {
+    pub name: String,
+    pub span: Option<Span>,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct DocumentPair {
     pub key: String,
@@ -287,6 +308,7 @@ pub struct DocumentPair {
 pub struct CastExpr {
     pub expr: Box<Expression>,
     pub to: Type,
+    pub format: Option<String>,
     pub on_null: Option<Box<Expression>>,
     pub on_error: Option<Box<Expression>>,
 }
@@ -309,6 +331,7 @@ pub struct BetweenExpr {
     pub arg: Box<Expression>,
     pub min: Box<Expression>,
     pub max: Box<Expression>,
+    pub symmetric: bool,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -338,6 +361,14 @@ pub struct SubqueryComparisonExpr {
     pub subquery: Box<Query>,
 }
 
+#[derive(PartialEq, Debug, Clone)]
+pub struct QuantifiedComparisonExpr {
+    pub expr: Box<Expression>,
+    pub op: ComparisonOp,
+    pub quantifier: SubqueryQuantifier,
+    pub values: Vec<Expression>,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct FunctionExpr {
     pub function: FunctionName,
@@ -367,6 +398,7 @@ pub enum DatePart {
     DayOfWeek,
     IsoWeek,
     IsoWeekday,
+    Epoch,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -382,10 +414,16 @@ pub struct ExtractExpr {
     pub arg: Box<Expression>,
 }
 
+#[derive(PartialEq, Debug, Clone)]
+pub struct IntervalExpr {
+    pub amount: Box<Expression>,
+    pub date_part: DatePart,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct TrimExpr {
     pub trim_spec: TrimSpec,
-    pub trim_chars: Box<Expression>,
+    pub trim_chars: Option<Box<Expression>>,
     pub arg: Box<Expression>,
 }
 
@@ -397,6 +435,7 @@ pub enum FunctionName {
     Avg,
     Count,
     First,
+    GroupConcat,
     Last,
     Max,
     MergeDocuments,
@@ -404,6 +443,8 @@ pub enum FunctionName {
     StddevPop,
     StddevSamp,
     Sum,
+    VarPop,
+    VarSamp,
 
     // Scalar functions.
     Abs,
@@ -415,9 +456,14 @@ pub enum FunctionName {
     CurrentTimestamp,
     Degrees,
     Floor,
+    IfNull,
+    Instr,
+    IsNull,
     Log,
     Log10,
+    Left,
     Lower,
+    LPad,
     LTrim,
     Mod,
     NullIf,
@@ -426,7 +472,9 @@ pub enum FunctionName {
     Pow,
     Radians,
     Replace,
+    Right,
     Round,
+    RPad,
     RTrim,
     Sin,
     Size,
@@ -515,6 +563,7 @@ impl TryFrom<&str> for DatePart {
             "MONTH" => Ok(DatePart::Month),
             "QUARTER" => Ok(DatePart::Quarter),
             "YEAR" => Ok(DatePart::Year),
+            "EPOCH" => Ok(DatePart::Epoch),
             _ => Err(format!("unknown date part {name}")),
         }
     }
@@ -545,12 +594,18 @@ impl TryFrom<&str> for FunctionName {
             "CURRENT_TIMESTAMP" => Ok(FunctionName::CurrentTimestamp),
             "DEGREES" => Ok(FunctionName::Degrees),
             "FIRST" => Ok(FunctionName::First),
+            "GROUP_CONCAT" => Ok(FunctionName::GroupConcat),
             "FLOOR" => Ok(FunctionName::Floor),
+            "IFNULL" => Ok(FunctionName::IfNull),
+            "INSTR" => Ok(FunctionName::Instr),
+            "ISNULL" => Ok(FunctionName::IsNull),
             "LAST" => Ok(FunctionName::Last),
             "LCASE" => Ok(FunctionName::Lower),
+            "LEFT" => Ok(FunctionName::Left),
             "LOG" => Ok(FunctionName::Log),
             "LOG10" => Ok(FunctionName::Log10),
             "LOWER" => Ok(FunctionName::Lower),
+            "LPAD" => Ok(FunctionName::LPad),
             "LTRIM" => Ok(FunctionName::LTrim),
             "MAX" => Ok(FunctionName::Max),
             "MERGE_DOCUMENTS" => Ok(FunctionName::MergeDocuments),
@@ -558,13 +613,16 @@ impl TryFrom<&str> for FunctionName {
             "MOD" => Ok(FunctionName::Mod),
             "NOW" => Ok(FunctionName::CurrentTimestamp),
             "NULLIF" => Ok(FunctionName::NullIf),
+            "NVL" => Ok(FunctionName::IfNull),
             "OCTET_LENGTH" => Ok(FunctionName::OctetLength),
             "POSITION" => Ok(FunctionName::Position),
             "POW" => Ok(FunctionName::Pow),
             "POWER" => Ok(FunctionName::Pow),
             "RADIANS" => Ok(FunctionName::Radians),
             "REPLACE" => Ok(FunctionName::Replace),
+            "RIGHT" => Ok(FunctionName::Right),
             "ROUND" => Ok(FunctionName::Round),
+            "RPAD" => Ok(FunctionName::RPad),
             "RTRIM" => Ok(FunctionName::RTrim),
             "SIN" => Ok(FunctionName::Sin),
             "SIZE" => Ok(FunctionName::Size),
@@ -578,6 +636,8 @@ impl TryFrom<&str> for FunctionName {
             "TAN" => Ok(FunctionName::Tan),
             "UCASE" => Ok(FunctionName::Upper),
             "UPPER" => Ok(FunctionName::Upper),
+            "VAR_POP" => Ok(FunctionName::VarPop),
+            "VAR_SAMP" => Ok(FunctionName::VarSamp),
 
             "DATEADD" => Ok(FunctionName::DateAdd),
             "DATEDIFF" => Ok(FunctionName::DateDiff),
@@ -618,11 +678,17 @@ impl FunctionName {
             FunctionName::CurrentTimestamp => "CURRENT_TIMESTAMP",
             FunctionName::Degrees => "DEGREES",
             FunctionName::First => "FIRST",
+            FunctionName::GroupConcat => "GROUP_CONCAT",
             FunctionName::Floor => "FLOOR",
             FunctionName::Last => "LAST",
+            FunctionName::IfNull => "IFNULL",
+            FunctionName::Instr => "INSTR",
+            FunctionName::IsNull => "ISNULL",
+            FunctionName::Left => "LEFT",
             FunctionName::Log => "LOG",
             FunctionName::Log10 => "LOG10",
             FunctionName::Lower => "LOWER",
+            FunctionName::LPad => "LPAD",
             FunctionName::LTrim => "LTRIM",
             FunctionName::Max => "MAX",
             FunctionName::MergeDocuments => "MERGE_DOCUMENTS",
@@ -634,7 +700,9 @@ impl FunctionName {
             FunctionName::Pow => "POW",
             FunctionName::Radians => "RADIANS",
             FunctionName::Replace => "REPLACE",
+            FunctionName::Right => "RIGHT",
             FunctionName::Round => "ROUND",
+            FunctionName::RPad => "RPAD",
             FunctionName::RTrim => "RTRIM",
             FunctionName::Size => "SIZE",
             FunctionName::Sin => "SIN",
@@ -647,6 +715,8 @@ impl FunctionName {
             FunctionName::Sum => "SUM",
             FunctionName::Tan => "TAN",
             FunctionName::Upper => "UPPER",
+            FunctionName::VarPop => "VAR_POP",
+            FunctionName::VarSamp => "VAR_SAMP",
             FunctionName::DateAdd => "DATEADD",
             FunctionName::DateDiff => "DATEDIFF",
             FunctionName::DateTrunc => "DATETRUNC",
@@ -671,13 +741,16 @@ impl FunctionName {
             | FunctionName::Avg
             | FunctionName::Count
             | FunctionName::First
+            | FunctionName::GroupConcat
             | FunctionName::Last
             | FunctionName::Max
             | FunctionName::MergeDocuments
             | FunctionName::Min
             | FunctionName::StddevPop
             | FunctionName::StddevSamp
-            | FunctionName::Sum => true,
+            | FunctionName::Sum
+            | FunctionName::VarPop
+            | FunctionName::VarSamp => true,
 
             FunctionName::Abs
             | FunctionName::BitLength
@@ -688,9 +761,14 @@ impl FunctionName {
             | FunctionName::CurrentTimestamp
             | FunctionName::Degrees
             | FunctionName::Floor
+            | FunctionName::IfNull
+            | FunctionName::Instr
+            | FunctionName::IsNull
             | FunctionName::Log
             | FunctionName::Log10
+            | FunctionName::Left
             | FunctionName::Lower
+            | FunctionName::LPad
             | FunctionName::LTrim
             | FunctionName::Mod
             | FunctionName::NullIf
@@ -699,7 +777,9 @@ impl FunctionName {
             | FunctionName::Pow
             | FunctionName::Radians
             | FunctionName::Replace
+            | FunctionName::Right
             | FunctionName::Round
+            | FunctionName::RPad
             | FunctionName::RTrim
             | FunctionName::Sin
             | FunctionName::Size
@@ -773,6 +853,19 @@ pub struct IsExpr {
     pub target_type: TypeOrMissing,
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy, VariantCount)]
+pub enum TruthValue {
+    True,
+    False,
+    Unknown,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct IsTruthValueExpr {
+    pub expr: Box<Expression>,
+    pub value: TruthValue,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct LikeExpr {
     pub expr: Box<Expression>,
@@ -800,6 +893,7 @@ pub enum BinaryOp {
     Concat,
     Div,
     In,
+    Mod,
     Mul,
     NotIn,
     Or,
@@ -816,6 +910,7 @@ impl BinaryOp {
             Concat => "Concat",
             Div => "Div",
             In => "In",
+            Mod => "Mod",
             Mul => "Mul",
             NotIn => "NotIn",
             Or => "Or",
@@ -915,3 +1010,30 @@ pub enum Type {
 }
 
 } // end of generate_visitors! block
+
+/// A range of byte offsets into the query text, with an exclusive end.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+// Spans only record where an identifier came from, so they are ignored when
+// comparing asts.
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl From<String> for Identifier {
+    fn from(name: String) -> Self {
+        Identifier { name, span: None }
+    }
+}
+
+impl From<&str> for Identifier {
+    fn from(name: &str) -> Self {
+        name.to_string().into()
+    }
+}
@@ -52,6 +52,7 @@ lazy_static! {
         r"(?i)from$",
         r"(?i)group$",
         r"(?i)having$",
+        r"(?i)ilike$",
         r"(?i)in$",
         r"(?i)index$",
         r"(?i)inner$",
@@ -72,6 +73,7 @@ lazy_static! {
         r"(?i)missing$",
         r"(?i)natural$",
         r"(?i)not$",
+        r"(?i)not\s+ilike$",
         r"(?i)not\s+in$",
         r"(?i)not\s+like$",
         r"(?i)null$",
@@ -96,6 +98,7 @@ lazy_static! {
         r"(?i)string$",
         r"(?i)substring$",
         r"(?i)symbol$",
+        r"(?i)symmetric$",
         r"(?i)then$",
         r"(?i)timestamp$",
         r"(?i)trailing$",
@@ -103,6 +106,7 @@ lazy_static! {
         r"(?i)true$",
         r"(?i)undefined$",
         r"(?i)union$",
+        r"(?i)unknown$",
         r"(?i)unwind$",
         r"(?i)value$",
         r"(?i)values$",
@@ -563,7 +567,7 @@ impl BinaryOp {
             Comparison(_) => Tier8,
             Concat => Tier9,
             Add | Sub => Tier10,
-            Mul | Div => Tier11,
+            Mul | Div | Mod => Tier11,
         }
     }
 }
@@ -603,6 +607,12 @@ impl IsExpr {
     }
 }
 
+impl IsTruthValueExpr {
+    fn get_tier(&self) -> ExpressionTier {
+        ExpressionTier::Tier4
+    }
+}
+
 impl BetweenExpr {
     fn get_tier(&self) -> ExpressionTier {
         ExpressionTier::Tier7
@@ -615,6 +625,12 @@ impl SubqueryComparisonExpr {
     }
 }
 
+impl QuantifiedComparisonExpr {
+    fn get_tier(&self) -> ExpressionTier {
+        ExpressionTier::Tier8
+    }
+}
+
 impl TypeAssertionExpr {
     fn get_tier(&self) -> ExpressionTier {
         ExpressionTier::Tier13
@@ -628,8 +644,10 @@ impl Expression {
         match self {
             Like(l) => l.get_tier(),
             Is(i) => i.get_tier(),
+            IsTruthValue(i) => i.get_tier(),
             Between(b) => b.get_tier(),
             SubqueryComparison(s) => s.get_tier(),
+            QuantifiedComparison(q) => q.get_tier(),
             Binary(b) => b.get_tier(),
             Unary(u) => u.get_tier(),
             TypeAssertion(t) => t.get_tier(),
@@ -638,8 +656,8 @@ impl Expression {
             // formatting for all the following is handled specially and will never conditionally
             // wrap arguments in parentheses
             Array(_) | Case(_) | Cast(_) | Document(_) | Exists(_) | Function(_) | Trim(_)
-            | DateFunction(_) | Extract(_) | Identifier(_) | Literal(_) | StringConstructor(_)
-            | Subquery(_) | Tuple(_) => Bottom,
+            | DateFunction(_) | Extract(_) | Interval(_) | Identifier(_) | Literal(_)
+            | DefaultArgument(_) | StringConstructor(_) | Subquery(_) | Tuple(_) => Bottom,
         }
     }
 }
@@ -660,16 +678,18 @@ impl PrettyPrint for Expression {
     fn pretty_print(&self) -> Result<String> {
         use Expression::*;
         match self {
-            Identifier(s) => Ok(identifier_to_string(s)),
+            Identifier(i) => Ok(identifier_to_string(&i.name)),
             Is(i) => i.pretty_print(),
+            IsTruthValue(i) => i.pretty_print(),
             Like(l) => l.pretty_print(),
             TypeAssertion(t) => t.pretty_print(),
             Cast(c) => c.pretty_print(),
-            Literal(l) => l.pretty_print(),
+            Literal(l) | DefaultArgument(l) => l.pretty_print(),
             StringConstructor(s) => Ok(format!("'{}'", escape_string_literal(s))),
             Unary(u) => u.pretty_print(),
             Binary(b) => b.pretty_print(),
             Extract(e) => e.pretty_print(),
+            Interval(i) => i.pretty_print(),
             DateFunction(d) => d.pretty_print(),
             Trim(t) => t.pretty_print(),
             Function(fun) => fun.pretty_print(),
@@ -705,6 +725,7 @@ impl PrettyPrint for Expression {
             Subquery(q) => Ok(format!("({})", q.pretty_print()?)),
             Exists(q) => Ok(format!("EXISTS({})", q.pretty_print()?)),
             SubqueryComparison(sc) => sc.pretty_print(),
+            QuantifiedComparison(qc) => qc.pretty_print(),
         }
     }
 }
@@ -720,6 +741,17 @@ impl PrettyPrint for IsExpr {
     }
 }
 
+impl PrettyPrint for IsTruthValueExpr {
+    fn pretty_print(&self) -> Result<String> {
+        let formatted_expr = self.get_tier().format_sub_expr(&self.expr)?;
+        match self.value {
+            TruthValue::True => Ok(format!("{formatted_expr} IS TRUE")),
+            TruthValue::False => Ok(format!("{formatted_expr} IS FALSE")),
+            TruthValue::Unknown => Ok(format!("{formatted_expr} IS UNKNOWN")),
+        }
+    }
+}
+
 impl PrettyPrint for LikeExpr {
     fn pretty_print(&self) -> Result<String> {
         let (formatted_expr, formatted_pattern) = (
@@ -752,9 +784,13 @@ impl PrettyPrint for TypeAssertionExpr {
 impl PrettyPrint for CastExpr {
     fn pretty_print(&self) -> Result<String> {
         Ok(format!(
-            "CAST({} AS {}{}{})",
+            "CAST({} AS {}{}{}{})",
             self.expr.pretty_print()?,
             self.to.pretty_print()?,
+            self.format.as_ref().map_or("".to_string(), |f| format!(
+                " FORMAT '{}'",
+                escape_string_literal(f)
+            )),
             self.on_null
                 .as_ref()
                 .map_or(Ok("".to_string()), |x| Ok(format!(
@@ -823,7 +859,7 @@ fn pretty_print_position(args: &FunctionArguments) -> Result<String> {
     match args {
         FunctionArguments::Star => unreachable!(),
         FunctionArguments::Args(args) => {
-            assert!(args.len() == 2);
+            assert!(args.len() == 2 || args.len() == 3);
             let tier = BinaryOp::In.get_tier();
             // This assumes the In operator is left associative, which is currently true.  If it
             // were right associative we would need to use strict_format_sub_expr on the right
@@ -832,7 +868,13 @@ fn pretty_print_position(args: &FunctionArguments) -> Result<String> {
                 tier.strict_format_sub_expr(&args[0])?,
                 tier.format_sub_expr(&args[1])?,
             );
-            Ok(format!("POSITION({formatted_left} IN {formatted_right})"))
+            match args.get(2) {
+                None => Ok(format!("POSITION({formatted_left} IN {formatted_right})")),
+                Some(start) => Ok(format!(
+                    "POSITION({formatted_left} IN {formatted_right} FROM {})",
+                    start.pretty_print()?
+                )),
+            }
         }
     }
 }
@@ -891,6 +933,22 @@ impl PrettyPrint for SubqueryComparisonExpr {
     }
 }
 
+impl PrettyPrint for QuantifiedComparisonExpr {
+    fn pretty_print(&self) -> Result<String> {
+        let formatted_expr = self.get_tier().format_sub_expr(&self.expr)?;
+        Ok(format!(
+            "{formatted_expr} {} {}({})",
+            self.op.pretty_print()?,
+            self.quantifier.pretty_print()?,
+            self.values
+                .iter()
+                .map(|v| v.pretty_print())
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ))
+    }
+}
+
 impl PrettyPrint for SubqueryQuantifier {
     fn pretty_print(&self) -> Result<String> {
         Ok(match self {
@@ -948,6 +1006,7 @@ impl PrettyPrint for DatePart {
             DatePart::DayOfYear => "DAY_OF_YEAR",
             DatePart::DayOfWeek => "DAY_OF_WEEK",
             DatePart::Millisecond => "MILLISECOND",
+            DatePart::Epoch => "EPOCH",
         }
         .to_string())
     }
@@ -972,8 +1031,9 @@ impl PrettyPrint for BetweenExpr {
             between_tier.format_sub_expr(&self.min)?,
             between_tier.format_sub_expr(&self.max)?,
         );
+        let symmetric = if self.symmetric { "SYMMETRIC " } else { "" };
         Ok(format!(
-            "{formatted_expr} BETWEEN {formatted_min} AND {formatted_max}"
+            "{formatted_expr} BETWEEN {symmetric}{formatted_min} AND {formatted_max}"
         ))
     }
 }
@@ -1055,10 +1115,24 @@ impl PrettyPrint for Literal {
 impl PrettyPrint for UnaryExpr {
     fn pretty_print(&self) -> Result<String> {
         let formatted_expr = self.get_tier().strict_format_sub_expr(&self.expr)?;
+        // NOT followed by an operand starting with `in`, `like`, or `ilike` (e.g. an
+        // identifier such as `interval`) would be lexed as NOT IN, NOT LIKE, or NOT
+        // ILIKE, so the operand is parenthesized to keep it a separate token.
+        let formatted_expr = match self.op {
+            UnaryOp::Not if starts_with_negatable_keyword(&formatted_expr) => {
+                format!("({formatted_expr})")
+            }
+            _ => formatted_expr,
+        };
         Ok(format!("{}{formatted_expr}", self.op.pretty_print()?))
     }
 }
 
+fn starts_with_negatable_keyword(s: &str) -> bool {
+    let lower = s.to_ascii_lowercase();
+    lower.starts_with("in") || lower.starts_with("like") || lower.starts_with("ilike")
+}
+
 impl PrettyPrint for UnaryOp {
     fn pretty_print(&self) -> Result<String> {
         Ok(match self {
@@ -1080,6 +1154,16 @@ impl PrettyPrint for ExtractExpr {
     }
 }
 
+impl PrettyPrint for IntervalExpr {
+    fn pretty_print(&self) -> Result<String> {
+        Ok(format!(
+            "INTERVAL {} {}",
+            self.amount.pretty_print()?,
+            self.date_part.pretty_print()?
+        ))
+    }
+}
+
 impl PrettyPrint for DateFunctionName {
     fn pretty_print(&self) -> Result<String> {
         Ok(match self {
@@ -1113,16 +1197,16 @@ impl PrettyPrint for TrimExpr {
             TrimSpec::Leading => "LEADING",
             TrimSpec::Trailing => "TRAILING",
         };
-        match *self.trim_chars {
-            Expression::StringConstructor(ref s) if s.as_str() == " " => Ok(format!(
-                "TRIM({} ' ' FROM {})",
+        match self.trim_chars {
+            None => Ok(format!(
+                "TRIM({} FROM {})",
                 trim_spec,
                 self.arg.pretty_print()?
             )),
-            _ => Ok(format!(
+            Some(ref trim_chars) => Ok(format!(
                 "TRIM({} {} FROM {})",
                 trim_spec,
-                self.trim_chars.pretty_print()?,
+                trim_chars.pretty_print()?,
                 self.arg.pretty_print()?
             )),
         }
@@ -1157,6 +1241,7 @@ impl PrettyPrint for BinaryOp {
             BinaryOp::Sub => "-".to_string(),
             BinaryOp::Mul => "*".to_string(),
             BinaryOp::Div => "/".to_string(),
+            BinaryOp::Mod => "%".to_string(),
             BinaryOp::In => "IN".to_string(),
             BinaryOp::NotIn => "NOT IN".to_string(),
         })
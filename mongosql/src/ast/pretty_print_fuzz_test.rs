@@ -140,7 +140,7 @@ mod arbitrary {
         if bool::arbitrary(g) {
             Expression::Literal(Literal::arbitrary(g))
         } else {
-            Expression::Identifier(arbitrary_identifier(g))
+            Expression::Identifier(arbitrary_identifier(g).into())
         }
     }
 
@@ -500,7 +500,7 @@ mod arbitrary {
                 0 => {
                     // The parser only supports Identifiers or Subpath expressions for PATH.
                     match bool::arbitrary(g) {
-                        true => Self::Path(Expression::Identifier(arbitrary_identifier(g))),
+                        true => Self::Path(Expression::Identifier(arbitrary_identifier(g).into())),
                         false => Self::Path(Expression::Subpath(SubpathExpr::arbitrary(g))),
                     }
                 }
@@ -545,13 +545,18 @@ mod arbitrary {
                 ),
                 14 => Self::Access(AccessExpr::arbitrary(nested_g)),
                 15 => Self::Subpath(SubpathExpr::arbitrary(nested_g)),
-                16 => Self::Identifier(arbitrary_identifier(g)),
+                16 => Self::Identifier(arbitrary_identifier(g).into()),
                 17 => Self::Is(IsExpr::arbitrary(nested_g)),
                 18 => Self::Like(LikeExpr::arbitrary(nested_g)),
                 19 => Self::Literal(Literal::arbitrary(nested_g)),
                 20 => Self::StringConstructor(arbitrary_string(g)),
                 21 => Self::Tuple((1..4).map(|_| Self::arbitrary(nested_g)).collect()),
                 22 => Self::TypeAssertion(TypeAssertionExpr::arbitrary(nested_g)),
+                23 => Self::QuantifiedComparison(QuantifiedComparisonExpr::arbitrary(nested_g)),
+                24 => Self::IsTruthValue(IsTruthValueExpr::arbitrary(nested_g)),
+                25 => Self::Interval(IntervalExpr::arbitrary(nested_g)),
+                // We never parse to DefaultArgument, it is only created by rewrites
+                26 => Self::Literal(Literal::arbitrary(nested_g)),
                 _ => panic!("missing Expression variant(s)"),
             }
         }
@@ -571,6 +576,7 @@ mod arbitrary {
             Self {
                 expr: Box::new(Expression::arbitrary(g)),
                 to: Type::arbitrary(g),
+                format: arbitrary_optional(g, arbitrary_string),
                 on_null: Option::arbitrary(g),
                 on_error: Option::arbitrary(g),
             }
@@ -602,6 +608,7 @@ mod arbitrary {
                 arg: Box::new(Expression::arbitrary(g)),
                 min: Box::new(Expression::arbitrary(g)),
                 max: Box::new(Expression::arbitrary(g)),
+                symmetric: bool::arbitrary(g),
             }
         }
     }
@@ -649,6 +656,17 @@ mod arbitrary {
         }
     }
 
+    impl Arbitrary for QuantifiedComparisonExpr {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Self {
+                expr: Box::new(Expression::arbitrary(g)),
+                op: ComparisonOp::arbitrary(g),
+                quantifier: SubqueryQuantifier::arbitrary(g),
+                values: (1..4).map(|_| Expression::arbitrary(g)).collect(),
+            }
+        }
+    }
+
     impl Arbitrary for FunctionExpr {
         fn arbitrary(g: &mut Gen) -> Self {
             // CurrentTimestamp, Position, and Substring need to be special-cased
@@ -667,13 +685,14 @@ mod arbitrary {
                     set_quantifier: None,
                 },
 
-                // Position can syntactically accept exactly 2 arguments.
+                // Position can syntactically accept 2 or 3 arguments.
                 1 => Self {
                     function: FunctionName::Position,
-                    args: FunctionArguments::Args(vec![
-                        Expression::arbitrary(g),
-                        Expression::arbitrary(g),
-                    ]),
+                    args: FunctionArguments::Args(
+                        (0..rand_len(2, 3))
+                            .map(|_| Expression::arbitrary(g))
+                            .collect(),
+                    ),
                     set_quantifier: None,
                 },
 
@@ -707,6 +726,18 @@ mod arbitrary {
             }
         }
     }
+
+    impl Arbitrary for IntervalExpr {
+        fn arbitrary(g: &mut Gen) -> Self {
+            // The INTERVAL amount can only be an unsigned integer literal.
+            Self {
+                amount: Box::new(Expression::Literal(Literal::Integer(
+                    u16::arbitrary(g) as i32
+                ))),
+                date_part: DatePart::arbitrary(g),
+            }
+        }
+    }
     impl Arbitrary for DateFunctionName {
         fn arbitrary(g: &mut Gen) -> Self {
             // Intentionally omitting Diff because it cannot be built
@@ -751,7 +782,7 @@ mod arbitrary {
         fn arbitrary(g: &mut Gen) -> Self {
             Self {
                 trim_spec: TrimSpec::arbitrary(g),
-                trim_chars: Box::new(Expression::arbitrary(g)),
+                trim_chars: Option::arbitrary(g),
                 arg: Box::new(Expression::arbitrary(g)),
             }
         }
@@ -823,6 +854,16 @@ mod arbitrary {
                 49 => Self::Second,
                 50 => Self::Millisecond,
                 51 => Self::Replace,
+                52 => Self::Left,
+                53 => Self::Right,
+                54 => Self::LPad,
+                55 => Self::RPad,
+                56 => Self::Instr,
+                57 => Self::IfNull,
+                58 => Self::IsNull,
+                59 => Self::VarPop,
+                60 => Self::VarSamp,
+                61 => Self::GroupConcat,
                 _ => panic!("missing FunctionName variant(s)"),
             }
         }
@@ -845,8 +886,8 @@ mod arbitrary {
 
     impl Arbitrary for DatePart {
         fn arbitrary(g: &mut Gen) -> Self {
-            let rng = &(0..Self::VARIANT_COUNT - 4).collect::<Vec<_>>();
-            // Intentionally omitting Quarter, DayOfYear, IsoWeek, and IsoWeekday so
+            let rng = &(0..Self::VARIANT_COUNT - 5).collect::<Vec<_>>();
+            // Intentionally omitting Quarter, DayOfYear, IsoWeek, IsoWeekday, and Epoch so
             // both Extract and DateFunction can utilize this method.
             match g.choose(rng).unwrap() {
                 0 => Self::Year,
@@ -890,7 +931,7 @@ mod arbitrary {
         //     the parser rejecting expressions like 1.a, for example
         fn arbitrary(g: &mut Gen) -> Self {
             Self {
-                expr: Box::new(Expression::Identifier(arbitrary_identifier(g))),
+                expr: Box::new(Expression::Identifier(arbitrary_identifier(g).into())),
                 subpath: arbitrary_identifier(g),
             }
         }
@@ -917,6 +958,27 @@ mod arbitrary {
         }
     }
 
+    impl Arbitrary for TruthValue {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let rng = &(0..Self::VARIANT_COUNT).collect::<Vec<_>>();
+            match g.choose(rng).unwrap() {
+                0 => Self::True,
+                1 => Self::False,
+                2 => Self::Unknown,
+                _ => panic!("missing TruthValue variant(s)"),
+            }
+        }
+    }
+
+    impl Arbitrary for IsTruthValueExpr {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Self {
+                expr: Box::new(Expression::arbitrary(g)),
+                value: TruthValue::arbitrary(g),
+            }
+        }
+    }
+
     impl Arbitrary for LikeExpr {
         fn arbitrary(g: &mut Gen) -> Self {
             let escape = match char::arbitrary(g) {
@@ -961,11 +1023,12 @@ mod arbitrary {
                 2 => Self::Concat,
                 3 => Self::Div,
                 4 => Self::In,
-                5 => Self::Mul,
-                6 => Self::NotIn,
-                7 => Self::Or,
-                8 => Self::Sub,
-                9 => Self::Comparison(ComparisonOp::arbitrary(g)),
+                5 => Self::Mod,
+                6 => Self::Mul,
+                7 => Self::NotIn,
+                8 => Self::Or,
+                9 => Self::Sub,
+                10 => Self::Comparison(ComparisonOp::arbitrary(g)),
                 _ => panic!("missing BinaryOp variant(s)"),
             }
         }
@@ -1032,10 +1095,15 @@ mod arbitrary {
                     Self::Positional(p)
                 }
                 1 => {
-                    let rng = &(0..2).collect::<Vec<i32>>();
+                    let rng = &(0..3).collect::<Vec<i32>>();
                     Self::Simple(match g.choose(rng).unwrap() {
-                        0 => Expression::Identifier(arbitrary_identifier(g)),
+                        0 => Expression::Identifier(arbitrary_identifier(g).into()),
                         1 => Expression::Subpath(SubpathExpr::arbitrary(g)),
+                        2 => Expression::Binary(BinaryExpr {
+                            left: Box::new(Expression::Identifier(arbitrary_identifier(g).into())),
+                            op: BinaryOp::Add,
+                            right: Box::new(Expression::Identifier(arbitrary_identifier(g).into())),
+                        }),
                         _ => panic!(),
                     })
                 }
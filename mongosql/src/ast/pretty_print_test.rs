@@ -529,6 +529,11 @@ mod is {
         expected = "true AND x IS INT",
         input = "true AND (x IS int)"
     );
+    expression_printer_test!(
+        truth_value,
+        expected = "NOT x IS FALSE",
+        input = "x is not false"
+    );
 }
 
 mod like {
@@ -712,6 +717,11 @@ mod cast_and_assert {
         expected = "CAST(x AS INT, 'bar' ON NULL, 'foo' ON ERROR)",
         input = "CAST(x as int, 'bar' ON NULL, 'foo' ON ERROR)"
     );
+    expression_printer_test!(
+        date_with_format_on_null,
+        expected = "CAST(x AS DATE FORMAT '%Y-%m-%d', 'bar' ON NULL)",
+        input = "CAST(x as date format '%Y-%m-%d', 'bar' ON NULL)"
+    );
 }
 
 mod literal {
@@ -753,6 +763,16 @@ mod unary {
         expected = "4 - - SUM(bar)",
         input = "4 - - SUM(bar)"
     );
+    expression_printer_test!(
+        not_identifier_starting_with_in,
+        expected = "NOT (interval)",
+        input = "NOT (interval)"
+    );
+    expression_printer_test!(
+        not_identifier_starting_with_like,
+        expected = "NOT (likes)",
+        input = "NOT (likes)"
+    );
 }
 
 mod binary {
@@ -817,6 +837,12 @@ mod binary {
     expression_printer_test!(sub, expected = "hello - world", input = "hello - world");
     expression_printer_test!(mul, expected = "hello * world", input = "hello * world");
     expression_printer_test!(div, expected = "hello / world", input = "hello / world");
+    expression_printer_test!(modulo, expected = "hello % world", input = "hello % world");
+    expression_printer_test!(
+        add_interval,
+        expected = "hello + INTERVAL 3 DAY",
+        input = "hello + interval 3 day"
+    );
     expression_printer_test!(
         binary_in,
         expected = "hello IN world",
@@ -904,7 +930,7 @@ mod function {
 
         expression_printer_test!(
             trim_basic,
-            expected = "TRIM(BOTH ' ' FROM hello)",
+            expected = "TRIM(BOTH FROM hello)",
             input = "TRIM(hello)"
         );
         expression_printer_test!(
@@ -914,17 +940,17 @@ mod function {
         );
         expression_printer_test!(
             trim_explicit_both,
-            expected = "TRIM(BOTH ' ' FROM hello)",
+            expected = "TRIM(BOTH FROM hello)",
             input = "TRIM(BOTH FROM hello)"
         );
         expression_printer_test!(
             trim_leading,
-            expected = "TRIM(LEADING ' ' FROM hello)",
+            expected = "TRIM(LEADING FROM hello)",
             input = "TRIM(LEADING FROM hello)"
         );
         expression_printer_test!(
             trim_trailing,
-            expected = "TRIM(TRAILING ' ' FROM hello)",
+            expected = "TRIM(TRAILING FROM hello)",
             input = "TRIM(TRAILING FROM hello)"
         );
         expression_printer_test!(
@@ -1002,6 +1028,16 @@ mod position {
         expected = "POSITION('x' IN ('y' IS DOCUMENT))",
         input = "position('x' in ('y' is document))"
     );
+    expression_printer_test!(
+        with_start,
+        expected = "POSITION('x' IN y FROM 3)",
+        input = "position('x' in y from 3)"
+    );
+    expression_printer_test!(
+        comma_form_with_start,
+        expected = "POSITION('x' IN y FROM a + 1)",
+        input = "position('x', y, a + 1)"
+    );
 }
 
 mod access {
@@ -1121,6 +1157,16 @@ mod subquery {
         expected = "x <> ALL(SELECT * UNION SELECT *)",
         input = "x!=ALL(SELECT * UNION SELECT *)"
     );
+    expression_printer_test!(
+        eq_any_value_list,
+        expected = "x = ANY(1, 2, 3)",
+        input = "x=any (1,2,3)"
+    );
+    expression_printer_test!(
+        gt_all_value_list,
+        expected = "x > ALL(a, b + 1)",
+        input = "x>ALL(a,b+1)"
+    );
 }
 
 mod precedence_tests {
@@ -1135,6 +1181,11 @@ mod precedence_tests {
         expected = "x BETWEEN y AND (z IS INT)",
         input = "x BETWEEN y AND (z IS INT)"
     );
+    expression_printer_test!(
+        between_symmetric,
+        expected = "x BETWEEN SYMMETRIC y AND z",
+        input = "x between symmetric y and z"
+    );
     expression_printer_test!(
         in_is_lower_prec_than_between,
         expected = "x BETWEEN (y IN z) AND p",
@@ -94,7 +94,8 @@ impl Visitor for CaseElseVisitor {
     }
 }
 
-/// This visitor rewrites explicit default values for functions when not specified.
+/// This visitor rewrites explicit default values for functions when not specified. The defaults
+/// are DefaultArguments so that later passes can tell them apart from arguments in the query.
 /// For SUBSTRING, a default third argument of -1 is added when only two arguments are present.
 /// For CURRENT_TIMESTAMP, the default precision value of 6 is set when no argument is present.
 struct FunctionVisitor;
@@ -106,7 +107,7 @@ impl Visitor for FunctionVisitor {
                 ast::FunctionArguments::Args(mut ve) => {
                     let arguments = match ve.len() {
                         2 => {
-                            ve.push(ast::Expression::Literal(ast::Literal::Integer(-1)));
+                            ve.push(ast::Expression::DefaultArgument(ast::Literal::Integer(-1)));
                             ve
                         }
                         _ => ve,
@@ -121,7 +122,7 @@ impl Visitor for FunctionVisitor {
                 ast::FunctionArguments::Star => unreachable!(),
                 ast::FunctionArguments::Args(ve) => {
                     let precision = if ve.is_empty() {
-                        vec![ast::Expression::Literal(ast::Literal::Integer(6))]
+                        vec![ast::Expression::DefaultArgument(ast::Literal::Integer(6))]
                     } else {
                         ve
                     };
@@ -91,7 +91,7 @@ impl Visitor for ScalarFunctionsVisitor {
                     Expression::Trim(TrimExpr {
                         trim_spec: function.try_into().unwrap(),
                         arg,
-                        trim_chars: Box::new(Expression::StringConstructor(" ".to_string())),
+                        trim_chars: None,
                     })
                 }
                 FunctionName::Log => match args.len() {
@@ -1047,7 +1047,7 @@ mod scalar_functions {
     test_rewrite!(
         ltrim,
         pass = ScalarFunctionsRewritePass,
-        expected = Ok("SELECT TRIM(LEADING ' ' FROM ' stuff ')"),
+        expected = Ok("SELECT TRIM(LEADING FROM ' stuff ')"),
         input = "SELECT LTRIM(' stuff ')",
     );
     test_rewrite!(
@@ -1063,7 +1063,7 @@ mod scalar_functions {
     test_rewrite!(
         rtrim,
         pass = ScalarFunctionsRewritePass,
-        expected = Ok("SELECT TRIM(TRAILING ' ' FROM ' stuff ')"),
+        expected = Ok("SELECT TRIM(TRAILING FROM ' stuff ')"),
         input = "SELECT RTRIM(' stuff ')",
     );
     test_rewrite!(
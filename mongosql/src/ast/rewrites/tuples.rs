@@ -87,7 +87,7 @@ impl InTupleRewriteVisitor {
         let select_clause = SelectClause {
             set_quantifier: SetQuantifier::All,
             body: SelectBody::Standard(vec![SelectExpression::Expression(
-                OptionallyAliasedExpr::Unaliased(Expression::Identifier("_1".to_string())),
+                OptionallyAliasedExpr::Unaliased(Expression::Identifier("_1".into())),
             )]),
         };
 
@@ -204,7 +204,7 @@ mod is_simple_field_ref_expr_test {
     test_is_simple_field_ref_expr!(
         simple_field_ref,
         expected = true,
-        input = Expression::Identifier("foo".to_string())
+        input = Expression::Identifier("foo".into())
     );
 
     test_is_simple_field_ref_expr!(
@@ -218,7 +218,7 @@ mod is_simple_field_ref_expr_test {
         expected = true,
         input = Expression::Access(AccessExpr {
             expr: Box::new(Expression::Subpath(SubpathExpr {
-                expr: Box::new(Expression::Identifier("foo2".to_string())),
+                expr: Box::new(Expression::Identifier("foo2".into())),
                 subpath: "bar".to_string(),
             })),
             subfield: Box::new(Expression::StringConstructor("foo1".to_string())),
@@ -241,7 +241,7 @@ mod is_simple_field_ref_expr_test {
         access_subfield_is_not_string,
         expected = false,
         input = Expression::Access(AccessExpr {
-            expr: Box::new(Expression::Identifier("foo".to_string())),
+            expr: Box::new(Expression::Identifier("foo".into())),
             subfield: Box::new(Expression::Literal(Literal::Integer(32))),
         })
     );
@@ -24,7 +24,7 @@ impl SubpathFieldVisitor {
                 entries.push(subpath_expr.subpath.clone());
             }
             Expression::Identifier(ident) => {
-                entries.push(ident.clone());
+                entries.push(ident.name.clone());
             }
             _ => (),
         }
@@ -9,10 +9,36 @@ impl MqlCodeGenerator {
     pub fn codegen_expression(&self, expr: air::Expression) -> Result<Bson> {
         use air::Expression::*;
         match expr {
-            MQLSemanticOperator(air::MQLSemanticOperator {
-                op: air::MQLOperator::ReplaceAll,
-                args,
-            }) => self.codegen_replace_all(args),
+            MQLSemanticOperator(
+                mql_op @ air::MQLSemanticOperator {
+                    op: air::MQLOperator::ReplaceAll,
+                    ..
+                },
+            ) => self.codegen_mql_semantic_operator_with_document_args(
+                &["input", "find", "replacement"],
+                mql_op,
+            ),
+            MQLSemanticOperator(
+                mql_op @ air::MQLSemanticOperator {
+                    op: air::MQLOperator::DateToString,
+                    ..
+                },
+            ) => self.codegen_mql_semantic_operator_with_document_args(&["date", "format"], mql_op),
+            MQLSemanticOperator(
+                mql_op @ air::MQLSemanticOperator {
+                    op: air::MQLOperator::DateFromString,
+                    ..
+                },
+            ) => self.codegen_mql_semantic_operator_with_document_args(
+                &["dateString", "format", "onError", "onNull"],
+                mql_op,
+            ),
+            MQLSemanticOperator(
+                mql_op @ air::MQLSemanticOperator {
+                    op: air::MQLOperator::SortArray,
+                    ..
+                },
+            ) => self.codegen_sort_array(mql_op),
             MQLSemanticOperator(mql_op) => self.codegen_mql_semantic_operator(mql_op),
             SQLSemanticOperator(sql_op) => self.codegen_sql_semantic_operator(sql_op),
             Literal(lit) => self.codegen_literal(lit),
@@ -40,26 +66,41 @@ impl MqlCodeGenerator {
         }
     }
 
-    // If we ever support another function that uses this document-style arguments, we should
-    // abstract this to something like:
-    // fn code_gen_mql_semantic_operator_with_document_args(
-    //     &self,
-    //     arg_names: &[&str],
-    //     mql_op: air::MQLSemanticOperator) -> Result<Bson>
-    // At this point, it is overkill.
-    fn codegen_replace_all(&self, args: Vec<air::Expression>) -> Result<Bson> {
-        // $replaceAll uses a document format for args that goes against
-        // most other scalar functions in mongodb, unfortunately.
-        let ops = ["input", "find", "replacement"]
-            .into_iter()
-            .zip(args)
+    // Some operators, such as $replaceAll and $dateToString, use a document format for args
+    // that goes against most other scalar functions in mongodb, unfortunately. The args are
+    // paired positionally with arg_names.
+    fn codegen_mql_semantic_operator_with_document_args(
+        &self,
+        arg_names: &[&str],
+        mql_op: air::MQLSemanticOperator,
+    ) -> Result<Bson> {
+        let ops = arg_names
+            .iter()
+            .zip(mql_op.args)
             .map(|(arg_name, arg)| Ok((arg_name.to_string(), self.codegen_expression(arg)?)))
             .collect::<Result<bson::Document>>()?;
         // We still use to_mql_op so that all MQL operator names can be found in one place.
-        let operator = Self::to_mql_op(air::MQLOperator::ReplaceAll);
+        let operator = Self::to_mql_op(mql_op.op);
         Ok(bson::bson!({ operator: Bson::Document(ops)}))
     }
 
+    // $sortArray requires its sortBy argument to be a constant, so a literal direction is
+    // emitted as-is rather than wrapped in $literal.
+    fn codegen_sort_array(&self, mql_op: air::MQLSemanticOperator) -> Result<Bson> {
+        let (input, sort_by) = match <[air::Expression; 2]>::try_from(mql_op.args) {
+            Ok([input, air::Expression::Literal(air::LiteralValue::Integer(i))]) => {
+                (input, Bson::Int32(i))
+            }
+            Ok([input, air::Expression::Literal(air::LiteralValue::Long(l))]) => {
+                (input, Bson::Int64(l))
+            }
+            _ => return Err(Error::InvalidSortArrayArguments),
+        };
+        let input = self.codegen_expression(input)?;
+        let operator = Self::to_mql_op(mql_op.op);
+        Ok(bson!({ operator: { "input": input, "sortBy": sort_by } }))
+    }
+
     fn codegen_mql_semantic_operator(&self, mql_op: air::MQLSemanticOperator) -> Result<Bson> {
         let ops = mql_op
             .args
@@ -102,6 +143,10 @@ impl MqlCodeGenerator {
             | SQLOperator::Sin
             | SQLOperator::Slice
             | SQLOperator::Split
+            | SQLOperator::Left
+            | SQLOperator::Right
+            | SQLOperator::LPad
+            | SQLOperator::RPad
             | SQLOperator::Sqrt
             | SQLOperator::SubstrCP
             | SQLOperator::Tan => {
@@ -305,6 +350,13 @@ impl MqlCodeGenerator {
                     "startOfWeek": self.codegen_expression(date_func_app.args[2].clone())?,
                 }})
             }
+            Sub => {
+                bson::bson!({"$dateSubtract" : {
+                    "startDate": self.codegen_expression(date_func_app.args[1].clone())?,
+                    "unit": Self::date_part_to_mql_unit(date_func_app.unit),
+                    "amount": self.codegen_expression(date_func_app.args[0].clone())?,
+                }})
+            }
             Trunc => {
                 bson::bson!({"$dateTrunc" : {
                     "date": self.codegen_expression(date_func_app.args[0].clone())?,
@@ -1,12 +1,12 @@
 use crate::{
     air::{AggregationFunction, DatePart, MQLOperator, SQLOperator},
-    codegen::MqlCodeGenerator,
+    codegen::{Error, MqlCodeGenerator, Result},
 };
 
 impl MqlCodeGenerator {
-    pub(crate) fn agg_func_to_mql_op(mqla: AggregationFunction) -> &'static str {
+    pub(crate) fn agg_func_to_mql_op(mqla: AggregationFunction) -> Result<&'static str> {
         use AggregationFunction::*;
-        match mqla {
+        Ok(match mqla {
             AddToArray => "$push",
             AddToSet => "$addToSet",
             Avg => "$avg",
@@ -19,12 +19,15 @@ impl MqlCodeGenerator {
             StddevPop => "$stdDevPop",
             StddevSamp => "$stdDevSamp",
             Sum => "$sum",
-        }
+            GroupConcat | VarPop | VarSamp => {
+                return Err(Error::UnsupportedAggregationFunction(mqla))
+            }
+        })
     }
 
-    pub(crate) fn agg_func_to_sql_op(mqla: AggregationFunction) -> &'static str {
+    pub(crate) fn agg_func_to_sql_op(mqla: AggregationFunction) -> Result<&'static str> {
         use AggregationFunction::*;
-        match mqla {
+        Ok(match mqla {
             AddToArray => unreachable!(),
             AddToSet => unreachable!(),
             Avg => "$sqlAvg",
@@ -37,7 +40,10 @@ impl MqlCodeGenerator {
             StddevPop => "$sqlStdDevPop",
             StddevSamp => "$sqlStdDevSamp",
             Sum => "$sqlSum",
-        }
+            GroupConcat | VarPop | VarSamp => {
+                return Err(Error::UnsupportedAggregationFunction(mqla))
+            }
+        })
     }
 
     pub(crate) fn date_part_to_mql_unit(unit: DatePart) -> bson::Bson {
@@ -71,6 +77,11 @@ impl MqlCodeGenerator {
             Multiply => "$multiply",
             Divide => "$divide",
 
+            // Bitwise operators
+            BitAnd => "$bitAnd",
+            BitOr => "$bitOr",
+            BitXor => "$bitXor",
+
             // Comparison operators
             Lt => "$lt",
             Lte => "$lte",
@@ -88,6 +99,9 @@ impl MqlCodeGenerator {
             // Array scalar functions
             Slice => "$slice",
             Size => "$size",
+            Range => "$range",
+            SetUnion => "$setUnion",
+            SortArray => "$sortArray",
             ElemAt => "$arrayElemAt",
             In => "$in",
             First => "$first",
@@ -103,6 +117,7 @@ impl MqlCodeGenerator {
             Cos => "$cos",
             DegreesToRadians => "$degreesToRadians",
             Floor => "$floor",
+            Trunc => "$trunc",
             Log => "$log",
             Mod => "$mod",
             Pow => "$pow",
@@ -142,6 +157,9 @@ impl MqlCodeGenerator {
             DateAdd => "$dateAdd",
             DateDiff => "$dateDiff",
             DateTrunc => "$dateTrunc",
+            DateToString => "$dateToString",
+            DateFromString => "$dateFromString",
+            ToDate => "$toDate",
 
             // MergeObjects merges an array of objects
             MergeObjects => "$mergeObjects",
@@ -200,6 +218,10 @@ impl MqlCodeGenerator {
             ToUpper => "$sqlToUpper",
             ToLower => "$sqlToLower",
             Split => "$sqlSplit",
+            Left => "$sqlLeft",
+            Right => "$sqlRight",
+            LPad => "$sqlLPad",
+            RPad => "$sqlRPad",
 
             // ComputedFieldAccess, CurrentTimestamp
             _ => return None,
@@ -1,5 +1,5 @@
-use crate::air;
 pub use crate::mapping_registry::MqlMappingRegistry;
+use crate::{air, options::SqlOptions};
 use thiserror::Error;
 
 #[cfg(test)]
@@ -21,6 +21,14 @@ pub enum Error {
     ConvertToDocument,
     #[error("cannot $convert to array")]
     ConvertToArray,
+    #[error("cannot exclude field path {0} with a $-prefixed segment")]
+    InvalidExclusionPath(String),
+    #[error("$sortArray requires an input and a literal sort direction")]
+    InvalidSortArrayArguments,
+    #[error("cannot generate MQL for {0:?} accumulator, which must be desugared")]
+    UnsupportedAggregationFunction(air::AggregationFunction),
+    #[error("a Group with a scalar _id must have exactly one key")]
+    InvalidScalarGroupId,
 }
 
 #[derive(PartialEq, Debug)]
@@ -30,11 +38,15 @@ pub struct MqlTranslation {
     pub pipeline: Vec<bson::Document>,
 }
 
-#[derive(Clone, Debug)]
-pub struct MqlCodeGenerator {}
+#[derive(Clone, Debug, Default)]
+pub struct MqlCodeGenerator {
+    facet_sibling_aggregations: bool,
+}
 
-pub fn generate_mql(plan: air::Stage) -> Result<MqlTranslation> {
-    let cg = MqlCodeGenerator {};
+pub fn generate_mql(plan: air::Stage, sql_options: SqlOptions) -> Result<MqlTranslation> {
+    let cg = MqlCodeGenerator {
+        facet_sibling_aggregations: sql_options.facet_sibling_aggregations,
+    };
 
     cg.codegen_stage(plan)
 }
@@ -1,6 +1,6 @@
 use crate::{
     air::{self, AggregationFunction, ProjectItem},
-    codegen::{MqlCodeGenerator, MqlTranslation, Result},
+    codegen::{Error, MqlCodeGenerator, MqlTranslation, Result},
 };
 use bson::{bson, doc, Bson};
 
@@ -188,7 +188,12 @@ impl MqlCodeGenerator {
         let project_doc = air_project
             .specifications
             .into_iter()
-            .map(|(k, v)| Ok((k, self.codegen_project_item(v)?)))
+            .map(|(k, v)| {
+                if let ProjectItem::Exclusion = v {
+                    Self::validate_exclusion_path(&k)?;
+                }
+                Ok((k, self.codegen_project_item(v)?))
+            })
             .collect::<Result<bson::Document>>()?;
         pipeline.push(doc! {"$project": project_doc});
         Ok(MqlTranslation {
@@ -198,6 +203,16 @@ impl MqlCodeGenerator {
         })
     }
 
+    /// An excluded key may be a dotted path into a nested field, such as one
+    /// derived from a FieldRef with a parent, but none of its segments may
+    /// start with $, since $project would read such a segment as an operator.
+    fn validate_exclusion_path(path: &str) -> Result<()> {
+        if path.split('.').any(|segment| segment.starts_with('$')) {
+            return Err(Error::InvalidExclusionPath(path.to_string()));
+        }
+        Ok(())
+    }
+
     fn codegen_project_item(&self, air_project_item: air::ProjectItem) -> Result<Bson> {
         match air_project_item {
             ProjectItem::Exclusion => Ok(Bson::Int32(0)),
@@ -228,12 +243,22 @@ impl MqlCodeGenerator {
     fn codegen_group(&self, air_group: air::Group) -> Result<MqlTranslation> {
         let source_translation = self.codegen_stage(*air_group.source)?;
         let mut pipeline = source_translation.pipeline;
-        let id_doc = air_group
-            .keys
-            .into_iter()
-            .map(|air::NameExprPair { name: k, expr: v }| Ok((k, self.codegen_expression(v)?)))
-            .collect::<Result<bson::Document>>()?;
-        let mut group_doc = doc! {"_id": id_doc};
+        let id = if air_group.scalar_id {
+            let [key] = <[air::NameExprPair; 1]>::try_from(air_group.keys)
+                .map_err(|_| Error::InvalidScalarGroupId)?;
+            self.codegen_expression(key.expr)?
+        } else {
+            Bson::Document(
+                air_group
+                    .keys
+                    .into_iter()
+                    .map(|air::NameExprPair { name: k, expr: v }| {
+                        Ok((k, self.codegen_expression(v)?))
+                    })
+                    .collect::<Result<bson::Document>>()?,
+            )
+        };
+        let mut group_doc = doc! {"_id": id};
         let aggs = air_group
             .aggregations
             .into_iter()
@@ -243,6 +268,7 @@ impl MqlCodeGenerator {
                      function,
                      distinct,
                      arg,
+                     ..
                  }| {
                     Ok(if function == AggregationFunction::AddToArray {
                         if distinct {
@@ -253,9 +279,11 @@ impl MqlCodeGenerator {
                         }
                     }
                     else if distinct || function == AggregationFunction::Count {
-                        (alias, bson!({ Self::agg_func_to_sql_op(function): {"var": self.codegen_expression(*arg)?, "distinct": distinct }}))
+                        let op = Self::agg_func_to_sql_op(function)?;
+                        (alias, bson!({ op: {"var": self.codegen_expression(*arg)?, "distinct": distinct }}))
                     } else {
-                        (alias, bson!({ Self::agg_func_to_mql_op(function): self.codegen_expression(*arg)? }))
+                        let op = Self::agg_func_to_mql_op(function)?;
+                        (alias, bson!({ op: self.codegen_expression(*arg)? }))
                     })
                 },
             )
@@ -340,6 +368,11 @@ impl MqlCodeGenerator {
     }
 
     fn codegen_join(&self, air_join: air::Join) -> Result<MqlTranslation> {
+        if self.facet_sibling_aggregations {
+            if let Some(translation) = self.codegen_join_as_facet(&air_join)? {
+                return Ok(translation);
+            }
+        }
         let mut left_translation = self.codegen_stage(*air_join.left)?;
         let right_translation = self.codegen_stage(*air_join.right)?;
         let join_type = match air_join.join_type {
@@ -381,6 +414,116 @@ impl MqlCodeGenerator {
         Ok(left_translation)
     }
 
+    /// Generates a single $facet stage for an inner join without a condition whose leaves are
+    /// all single-row aggregations over the same collection. Each aggregation becomes one $facet
+    /// pipeline, and the joined row is rebuilt by unwinding every $facet output and merging the
+    /// results. Returns None if the join does not have this shape.
+    fn codegen_join_as_facet(&self, air_join: &air::Join) -> Result<Option<MqlTranslation>> {
+        if !Self::is_sibling_join(air_join) {
+            return Ok(None);
+        }
+        let mut leaves = vec![];
+        Self::collect_sibling_join_leaves(&air_join.left, &mut leaves);
+        Self::collect_sibling_join_leaves(&air_join.right, &mut leaves);
+
+        // The shape is checked before generating any code, so that each leaf is only generated
+        // once whether or not the join becomes a $facet.
+        let sources = match leaves
+            .iter()
+            .map(|leaf| Self::single_row_aggregation_source(leaf))
+            .collect::<Option<Vec<&air::Collection>>>()
+        {
+            Some(sources) => sources,
+            None => return Ok(None),
+        };
+        if sources.iter().any(|source| *source != sources[0]) {
+            return Ok(None);
+        }
+
+        let facet_names = (0..leaves.len())
+            .map(|i| format!("facet_{i}"))
+            .collect::<Vec<String>>();
+        let facet_doc = facet_names
+            .iter()
+            .cloned()
+            .zip(leaves)
+            .map(|(name, leaf)| {
+                let translation = self.codegen_stage(leaf.clone())?;
+                Ok((name, Bson::from(translation.pipeline)))
+            })
+            .collect::<Result<bson::Document>>()?;
+
+        let mut pipeline = vec![doc! {"$facet": facet_doc}];
+        pipeline.extend(
+            facet_names
+                .iter()
+                .map(|name| doc! {"$unwind": format!("${name}")}),
+        );
+        pipeline.push(doc! {"$replaceWith": {"$mergeObjects": facet_names
+            .iter()
+            .map(|name| format!("${name}"))
+            .collect::<Vec<String>>()
+        }});
+        Ok(Some(MqlTranslation {
+            database: Some(sources[0].db.clone()),
+            collection: Some(sources[0].collection.clone()),
+            pipeline,
+        }))
+    }
+
+    fn is_sibling_join(air_join: &air::Join) -> bool {
+        air_join.join_type == air::JoinType::Inner
+            && air_join.condition.is_none()
+            && air_join.let_vars.is_none()
+    }
+
+    /// Collects the sources of a tree of inner joins without conditions, in left to right order.
+    fn collect_sibling_join_leaves<'a>(stage: &'a air::Stage, leaves: &mut Vec<&'a air::Stage>) {
+        match stage {
+            air::Stage::Join(j) if Self::is_sibling_join(j) => {
+                Self::collect_sibling_join_leaves(&j.left, leaves);
+                Self::collect_sibling_join_leaves(&j.right, leaves);
+            }
+            stage => leaves.push(stage),
+        }
+    }
+
+    /// Returns the collection read by a pipeline that can be moved into a $facet, or None if the
+    /// pipeline cannot be. The pipeline must group without keys, so that the $facet output is a
+    /// single row, and may only contain stages permitted in $facet. Stages after the Group must
+    /// not add rows.
+    fn single_row_aggregation_source(stage: &air::Stage) -> Option<&air::Collection> {
+        let mut stage = stage;
+        // Stages are visited from the end of the pipeline, so this is set once every remaining
+        // stage comes before the Group.
+        let mut grouped = false;
+        loop {
+            stage = match stage {
+                air::Stage::Collection(c) => return grouped.then_some(c),
+                air::Stage::Group(g) if g.keys.is_empty() || grouped => {
+                    grouped = true;
+                    &g.source
+                }
+                air::Stage::Unwind(u) if grouped => &u.source,
+                air::Stage::AddFields(air::AddFields { source, .. })
+                | air::Stage::Project(air::Project { source, .. })
+                | air::Stage::ReplaceWith(air::ReplaceWith { source, .. })
+                | air::Stage::Sort(air::Sort { source, .. })
+                | air::Stage::Limit(air::Limit { source, .. })
+                | air::Stage::Skip(air::Skip { source, .. })
+                | air::Stage::Lookup(air::Lookup { source, .. })
+                | air::Stage::EquiLookup(air::EquiLookup { source, .. })
+                | air::Stage::Match(air::Match::ExprLanguage(air::ExprLanguage {
+                    source, ..
+                }))
+                | air::Stage::Match(air::Match::MatchLanguage(air::MatchLanguage {
+                    source, ..
+                })) => source,
+                _ => return None,
+            };
+        }
+    }
+
     fn codegen_equijoin(&self, air_join: air::EquiJoin) -> Result<MqlTranslation> {
         let mut source_translation = self.codegen_stage(*air_join.source)?;
         let join_type = match air_join.join_type {
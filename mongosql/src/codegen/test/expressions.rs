@@ -6,7 +6,7 @@ macro_rules! test_codegen_expression {
             let expected = $expected;
             let input = $input;
 
-            let gen = MqlCodeGenerator {};
+            let gen = MqlCodeGenerator::default();
             assert_eq!(expected, gen.codegen_expression(input));
         }
     };
@@ -37,6 +37,24 @@ mod date_function {
         })
     );
 
+    test_codegen_expression!(
+        datesub,
+        expected = Ok(
+            bson!({"$dateSubtract": {"startDate": "$$NOW", "unit": {"$literal": "month"}, "amount": {"$literal": 2}}})
+        ),
+        input = DateFunction(DateFunctionApplication {
+            function: Sub,
+            unit: Month,
+            args: vec![
+                Literal(Integer(2)),
+                SQLSemanticOperator(SQLSemanticOperator {
+                    op: CurrentTimestamp,
+                    args: vec![],
+                }),
+            ],
+        })
+    );
+
     test_codegen_expression!(
         datediff,
         expected = Ok(
@@ -59,6 +77,22 @@ mod date_function {
         })
     );
 
+    test_codegen_expression!(
+        unix_timestamp_diff_from_epoch,
+        expected = Ok(
+            bson!({"$dateDiff": {"startDate": {"$literal": bson::DateTime::from_millis(0)}, "endDate": "$d", "unit": {"$literal": "second"}, "startOfWeek": {"$literal": "sunday"}}})
+        ),
+        input = DateFunction(DateFunctionApplication {
+            function: Diff,
+            unit: Second,
+            args: vec![
+                Literal(DateTime(bson::DateTime::from_millis(0))),
+                FieldRef("d".to_string().into()),
+                Literal(String("sunday".to_string())),
+            ],
+        })
+    );
+
     test_codegen_expression!(
         datetrunc,
         expected = Ok(
@@ -342,7 +376,10 @@ mod trim {
 }
 
 mod mql_semantic_operator {
-    use crate::air::{self, Expression::*, LiteralValue::*, MQLOperator::*, MQLSemanticOperator};
+    use crate::{
+        air::{self, Expression::*, LiteralValue::*, MQLOperator::*, MQLSemanticOperator},
+        codegen::Error,
+    };
     use bson::bson;
 
     test_codegen_expression!(
@@ -520,6 +557,78 @@ mod mql_semantic_operator {
         })
     );
 
+    test_codegen_expression!(
+        set_union,
+        expected = Ok(bson!({ "$setUnion": [[{"$literal": 1}, {"$literal": 1}, {"$literal": 2}]]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: SetUnion,
+            args: vec![air::Expression::Array(vec![
+                Literal(Integer(1)),
+                Literal(Integer(1)),
+                Literal(Integer(2))
+            ])],
+        })
+    );
+
+    test_codegen_expression!(
+        sort_array,
+        expected = Ok(bson!({ "$sortArray": {
+            "input": [{"$literal": 3}, {"$literal": 1}, {"$literal": 2}],
+            "sortBy": -1,
+        }})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: SortArray,
+            args: vec![
+                air::Expression::Array(vec![
+                    Literal(Integer(3)),
+                    Literal(Integer(1)),
+                    Literal(Integer(2))
+                ]),
+                Literal(Integer(-1)),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        sort_array_with_non_literal_direction_is_error,
+        expected = Err(Error::InvalidSortArrayArguments),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: SortArray,
+            args: vec![
+                air::Expression::Array(vec![Literal(Integer(3)), Literal(Integer(1))]),
+                FieldRef("dir".to_string().into()),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        range,
+        expected = Ok(bson!({ "$range": [{"$literal": 0}, {"$literal": 6}, {"$literal": 2}]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: Range,
+            args: vec![
+                Literal(Integer(0)),
+                Literal(Integer(6)),
+                Literal(Integer(2))
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        index_of_cp_with_start,
+        expected = Ok(
+            bson!({ "$indexOfCP": [{ "$literal": "foo"}, { "$literal": "o"}, { "$literal": 2}]})
+        ),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: IndexOfCP,
+            args: vec![
+                Literal(String("foo".to_string())),
+                Literal(String("o".to_string())),
+                Literal(Integer(2)),
+            ],
+        })
+    );
+
     test_codegen_expression!(
         index_of_cp,
         expected = Ok(
@@ -871,6 +980,51 @@ mod mql_semantic_operator {
         })
     );
 
+    test_codegen_expression!(
+        bit_and,
+        expected = Ok(bson!({ "$bitAnd": [{ "$literal": 6 }, { "$literal": 3 }]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: BitAnd,
+            args: vec![Literal(Integer(6)), Literal(Integer(3))],
+        })
+    );
+
+    test_codegen_expression!(
+        bit_or,
+        expected = Ok(bson!({ "$bitOr": [{ "$literal": 6 }, { "$literal": 3 }]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: BitOr,
+            args: vec![Literal(Integer(6)), Literal(Integer(3))],
+        })
+    );
+
+    test_codegen_expression!(
+        bit_xor,
+        expected = Ok(bson!({ "$bitXor": [{ "$literal": 6 }, { "$literal": 3 }]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: BitXor,
+            args: vec![Literal(Integer(6)), Literal(Integer(3))],
+        })
+    );
+
+    test_codegen_expression!(
+        trunc,
+        expected = Ok(bson!({ "$trunc": [{ "$literal": 3.5 }]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: Trunc,
+            args: vec![Literal(Double(3.5)),],
+        })
+    );
+
+    test_codegen_expression!(
+        millisecond,
+        expected = Ok(bson!({ "$millisecond": [{ "$literal": "foo" }]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: Millisecond,
+            args: vec![Literal(String("foo".to_string())),],
+        })
+    );
+
     test_codegen_expression!(
         week,
         expected = Ok(bson!({ "$week": [{ "$literal": "foo" }]})),
@@ -934,6 +1088,57 @@ mod mql_semantic_operator {
         })
     );
 
+    test_codegen_expression!(
+        date_to_string,
+        expected = Ok(bson!({ "$dateToString": {
+            "date": "$foo",
+            "format": { "$literal": "%Y-%m-%d" }
+        }})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: DateToString,
+            args: vec![
+                FieldRef("foo".to_string().into()),
+                Literal(String("%Y-%m-%d".to_string())),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        date_from_string,
+        expected = Ok(bson!({ "$dateFromString": {
+            "dateString": "$foo",
+            "format": { "$literal": "%Y-%m-%d" },
+            "onError": { "$literal": null }
+        }})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: DateFromString,
+            args: vec![
+                FieldRef("foo".to_string().into()),
+                Literal(String("%Y-%m-%d".to_string())),
+                Literal(Null),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        date_from_string_with_on_null,
+        expected = Ok(bson!({ "$dateFromString": {
+            "dateString": "$foo",
+            "format": { "$literal": "%Y-%m-%d" },
+            "onError": { "$literal": "error" },
+            "onNull": { "$literal": "null" }
+        }})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: DateFromString,
+            args: vec![
+                FieldRef("foo".to_string().into()),
+                Literal(String("%Y-%m-%d".to_string())),
+                Literal(String("error".to_string())),
+                Literal(String("null".to_string())),
+            ],
+        })
+    );
+
     test_codegen_expression!(
         merge_object,
         expected = Ok(bson!({ "$mergeObjects": [{ "$literal": "foo" }]})),
@@ -999,6 +1204,57 @@ mod mql_semantic_operator {
         })
     );
 
+    test_codegen_expression!(
+        month_name_date_to_string,
+        expected = Ok(bson!({ "$dateToString": {
+            "date": "$d",
+            "format": { "$literal": "%B" }
+        }})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: DateToString,
+            args: vec![
+                FieldRef("d".to_string().into()),
+                Literal(String("%B".to_string())),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        month_name_lookup,
+        expected = Ok(bson!({ "$arrayElemAt": [
+            [{ "$literal": "January" }, { "$literal": "February" }],
+            { "$subtract": [{ "$month": ["$d"] }, { "$literal": 1 }] },
+        ]})),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: ElemAt,
+            args: vec![
+                air::Expression::Array(vec![
+                    Literal(String("January".to_string())),
+                    Literal(String("February".to_string()))
+                ]),
+                MQLSemanticOperator(MQLSemanticOperator {
+                    op: Subtract,
+                    args: vec![
+                        MQLSemanticOperator(MQLSemanticOperator {
+                            op: Month,
+                            args: vec![FieldRef("d".to_string().into())],
+                        }),
+                        Literal(Integer(1)),
+                    ],
+                }),
+            ],
+        })
+    );
+
+    test_codegen_expression!(
+        to_date,
+        expected = Ok(bson!({ "$toDate": ["$ms"] })),
+        input = MQLSemanticOperator(MQLSemanticOperator {
+            op: ToDate,
+            args: vec![FieldRef("ms".to_string().into())],
+        })
+    );
+
     test_codegen_expression!(
         in_op,
         expected = Ok(bson!({ "$in": [{ "$literal": 1}, [{ "$literal": 1 }, { "$literal": 2 }]]})),
@@ -1167,6 +1423,14 @@ mod sql_semantic_operator {
             args: vec![Literal(Boolean(false))],
         })
     );
+    test_codegen_expression!(
+        not_of_nullable_field,
+        expected = Ok(bson!({ "$sqlNot": ["$a"]})),
+        input = SQLSemanticOperator(SQLSemanticOperator {
+            op: Not,
+            args: vec![FieldRef("a".to_string().into())],
+        })
+    );
 
     test_codegen_expression!(
         and,
@@ -1353,6 +1617,50 @@ mod sql_semantic_operator {
             ],
         })
     );
+    test_codegen_expression!(
+        left,
+        expected = Ok(bson!({ "$sqlLeft": [{ "$literal": "foo" }, { "$literal": 2 }]})),
+        input = SQLSemanticOperator(SQLSemanticOperator {
+            op: Left,
+            args: vec![Literal(String("foo".to_string())), Literal(Integer(2))],
+        })
+    );
+    test_codegen_expression!(
+        right,
+        expected = Ok(bson!({ "$sqlRight": [{ "$literal": "foo" }, { "$literal": 2 }]})),
+        input = SQLSemanticOperator(SQLSemanticOperator {
+            op: Right,
+            args: vec![Literal(String("foo".to_string())), Literal(Integer(2))],
+        })
+    );
+    test_codegen_expression!(
+        lpad,
+        expected = Ok(
+            bson!({ "$sqlLPad": [{ "$literal": "foo" }, { "$literal": 5 }, { "$literal": "*" }]})
+        ),
+        input = SQLSemanticOperator(SQLSemanticOperator {
+            op: LPad,
+            args: vec![
+                Literal(String("foo".to_string())),
+                Literal(Integer(5)),
+                Literal(String("*".to_string()))
+            ],
+        })
+    );
+    test_codegen_expression!(
+        rpad,
+        expected = Ok(
+            bson!({ "$sqlRPad": [{ "$literal": "foo" }, { "$literal": 5 }, { "$literal": "*" }]})
+        ),
+        input = SQLSemanticOperator(SQLSemanticOperator {
+            op: RPad,
+            args: vec![
+                Literal(String("foo".to_string())),
+                Literal(Integer(5)),
+                Literal(String("*".to_string()))
+            ],
+        })
+    );
     test_codegen_expression!(
         current_timestamp,
         expected = Ok(bson!("$$NOW")),
@@ -1747,6 +2055,24 @@ mod convert {
 
     test_codegen_working_convert!(convert_date, expected = "date", input = Type::Datetime);
 
+    test_codegen_expression!(
+        convert_long_to_date_with_on_null_and_on_error,
+        expected = Ok(bson!({
+            "$convert": {
+                "input": {"$literal": 0_i64},
+                "to": "date",
+                "onError": {"$literal": "bad"},
+                "onNull": {"$literal": 1.0},
+            }
+        })),
+        input = Expression::Convert(Convert {
+            input: Expression::Literal(Long(0)).into(),
+            to: Type::Datetime,
+            on_null: Expression::Literal(Double(1.0)).into(),
+            on_error: Expression::Literal(String("bad".to_string())).into(),
+        })
+    );
+
     test_codegen_working_convert!(
         convert_db_pointer,
         expected = "dbPointer",
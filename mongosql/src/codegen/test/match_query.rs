@@ -8,7 +8,7 @@ macro_rules! test_codegen_match_query {
             let expected = $expected;
             let input = $input;
 
-            let gen = MqlCodeGenerator {};
+            let gen = MqlCodeGenerator::default();
             assert_eq!(expected, gen.codegen_match_query(input));
         }
     };
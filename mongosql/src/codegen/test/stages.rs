@@ -10,7 +10,10 @@ macro_rules! test_codegen_stage {
 	) => {
         #[test]
         fn $func_name() {
-            use crate::codegen::{generate_mql, MqlTranslation};
+            use crate::{
+                codegen::{generate_mql, MqlTranslation},
+                options::SqlOptions,
+            };
 
             let input = $input;
             let expected_db = $expected_db;
@@ -21,7 +24,7 @@ macro_rules! test_codegen_stage {
                 database: db,
                 collection: col,
                 pipeline: pipeline,
-            } = generate_mql(input).expect("codegen failed");
+            } = generate_mql(input, SqlOptions::default()).expect("codegen failed");
 
             assert_eq!(expected_db, db);
             assert_eq!(expected_collection, col);
@@ -32,12 +35,12 @@ macro_rules! test_codegen_stage {
     ($func_name:ident, expected = Err($expected_err:expr), input = $input:expr,) => {
         #[test]
         fn $func_name() {
-            use crate::codegen::generate_mql;
+            use crate::{codegen::generate_mql, options::SqlOptions};
 
             let input = $input;
             let expected = Err($expected_err);
 
-            assert_eq!(expected, generate_mql(input));
+            assert_eq!(expected, generate_mql(input, SqlOptions::default()));
         }
     };
 }
@@ -253,7 +256,9 @@ mod collection {
 }
 
 mod project {
-    use crate::{air::*, unchecked_unique_linked_hash_map, util::air_collection_stage};
+    use crate::{
+        air::*, codegen::Error, unchecked_unique_linked_hash_map, util::air_collection_stage,
+    };
     use bson::doc;
 
     test_codegen_stage!(
@@ -288,6 +293,37 @@ mod project {
         }),
     );
 
+    test_codegen_stage!(
+        exclusion_of_nested_field,
+        expected = Ok({
+            database: Some("mydb".to_string()),
+            collection: Some("col".to_string()),
+            pipeline: vec![doc!{"$project": {"_id": 0, "a.b": 0}}],
+        }),
+        input = Stage::Project(Project {
+            source: air_collection_stage("mydb", "col"),
+            specifications: unchecked_unique_linked_hash_map! {
+                "_id".to_string() => ProjectItem::Exclusion,
+                FieldRef {
+                    parent: Some(Box::new("a".into())),
+                    name: "b".to_string(),
+                }
+                .to_string() => ProjectItem::Exclusion,
+            },
+        }),
+    );
+
+    test_codegen_stage!(
+        exclusion_of_dollar_prefixed_nested_field_is_error,
+        expected = Err(Error::InvalidExclusionPath("a.$b".to_string())),
+        input = Stage::Project(Project {
+            source: air_collection_stage("mydb", "col"),
+            specifications: unchecked_unique_linked_hash_map! {
+                "a.$b".to_string() => ProjectItem::Exclusion,
+            },
+        }),
+    );
+
     test_codegen_stage!(
         project_of_id_overwritten,
         expected = Ok({
@@ -303,6 +339,39 @@ mod project {
             },
         }),
     );
+
+    // Subquery expressions are normally desugared away before codegen, but
+    // codegen still supports them by generating the $subquery operator.
+    test_codegen_stage!(
+        assignment_of_undesugared_subquery,
+        expected = Ok({
+            database: Some("mydb".to_string()),
+            collection: Some("col".to_string()),
+            pipeline: vec![doc!{"$project": {"foo": {"$subquery": {
+                "let": {},
+                "outputPath": ["arr"],
+                "pipeline": [
+                    {"$documents": []},
+                    {"$project": {"arr": "$$ROOT"}},
+                ],
+            }}}}],
+        }),
+        input = Stage::Project(Project {
+            source: air_collection_stage("mydb", "col"),
+            specifications: unchecked_unique_linked_hash_map! {
+                "foo".to_string() => ProjectItem::Assignment(Expression::Subquery(Subquery {
+                    let_bindings: vec![],
+                    output_path: vec!["arr".to_string()],
+                    pipeline: Box::new(Stage::Project(Project {
+                        source: Box::new(Stage::Documents(Documents { array: vec![] })),
+                        specifications: unchecked_unique_linked_hash_map! {
+                            "arr".to_string() => ProjectItem::Assignment(crate::util::ROOT.clone()),
+                        },
+                    })),
+                })),
+            },
+        }),
+    );
 }
 
 mod add_fields {
@@ -327,7 +396,7 @@ mod add_fields {
 }
 
 mod group {
-    use crate::{air::*, util::air_collection_stage};
+    use crate::{air::*, unchecked_unique_linked_hash_map, util::air_collection_stage};
     use bson::doc;
 
     test_codegen_stage!(
@@ -367,6 +436,7 @@ mod group {
                     function: AggregationFunction::Min,
                     distinct: false,
                     arg: Expression::FieldRef("x".into()).into(),
+                    separator: None,
                 },
                 AccumulatorExpr {
                     alias: "y".into(),
@@ -379,8 +449,10 @@ mod group {
                             Expression::Literal(LiteralValue::Integer(1i32))
                         ],
                     }).into(),
+                    separator: None,
                 },
             ],
+            scalar_id: false,
         }),
     );
 
@@ -410,8 +482,10 @@ mod group {
                     function: AggregationFunction::Min,
                     distinct: true,
                     arg: Expression::FieldRef("x".into()).into(),
+                    separator: None,
                 },
             ],
+            scalar_id: false,
         }),
     );
 
@@ -441,8 +515,127 @@ mod group {
                     function: AggregationFunction::Count,
                     distinct: false,
                     arg: Expression::FieldRef("x".into()).into(),
+                    separator: None,
                 },
             ],
+            scalar_id: false,
+        }),
+    );
+
+    fn key_ref(name: &str) -> Expression {
+        Expression::FieldRef(format!("_id.{name}").into())
+    }
+
+    fn grouped_by(keys: Vec<(&str, &str)>, scalar_id: bool) -> Box<Stage> {
+        Box::new(Stage::Group(Group {
+            source: air_collection_stage("mydb", "col"),
+            keys: keys
+                .into_iter()
+                .map(|(name, field)| NameExprPair {
+                    name: name.into(),
+                    expr: Expression::FieldRef(field.into()),
+                })
+                .collect(),
+            aggregations: vec![AccumulatorExpr {
+                alias: "x".into(),
+                function: AggregationFunction::Min,
+                distinct: false,
+                arg: Expression::FieldRef("x".into()).into(),
+                separator: None,
+            }],
+            scalar_id,
+        }))
+    }
+
+    test_codegen_stage!(
+        scalar_id_is_key_expression,
+        expected = Ok({
+            database: Some("mydb".to_string()),
+            collection: Some("col".to_string()),
+            pipeline: vec![
+                doc!{"$group": {"_id": "$foo.a", "x": {"$min": "$x"}}},
+                doc!{"$project": {"_id": 0, "foo": {"a": "$_id"}, "__bot": {"x": "$x"}}},
+            ],
+        }),
+        input = Stage::Project(Project {
+            source: grouped_by(vec![("__unaliasedKey1", "foo.a")], true),
+            specifications: unchecked_unique_linked_hash_map! {
+                "_id".to_string() => ProjectItem::Exclusion,
+                "foo".to_string() => ProjectItem::Assignment(Expression::Document(unchecked_unique_linked_hash_map! {
+                    "a".to_string() => Expression::FieldRef("_id".into()),
+                })),
+                "__bot".to_string() => ProjectItem::Assignment(Expression::Document(unchecked_unique_linked_hash_map! {
+                    "x".to_string() => Expression::FieldRef("x".into()),
+                })),
+            },
+        }),
+    );
+
+    test_codegen_stage!(
+        document_id_holds_every_key,
+        expected = Ok({
+            database: Some("mydb".to_string()),
+            collection: Some("col".to_string()),
+            pipeline: vec![
+                doc!{"$group": {"_id": {"__unaliasedKey1": "$foo.a", "b": "$foo.b"}, "x": {"$min": "$x"}}},
+                doc!{"$project": {"_id": 0, "foo": {"a": "$_id.__unaliasedKey1"}, "__bot": {"b": "$_id.b"}}},
+            ],
+        }),
+        input = Stage::Project(Project {
+            source: grouped_by(vec![("__unaliasedKey1", "foo.a"), ("b", "foo.b")], false),
+            specifications: unchecked_unique_linked_hash_map! {
+                "_id".to_string() => ProjectItem::Exclusion,
+                "foo".to_string() => ProjectItem::Assignment(Expression::Document(unchecked_unique_linked_hash_map! {
+                    "a".to_string() => key_ref("__unaliasedKey1"),
+                })),
+                "__bot".to_string() => ProjectItem::Assignment(Expression::Document(unchecked_unique_linked_hash_map! {
+                    "b".to_string() => key_ref("b"),
+                })),
+            },
+        }),
+    );
+
+    test_codegen_stage!(
+        scalar_id_with_multiple_keys_is_error,
+        expected = Err(crate::codegen::Error::InvalidScalarGroupId),
+        input = *grouped_by(vec![("a", "foo.a"), ("b", "foo.b")], true),
+    );
+
+    test_codegen_stage!(
+        group_concat_must_be_desugared,
+        expected = Err(crate::codegen::Error::UnsupportedAggregationFunction(
+            AggregationFunction::GroupConcat
+        )),
+        input = Stage::Group(Group {
+            source: air_collection_stage("mydb", "col"),
+            keys: vec![],
+            aggregations: vec![AccumulatorExpr {
+                alias: "x".into(),
+                function: AggregationFunction::GroupConcat,
+                distinct: false,
+                arg: Expression::FieldRef("x".into()).into(),
+                separator: None,
+            }],
+            scalar_id: false,
+        }),
+    );
+
+    test_codegen_stage!(
+        var_pop_must_be_desugared,
+        expected = Err(crate::codegen::Error::UnsupportedAggregationFunction(
+            AggregationFunction::VarPop
+        )),
+        input = Stage::Group(Group {
+            source: air_collection_stage("mydb", "col"),
+            keys: vec![],
+            aggregations: vec![AccumulatorExpr {
+                alias: "x".into(),
+                function: AggregationFunction::VarPop,
+                distinct: false,
+                arg: Expression::FieldRef("x".into()).into(),
+                separator: None,
+            }],
+            scalar_id: false,
         }),
     );
 }
@@ -1013,3 +1206,154 @@ mod equilookup {
         }),
     );
 }
+
+mod facet {
+    use crate::{
+        air::*,
+        codegen::{generate_mql, MqlTranslation},
+        options::SqlOptions,
+        util::air_collection_stage,
+    };
+    use bson::doc;
+
+    fn facet_options() -> SqlOptions {
+        SqlOptions {
+            facet_sibling_aggregations: true,
+            ..Default::default()
+        }
+    }
+
+    fn aggregation(collection: &str, alias: &str, function: AggregationFunction) -> Box<Stage> {
+        Box::new(Stage::Group(Group {
+            source: air_collection_stage("mydb", collection),
+            keys: vec![],
+            aggregations: vec![AccumulatorExpr {
+                alias: alias.into(),
+                function,
+                distinct: false,
+                arg: Expression::FieldRef("x".into()).into(),
+                separator: None,
+            }],
+            scalar_id: false,
+        }))
+    }
+
+    fn sibling_aggregations(right_collection: &str) -> Stage {
+        Stage::Join(Join {
+            join_type: JoinType::Inner,
+            left: aggregation("col", "s", AggregationFunction::Sum),
+            right: aggregation(right_collection, "m", AggregationFunction::Max),
+            let_vars: None,
+            condition: None,
+        })
+    }
+
+    #[test]
+    fn facet_replaces_join_of_sibling_aggregations() {
+        let default_translation =
+            generate_mql(sibling_aggregations("col"), SqlOptions::default()).unwrap();
+        assert_eq!(
+            MqlTranslation {
+                database: Some("mydb".to_string()),
+                collection: Some("col".to_string()),
+                pipeline: vec![
+                    doc! {"$group": {"_id": {}, "s": {"$sum": "$x"}}},
+                    doc! {"$join": {
+                        "collection": "col",
+                        "joinType": "inner",
+                        "pipeline": [{"$group": {"_id": {}, "m": {"$max": "$x"}}}],
+                    }},
+                ],
+            },
+            default_translation
+        );
+
+        let facet_translation = generate_mql(sibling_aggregations("col"), facet_options()).unwrap();
+        assert_eq!(
+            MqlTranslation {
+                database: Some("mydb".to_string()),
+                collection: Some("col".to_string()),
+                pipeline: vec![
+                    doc! {"$facet": {
+                        "facet_0": [{"$group": {"_id": {}, "s": {"$sum": "$x"}}}],
+                        "facet_1": [{"$group": {"_id": {}, "m": {"$max": "$x"}}}],
+                    }},
+                    doc! {"$unwind": "$facet_0"},
+                    doc! {"$unwind": "$facet_1"},
+                    doc! {"$replaceWith": {"$mergeObjects": ["$facet_0", "$facet_1"]}},
+                ],
+            },
+            facet_translation
+        );
+    }
+
+    #[test]
+    fn facet_flattens_nested_sibling_joins() {
+        let input = Stage::Join(Join {
+            join_type: JoinType::Inner,
+            left: Box::new(sibling_aggregations("col")),
+            right: aggregation("col", "n", AggregationFunction::Min),
+            let_vars: None,
+            condition: None,
+        });
+        assert_eq!(
+            vec![
+                doc! {"$facet": {
+                    "facet_0": [{"$group": {"_id": {}, "s": {"$sum": "$x"}}}],
+                    "facet_1": [{"$group": {"_id": {}, "m": {"$max": "$x"}}}],
+                    "facet_2": [{"$group": {"_id": {}, "n": {"$min": "$x"}}}],
+                }},
+                doc! {"$unwind": "$facet_0"},
+                doc! {"$unwind": "$facet_1"},
+                doc! {"$unwind": "$facet_2"},
+                doc! {"$replaceWith": {"$mergeObjects": ["$facet_0", "$facet_1", "$facet_2"]}},
+            ],
+            generate_mql(input, facet_options()).unwrap().pipeline
+        );
+    }
+
+    #[test]
+    fn facet_is_not_used_for_aggregations_over_different_collections() {
+        assert_eq!(
+            generate_mql(sibling_aggregations("col2"), SqlOptions::default()),
+            generate_mql(sibling_aggregations("col2"), facet_options()),
+        );
+    }
+
+    #[test]
+    fn facet_is_not_used_for_aggregations_with_group_keys() {
+        let mut input = sibling_aggregations("col");
+        if let Stage::Join(Join { right, .. }) = &mut input {
+            if let Stage::Group(group) = right.as_mut() {
+                group.keys = vec![NameExprPair {
+                    name: "k".into(),
+                    expr: Expression::FieldRef("k".into()),
+                }];
+            }
+        }
+        assert_eq!(
+            generate_mql(input.clone(), SqlOptions::default()),
+            generate_mql(input, facet_options()),
+        );
+    }
+
+    #[test]
+    fn facet_is_not_used_for_stages_that_add_rows_after_the_group() {
+        let input = Stage::Join(Join {
+            join_type: JoinType::Inner,
+            left: aggregation("col", "s", AggregationFunction::Sum),
+            right: Box::new(Stage::Unwind(Unwind {
+                source: aggregation("col", "m", AggregationFunction::Max),
+                path: Expression::FieldRef("m".into()),
+                index: None,
+                outer: false,
+            })),
+            let_vars: None,
+            condition: None,
+        });
+        assert_eq!(
+            generate_mql(input.clone(), SqlOptions::default()),
+            generate_mql(input, facet_options()),
+        );
+    }
+}
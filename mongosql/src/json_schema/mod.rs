@@ -9,6 +9,11 @@ use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The maximum nesting depth of a JSON schema accepted in a catalog. BSON values
+/// nest at most 100 levels, and each level may add an anyOf or oneOf wrapper, so
+/// this leaves headroom above twice that.
+pub const MAX_DEPTH: usize = 256;
+
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
 pub enum Error {
     #[error("failed to serialize JSON Schema to BSON")]
@@ -92,4 +97,31 @@ impl Schema {
         let deserializer = serde_stacker::Deserializer::new(deserializer);
         Deserialize::deserialize(deserializer).map_err(|_| Error::BsonDeserializationFailure)
     }
+
+    /// Returns the nesting depth of this schema, counting properties, items, anyOf,
+    /// and oneOf as one level each. This is computed iteratively so that arbitrarily
+    /// deep schemas can be measured before any recursive traversal of them.
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((schema, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            let items: &[Schema] = match &schema.items {
+                Some(Items::Single(s)) => std::slice::from_ref(s.as_ref()),
+                Some(Items::Multiple(v)) => v,
+                None => &[],
+            };
+            stack.extend(
+                schema
+                    .properties
+                    .iter()
+                    .flat_map(|p| p.values())
+                    .chain(items)
+                    .chain(schema.any_of.iter().flatten())
+                    .chain(schema.one_of.iter().flatten())
+                    .map(|s| (s, depth + 1)),
+            );
+        }
+        max_depth
+    }
 }
@@ -195,6 +195,58 @@ validate_json_schema!(
     input = r#"{"bsonType":"array","maxItems":0}"#,
 );
 
+mod depth {
+    use super::*;
+
+    #[test]
+    fn atomic() {
+        let schema = Schema {
+            bson_type: Some(BsonType::Single(BsonTypeName::Int)),
+            ..Default::default()
+        };
+        assert_eq!(1, schema.depth());
+    }
+
+    #[test]
+    fn counts_deepest_branch() {
+        let int = Schema {
+            bson_type: Some(BsonType::Single(BsonTypeName::Int)),
+            ..Default::default()
+        };
+        let schema = Schema {
+            bson_type: Some(BsonType::Single(BsonTypeName::Object)),
+            properties: Some(hashmap! {
+                "shallow".to_string() => int.clone(),
+                "deep".to_string() => Schema {
+                    any_of: Some(vec![Schema {
+                        bson_type: Some(BsonType::Single(BsonTypeName::Array)),
+                        items: Some(Items::Single(Box::new(int.clone()))),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }
+            }),
+            ..Default::default()
+        };
+        assert_eq!(4, schema.depth());
+    }
+
+    #[test]
+    fn does_not_overflow_the_stack() {
+        let mut schema = Schema {
+            bson_type: Some(BsonType::Single(BsonTypeName::Int)),
+            ..Default::default()
+        };
+        for _ in 0..1000 {
+            schema = Schema {
+                one_of: Some(vec![schema]),
+                ..Default::default()
+            };
+        }
+        assert_eq!(1001, schema.depth());
+    }
+}
+
 mod no_stack_overflows {
     use super::*;
 
@@ -31,11 +31,12 @@ use base64::{engine::general_purpose, Engine as _};
 
 use crate::{
     algebrizer::Algebrizer,
+    ast::pretty_print::PrettyPrint,
     catalog::Catalog,
     mir::schema::CachedSchema,
     options::{ExcludeNamespacesOption, SqlOptions},
     result::Result,
-    schema::{Schema, SchemaEnvironment},
+    schema::{ResultSet, Schema, SchemaEnvironment},
     translator::MqlTranslator,
 };
 use serde::{Deserialize, Serialize};
@@ -59,9 +60,104 @@ pub fn translate_sql(
     catalog: &Catalog,
     sql_options: SqlOptions,
 ) -> Result<Translation> {
+    let TranslatedPipeline {
+        select_order,
+        schema_env,
+        mapping_registry,
+        mql_translation,
+    } = translate_pipeline(current_db, sql, catalog, sql_options, None)?;
+
+    // A non-empty database value is needed for ADF
+    let target_db = mql_translation
+        .database
+        .clone()
+        .unwrap_or_else(|| current_db.to_string());
+
+    let target_collection = mql_translation.collection;
+
+    let pipeline = bson::Bson::Array(
+        mql_translation
+            .pipeline
+            .into_iter()
+            .map(bson::Bson::Document)
+            .collect(),
+    );
+
+    let result_set_schema =
+        mql_schema_env_to_json_schema(schema_env, &mapping_registry, sql_options)?;
+    let select_order =
+        parse_select_list_order(select_order, result_set_schema.clone(), sql_options);
+
+    Ok(Translation {
+        target_db,
+        target_collection,
+        pipeline,
+        result_set_schema,
+        select_order,
+    })
+}
+
+/// Contains the intermediate representations produced while translating a SQL query. This is
+/// intended for debugging translations and its format is not stable.
+#[derive(Debug, Default)]
+pub struct ExplainOutput {
+    /// The query after syntactic rewrites, pretty printed as SQL.
+    pub rewritten_query: String,
+    /// The optimized mir plan produced by the algebrizer.
+    pub ir: String,
+    /// The air pipeline after desugaring.
+    pub desugared_pipeline: String,
+    /// The final MQL pipeline, as relaxed extended JSON.
+    pub mql: String,
+}
+
+/// Returns the intermediate representations of the provided SQL query in the
+/// specified db, as produced by each stage of translation.
+pub fn explain(
+    current_db: &str,
+    sql: &str,
+    catalog: &Catalog,
+    sql_options: SqlOptions,
+) -> Result<ExplainOutput> {
+    let mut output = ExplainOutput::default();
+    let TranslatedPipeline {
+        mql_translation, ..
+    } = translate_pipeline(current_db, sql, catalog, sql_options, Some(&mut output))?;
+    let pipeline = bson::Bson::Array(
+        mql_translation
+            .pipeline
+            .into_iter()
+            .map(bson::Bson::Document)
+            .collect(),
+    );
+    output.mql = format!("{:#}", pipeline.into_relaxed_extjson());
+    Ok(output)
+}
+
+/// The parts of a translation that translate_sql needs after codegen.
+struct TranslatedPipeline {
+    select_order: Option<ast::SelectBody>,
+    schema_env: SchemaEnvironment,
+    mapping_registry: codegen::MqlMappingRegistry,
+    mql_translation: codegen::MqlTranslation,
+}
+
+/// Runs every step of translation shared by translate_sql and explain, from
+/// parsing through codegen. When `explain` is provided, the intermediate
+/// representation produced by each step is recorded in it.
+fn translate_pipeline(
+    current_db: &str,
+    sql: &str,
+    catalog: &Catalog,
+    sql_options: SqlOptions,
+    mut explain: Option<&mut ExplainOutput>,
+) -> Result<TranslatedPipeline> {
     // parse the query and apply syntactic rewrites
     let ast = parser::parse_query(sql)?;
     let ast = ast::rewrites::rewrite_query(ast)?;
+    if let Some(explain) = explain.as_deref_mut() {
+        explain.rewritten_query = ast.pretty_print()?;
+    }
     let select_order = get_select_order(&ast);
 
     // construct the algebrizer and use it to build an mir plan
@@ -81,6 +177,9 @@ pub fn translate_sql(
         sql_options.schema_checking_mode,
         &algebrizer.schema_inference_state(),
     );
+    if let Some(explain) = explain.as_deref_mut() {
+        explain.ir = format!("{plan:#?}");
+    }
 
     // get the schema_env for the plan
     let schema_env = plan
@@ -96,40 +195,48 @@ pub fn translate_sql(
     let mut translator = MqlTranslator::new(sql_options);
     let agg_plan = translator.translate_plan(plan)?;
 
-    // desugar the air plan
-    let agg_plan = air::desugarer::desugar_pipeline(agg_plan)?;
+    // desugar the air plan and make sure codegen can handle what is left
+    let agg_plan = air::desugarer::desugar_pipeline(agg_plan, sql_options)?;
+    air::validate::ensure_desugared(&agg_plan)
+        .map_err(air::desugarer::Error::IncompleteDesugaring)?;
+    if let Some(explain) = explain {
+        explain.desugared_pipeline = format!("{agg_plan:#?}");
+    }
 
     // codegen the plan into MQL
-    let mql_translation = codegen::generate_mql(agg_plan)?;
+    let mql_translation = codegen::generate_mql(agg_plan, sql_options)?;
 
-    // A non-empty database value is needed for ADF
-    let target_db = mql_translation
-        .database
-        .clone()
-        .unwrap_or_else(|| current_db.to_string());
+    Ok(TranslatedPipeline {
+        select_order,
+        schema_env,
+        mapping_registry: translator.mapping_registry,
+        mql_translation,
+    })
+}
 
-    let target_collection = mql_translation.collection;
+/// Returns the schema of the result set of the provided SQL query in the
+/// specified db, without translating the query to MQL. This can be used to
+/// validate a query and report the types of its output columns.
+pub fn schema_of_sql(
+    current_db: &str,
+    sql: &str,
+    catalog: &Catalog,
+    sql_options: SqlOptions,
+) -> Result<ResultSet> {
+    let ast = parser::parse_query(sql)?;
+    let ast = ast::rewrites::rewrite_query(ast)?;
 
-    let pipeline = bson::Bson::Array(
-        mql_translation
-            .pipeline
-            .into_iter()
-            .map(bson::Bson::Document)
-            .collect(),
+    let algebrizer = Algebrizer::new(
+        current_db,
+        catalog,
+        0u16,
+        sql_options.schema_checking_mode,
+        sql_options.allow_order_by_missing_columns,
+        crate::algebrizer::ClauseType::Unintialized,
     );
+    let plan = algebrizer.algebrize_query(ast)?;
 
-    let result_set_schema =
-        mql_schema_env_to_json_schema(schema_env, &translator.mapping_registry, sql_options)?;
-    let select_order =
-        parse_select_list_order(select_order, result_set_schema.clone(), sql_options);
-
-    Ok(Translation {
-        target_db,
-        target_collection,
-        pipeline,
-        result_set_schema,
-        select_order,
-    })
+    Ok(plan.schema(&algebrizer.schema_inference_state())?)
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, PartialOrd, Ord)]
@@ -352,7 +459,11 @@ pub fn build_catalog_from_catalog_schema(
         .into_iter()
         .flat_map(|(db, coll_schemas)| {
             coll_schemas.into_iter().map(move |(coll, schema)| {
-                let mongosql_schema = Schema::try_from(schema).map_err(|e| e.to_string()).unwrap();
+                let mongosql_schema = Schema::try_from(schema).map_err(|e| {
+                    result::Error::Catalog(format!(
+                        "failed to add JSON schema for collection {db}.{coll} to the catalog: {e}"
+                    ))
+                })?;
                 Ok((
                     catalog::Namespace {
                         db: db.clone(),
@@ -461,4 +572,38 @@ mod build_catalog_test {
         .unwrap();
         assert_eq!(base, schemas);
     }
+
+    fn nested_json_schema(depth: usize) -> Schema {
+        (1..depth).fold(
+            Schema {
+                bson_type: Some(json_schema::BsonType::Single(
+                    json_schema::BsonTypeName::String,
+                )),
+                ..Default::default()
+            },
+            |inner, _| Schema {
+                bson_type: Some(json_schema::BsonType::Single(
+                    json_schema::BsonTypeName::Object,
+                )),
+                properties: Some(std::collections::HashMap::from([("a".to_string(), inner)])),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn build_catalog_rejects_schema_exceeding_max_depth() {
+        let schema = nested_json_schema(json_schema::MAX_DEPTH + 1);
+
+        let actual = build_catalog_from_catalog_schema(map! {
+            "db1".to_string() => map! { "coll1".to_string() => schema },
+        });
+        assert_eq!(
+            Err(result::Error::Catalog(format!(
+                "failed to add JSON schema for collection db1.coll1 to the catalog: schema exceeds the maximum nesting depth of {}",
+                json_schema::MAX_DEPTH
+            ))),
+            actual
+        );
+    }
 }
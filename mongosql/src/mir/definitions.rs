@@ -220,6 +220,8 @@ pub struct AggregationFunctionApplication {
     pub function: AggregationFunction,
     pub distinct: bool,
     pub arg: Box<Expression>,
+    /// The separator of a GroupConcat, which defaults to "," when None.
+    pub separator: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -482,6 +484,7 @@ pub enum AggregationFunction {
     Avg,
     Count,
     First,
+    GroupConcat,
     Last,
     Max,
     MergeDocuments,
@@ -489,6 +492,8 @@ pub enum AggregationFunction {
     StddevPop,
     StddevSamp,
     Sum,
+    VarPop,
+    VarSamp,
 }
 
 impl AggregationFunction {
@@ -499,6 +504,7 @@ impl AggregationFunction {
             AggregationFunction::Avg => "Avg",
             AggregationFunction::Count => "Count",
             AggregationFunction::First => "First",
+            AggregationFunction::GroupConcat => "GroupConcat",
             AggregationFunction::Last => "Last",
             AggregationFunction::Max => "Max",
             AggregationFunction::MergeDocuments => "MergeDocuments",
@@ -506,10 +512,16 @@ impl AggregationFunction {
             AggregationFunction::StddevPop => "StddevPop",
             AggregationFunction::StddevSamp => "StddevSamp",
             AggregationFunction::Sum => "Sum",
+            AggregationFunction::VarPop => "VarPop",
+            AggregationFunction::VarSamp => "VarSamp",
         }
     }
 }
 
+/// The locales accepted by the optional second argument of MonthName and DayName.
+/// The first is the default when no locale is given.
+pub const DATE_NAME_LOCALES: [&str; 4] = ["en_US", "de_DE", "es_ES", "fr_FR"];
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum ScalarFunction {
     // String operators
@@ -524,6 +536,12 @@ pub enum ScalarFunction {
     Sub,
     Mul,
     Div,
+    IntDiv,
+
+    // Bitwise operators
+    BitAnd,
+    BitOr,
+    BitXor,
 
     // Comparison operators
     Lt,
@@ -533,11 +551,16 @@ pub enum ScalarFunction {
     Gt,
     Gte,
     Between,
+    Greatest,
+    Least,
 
     // Boolean operators
     Not,
     And,
     Or,
+    IsTrue,
+    IsFalse,
+    IsUnknown,
 
     // Computed Field Access operator
     // when the field is not known until runtime.
@@ -550,6 +573,9 @@ pub enum ScalarFunction {
     // Array scalar functions
     Slice,
     Size,
+    ArrayLength,
+    ArrayDistinct,
+    SortArray,
 
     // Numeric value scalar functions
     Position,
@@ -573,6 +599,10 @@ pub enum ScalarFunction {
     // String value scalar functions
     Replace,
     Substring,
+    Left,
+    Right,
+    LPad,
+    RPad,
     Upper,
     Lower,
     BTrim,
@@ -594,6 +624,12 @@ pub enum ScalarFunction {
     DayOfYear,
     IsoWeek,
     IsoWeekday,
+    MonthName,
+    DayName,
+    UnixTimestamp,
+    FromUnixTimestamp,
+    DateFormat,
+    ParseDate,
 
     // MergeObjects merges an array of objects
     MergeObjects,
@@ -624,17 +660,31 @@ impl ScalarFunction {
             ScalarFunction::IsoWeekday => "IsoWeekday",
             ScalarFunction::DayOfWeek => "DayOfWeek",
             ScalarFunction::DayOfYear => "DayOfYear",
+            ScalarFunction::MonthName => "MonthName",
+            ScalarFunction::DayName => "DayName",
+            ScalarFunction::UnixTimestamp => "UnixTimestamp",
+            ScalarFunction::FromUnixTimestamp => "FromUnixTimestamp",
+            ScalarFunction::DateFormat => "DateFormat",
+            ScalarFunction::ParseDate => "ParseDate",
             ScalarFunction::Abs => "Abs",
             ScalarFunction::Ceil => "Ceil",
             ScalarFunction::Degrees => "Degrees",
             ScalarFunction::Div => "Div",
+            ScalarFunction::IntDiv => "IntDiv",
+            ScalarFunction::BitAnd => "BitAnd",
+            ScalarFunction::BitOr => "BitOr",
+            ScalarFunction::BitXor => "BitXor",
             ScalarFunction::Eq => "Eq",
             ScalarFunction::Floor => "Floor",
             ScalarFunction::Gt => "Gt",
             ScalarFunction::Gte => "Gte",
             ScalarFunction::Between => "Between",
+            ScalarFunction::Greatest => "Greatest",
+            ScalarFunction::Least => "Least",
+            ScalarFunction::Left => "Left",
             ScalarFunction::Log => "Log",
             ScalarFunction::Lower => "Lower",
+            ScalarFunction::LPad => "LPad",
             ScalarFunction::Lt => "Lt",
             ScalarFunction::Lte => "Lte",
             ScalarFunction::Mod => "Mod",
@@ -645,15 +695,23 @@ impl ScalarFunction {
             ScalarFunction::NullIf => "NullIf",
             ScalarFunction::OctetLength => "OctetLength",
             ScalarFunction::Or => "Or",
+            ScalarFunction::IsTrue => "IsTrue",
+            ScalarFunction::IsFalse => "IsFalse",
+            ScalarFunction::IsUnknown => "IsUnknown",
             ScalarFunction::Pos => "Pos",
             ScalarFunction::Position => "Position",
             ScalarFunction::Pow => "Pow",
             ScalarFunction::Radians => "Radians",
             ScalarFunction::Replace => "Replace",
+            ScalarFunction::Right => "Right",
             ScalarFunction::Round => "Round",
+            ScalarFunction::RPad => "RPad",
             ScalarFunction::Sin => "Sin",
             ScalarFunction::Size => "Size",
+            ScalarFunction::ArrayLength => "ArrayLength",
             ScalarFunction::Slice => "Slice",
+            ScalarFunction::ArrayDistinct => "ArrayDistinct",
+            ScalarFunction::SortArray => "SortArray",
             ScalarFunction::Split => "Split",
             ScalarFunction::Sqrt => "Sqrt",
             ScalarFunction::Sub => "Sub",
@@ -681,6 +739,7 @@ impl ScalarFunction {
             | ScalarFunction::Sin
             // number / divisor : A divisor of 0 will result in NULL.
             | ScalarFunction::Div
+            | ScalarFunction::IntDiv
             // MOD( number, divisor ) : A divisor of 0 will result in NULL.
             | ScalarFunction::Mod
             // NULLIF (arg1, arg2) returns NULL if arg1 = arg2
@@ -690,10 +749,20 @@ impl ScalarFunction {
             // TAN( number ) : If the argument evaluates to negative or positive Infinity, the result of the operation is NULL
             | ScalarFunction::Tan
             // SPLIT(string, delimiter, token number) : If any argument is NULL or MISSING, or a delimiter evaluates to an empty string, the result is NULL.
-            | ScalarFunction::Split => true,
+            | ScalarFunction::Split
+            // ParseDate(string, format[, on_null, on_error]) : If the string cannot be parsed with the format, the result is on_error, which defaults to NULL.
+            | ScalarFunction::ParseDate
+            // GREATEST(v1, ..., vn) and LEAST(v1, ..., vn) : NULL and MISSING arguments are ignored, so the result is NULL when every argument is.
+            | ScalarFunction::Greatest
+            | ScalarFunction::Least
+            // ArrayLength(value) : If the argument is not an array, the result is NULL.
+            | ScalarFunction::ArrayLength => true,
 
             ScalarFunction::Add
             | ScalarFunction::And
+            | ScalarFunction::BitAnd
+            | ScalarFunction::BitOr
+            | ScalarFunction::BitXor
             | ScalarFunction::BitLength
             | ScalarFunction::CharLength
             | ScalarFunction::ComputedFieldAccess
@@ -711,6 +780,11 @@ impl ScalarFunction {
             | ScalarFunction::IsoWeekday
             | ScalarFunction::DayOfWeek
             | ScalarFunction::DayOfYear
+            | ScalarFunction::MonthName
+            | ScalarFunction::DayName
+            | ScalarFunction::UnixTimestamp
+            | ScalarFunction::FromUnixTimestamp
+            | ScalarFunction::DateFormat
             | ScalarFunction::Abs
             | ScalarFunction::Ceil
             | ScalarFunction::Degrees
@@ -729,6 +803,9 @@ impl ScalarFunction {
             | ScalarFunction::Not
             | ScalarFunction::OctetLength
             | ScalarFunction::Or
+            | ScalarFunction::IsTrue
+            | ScalarFunction::IsFalse
+            | ScalarFunction::IsUnknown
             | ScalarFunction::Pos
             | ScalarFunction::Position
             | ScalarFunction::Pow
@@ -736,9 +813,15 @@ impl ScalarFunction {
             | ScalarFunction::Replace
             | ScalarFunction::Size
             | ScalarFunction::Slice
+            | ScalarFunction::ArrayDistinct
+            | ScalarFunction::SortArray
             | ScalarFunction::Sqrt
             | ScalarFunction::Sub
             | ScalarFunction::Substring
+            | ScalarFunction::Left
+            | ScalarFunction::Right
+            | ScalarFunction::LPad
+            | ScalarFunction::RPad
             | ScalarFunction::LTrim
             | ScalarFunction::RTrim
             | ScalarFunction::BTrim
@@ -765,6 +848,7 @@ pub enum DatePart {
 pub enum DateFunction {
     Add,
     Diff,
+    Sub,
     Trunc,
 }
 
@@ -784,6 +868,7 @@ impl DateFunction {
         match self {
             DateFunction::Add => "DateAdd",
             DateFunction::Diff => "DateDiff",
+            DateFunction::Sub => "DateSub",
             DateFunction::Trunc => "DateTrunc",
         }
     }
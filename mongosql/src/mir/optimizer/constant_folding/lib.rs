@@ -6,7 +6,13 @@
 ///
 use crate::{
     catalog::Catalog,
-    mir::{definitions::*, schema::SchemaInferenceState, visitor::Visitor},
+    map,
+    mir::{
+        binding_tuple::{BindingTuple, Key},
+        definitions::*,
+        schema::{SchemaCache, SchemaInferenceState},
+        visitor::Visitor,
+    },
     schema::{Atomic, Satisfaction, Schema, NULLISH},
 };
 use lazy_static::lazy_static;
@@ -62,7 +68,10 @@ impl ConstantFoldExprVisitor<'_> {
         }
     }
 
-    // Constant folds boolean functions
+    // Constant folds boolean functions following three-valued logic. A false literal in an And
+    // (or a true literal in an Or) short-circuits the whole function regardless of null or
+    // non-literal arguments. Otherwise the boolean literals are dropped and any nulls collapse
+    // into a single null argument, so e.g. `true AND null` folds to null.
     fn fold_logical_function(&mut self, sf: ScalarFunctionApplication) -> (Expression, bool) {
         let (nullish, non_nullish): (Vec<Expression>, Vec<Expression>) =
             sf.args.clone().into_iter().partition(|e| {
@@ -136,6 +145,19 @@ impl ConstantFoldExprVisitor<'_> {
                 _ => unreachable!("fold associative function only called on Add and Mul"),
             }
         }
+        if sf.args.iter().all(Self::is_numeric_literal) {
+            let folded = sf.args[1..]
+                .iter()
+                .try_fold(sf.args[0].clone(), |acc, arg| {
+                    Self::fold_numeric_literals(sf.function, &acc, arg)
+                });
+            // If the result overflows we leave the function as is and let it be evaluated
+            // at query time.
+            return match folded {
+                Some(expr) => (expr, true),
+                None => (Expression::ScalarFunction(sf), false),
+            };
+        }
         let mut non_literals = Vec::<Expression>::new();
         let (int_fold, long_fold, float_fold, arg_count) = match sf.function {
             ScalarFunction::Add => {
@@ -220,6 +242,71 @@ impl ConstantFoldExprVisitor<'_> {
         )
     }
 
+    // Checks if an expression is an Integer, Long, or Double literal. Decimal128 literals are
+    // not considered since we cannot perform decimal arithmetic at compile time.
+    fn is_numeric_literal(expr: &Expression) -> bool {
+        matches!(
+            expr,
+            Expression::Literal(
+                LiteralValue::Integer(_) | LiteralValue::Long(_) | LiteralValue::Double(_)
+            )
+        )
+    }
+
+    // Applies an arithmetic function to two numeric literals, promoting the result to the
+    // wider of the two argument types (Integer < Long < Double). An Integer result that does
+    // not fit in an Integer is promoted to a Long. Returns None if either argument is not a
+    // numeric literal or if the result cannot be represented as a Long. Callers are expected
+    // to have already handled division by zero.
+    fn fold_numeric_literals(
+        function: ScalarFunction,
+        left: &Expression,
+        right: &Expression,
+    ) -> Option<Expression> {
+        let long_op = |l: i64, r: i64| match function {
+            ScalarFunction::Add => l.checked_add(r),
+            ScalarFunction::Sub => l.checked_sub(r),
+            ScalarFunction::Mul => l.checked_mul(r),
+            ScalarFunction::Div => l.checked_div(r),
+            _ => unreachable!("fold numeric literals is only called on arithmetic functions"),
+        };
+        let double_op = |l: f64, r: f64| match function {
+            ScalarFunction::Add => l + r,
+            ScalarFunction::Sub => l - r,
+            ScalarFunction::Mul => l * r,
+            ScalarFunction::Div => l / r,
+            _ => unreachable!("fold numeric literals is only called on arithmetic functions"),
+        };
+        let as_double = |lit: &LiteralValue| match lit {
+            LiteralValue::Integer(i) => Some(*i as f64),
+            LiteralValue::Long(l) => Some(*l as f64),
+            LiteralValue::Double(d) => Some(*d),
+            _ => None,
+        };
+        let (left, right) = match (left, right) {
+            (Expression::Literal(l), Expression::Literal(r)) => (l, r),
+            _ => return None,
+        };
+        let folded = match (left, right) {
+            (LiteralValue::Integer(l), LiteralValue::Integer(r)) => {
+                let result = long_op(*l as i64, *r as i64)?;
+                i32::try_from(result).map_or(LiteralValue::Long(result), LiteralValue::Integer)
+            }
+            (LiteralValue::Integer(l), LiteralValue::Long(r)) => {
+                LiteralValue::Long(long_op(*l as i64, *r)?)
+            }
+            (LiteralValue::Long(l), LiteralValue::Integer(r)) => {
+                LiteralValue::Long(long_op(*l, *r as i64)?)
+            }
+            (LiteralValue::Long(l), LiteralValue::Long(r)) => LiteralValue::Long(long_op(*l, *r)?),
+            (LiteralValue::Double(_), _) | (_, LiteralValue::Double(_)) => {
+                LiteralValue::Double(double_op(as_double(left)?, as_double(right)?))
+            }
+            _ => return None,
+        };
+        Some(Expression::Literal(folded))
+    }
+
     // Constant folds binary arithmetic functions: subtract and divide
     fn fold_binary_arithmetic_function(
         &mut self,
@@ -232,21 +319,7 @@ impl ConstantFoldExprVisitor<'_> {
                 if Self::numeric_eq(&right, 0.0) {
                     Some(left)
                 } else {
-                    match (&left, &right) {
-                        (
-                            Expression::Literal(LiteralValue::Integer(l)),
-                            Expression::Literal(LiteralValue::Integer(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Integer(l - r))),
-                        (
-                            Expression::Literal(LiteralValue::Long(l)),
-                            Expression::Literal(LiteralValue::Long(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Long(l - r))),
-                        (
-                            Expression::Literal(LiteralValue::Double(l)),
-                            Expression::Literal(LiteralValue::Double(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Double(l - r))),
-                        _ => None,
-                    }
+                    Self::fold_numeric_literals(function, &left, &right)
                 }
             }
             ScalarFunction::Div => {
@@ -255,27 +328,35 @@ impl ConstantFoldExprVisitor<'_> {
                 } else if Self::numeric_eq(&right, 1.0) {
                     Some(left)
                 } else {
-                    match (&left, &right) {
-                        (
-                            Expression::Literal(LiteralValue::Integer(l)),
-                            Expression::Literal(LiteralValue::Integer(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Integer(l / r))),
-                        (
-                            Expression::Literal(LiteralValue::Long(l)),
-                            Expression::Literal(LiteralValue::Long(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Long(l / r))),
-                        (
-                            Expression::Literal(LiteralValue::Double(l)),
-                            Expression::Literal(LiteralValue::Double(r)),
-                        ) => Some(Expression::Literal(LiteralValue::Double(l / r))),
-                        _ => None,
-                    }
+                    Self::fold_numeric_literals(function, &left, &right)
                 }
             }
             _ => unreachable!("fold binary arithmetic only called on sub and div"),
         }
     }
 
+    // Compares two numeric literals by value, regardless of their numeric types. Integers and
+    // Longs are compared exactly; if either side is a Double both are compared as Doubles.
+    // Returns None if either literal is not an Integer, Long, or Double, or if a Double is NaN.
+    fn compare_numeric_literals(
+        left: &LiteralValue,
+        right: &LiteralValue,
+    ) -> Option<std::cmp::Ordering> {
+        let as_long = |lit: &LiteralValue| match lit {
+            LiteralValue::Integer(i) => Some(*i as i64),
+            LiteralValue::Long(l) => Some(*l),
+            _ => None,
+        };
+        let as_double = |lit: &LiteralValue| match lit {
+            LiteralValue::Double(d) => Some(*d),
+            _ => as_long(lit).map(|l| l as f64),
+        };
+        match (as_long(left), as_long(right)) {
+            (Some(l), Some(r)) => Some(l.cmp(&r)),
+            _ => as_double(left)?.partial_cmp(&as_double(right)?),
+        }
+    }
+
     // Constant folds binary comparison functions
     fn fold_comparison_function(
         &mut self,
@@ -289,22 +370,15 @@ impl ConstantFoldExprVisitor<'_> {
                 Expression::Literal(LiteralValue::Boolean(l)),
                 Expression::Literal(LiteralValue::Boolean(r)),
             ) => l.partial_cmp(r),
-            (
-                Expression::Literal(LiteralValue::Integer(l)),
-                Expression::Literal(LiteralValue::Integer(r)),
-            ) => l.partial_cmp(r),
-            (
-                Expression::Literal(LiteralValue::Long(l)),
-                Expression::Literal(LiteralValue::Long(r)),
-            ) => l.partial_cmp(r),
-            (
-                Expression::Literal(LiteralValue::Double(l)),
-                Expression::Literal(LiteralValue::Double(r)),
-            ) => l.partial_cmp(r),
             (
                 Expression::Literal(LiteralValue::String(l)),
                 Expression::Literal(LiteralValue::String(r)),
             ) => l.partial_cmp(r),
+            (Expression::Literal(l), Expression::Literal(r)) => {
+                Self::compare_numeric_literals(l, r)
+            }
+            // Incomparable types are left for query time, since schema checking is responsible
+            // for rejecting them.
             _ => None,
         };
         ord.map(|ord_val| {
@@ -370,6 +444,20 @@ impl ConstantFoldExprVisitor<'_> {
             } else {
                 None
             }
+        } else if let Expression::ScalarFunction(ScalarFunctionApplication {
+            function: ScalarFunction::Not,
+            ref args,
+            ..
+        }) = arg
+        {
+            // The argument to Not is schema checked to be boolean or nullish, so a double
+            // negation is the identity. Since we fold bottom up, deeper nestings of Not have
+            // already been collapsed by the time we get here.
+            if func == ScalarFunction::Not {
+                Some(args[0].clone())
+            } else {
+                None
+            }
         } else if let Expression::Literal(lit) = arg {
             match func {
                 ScalarFunction::Pos => match lit {
@@ -609,29 +697,43 @@ impl ConstantFoldExprVisitor<'_> {
         if sf.args.is_empty() {
             return (Expression::Literal(LiteralValue::Null), true);
         }
-        let mut is_all_null = true;
-        for expr in &sf.args {
-            match expr.schema(self.state) {
-                Err(_) => {
-                    // If an Err occurs, it means there is a reference in this `expr`, so we cannot
-                    // possibly know if this expression satisfies NULLISH, thus is_all_null must be
-                    // set to false.
-                    is_all_null = false;
-                    break;
-                }
-                Ok(sch) => {
-                    let sat = sch.satisfies(&NULLISH);
-                    if sat == Satisfaction::Not {
-                        return (expr.clone(), true);
-                    }
-                    is_all_null = is_all_null && sat == Satisfaction::Must;
+        // Leading arguments that must be null or missing can never be the result, so they are
+        // dropped. If an argument's schema cannot be determined (e.g. it contains a reference),
+        // we must assume it may be non-null and stop.
+        let nullish_prefix_len = sf
+            .args
+            .iter()
+            .take_while(|expr| {
+                expr.schema(self.state)
+                    .is_ok_and(|sch| sch.satisfies(&NULLISH) == Satisfaction::Must)
+            })
+            .count();
+        let mut args = sf.args[nullish_prefix_len..].to_vec();
+        match args.first() {
+            None => return (Expression::Literal(LiteralValue::Null), true),
+            Some(first) => {
+                // The first surviving argument is always the result if it is never null. A
+                // lone argument that may be null is kept wrapped, since COALESCE turns a
+                // missing value into null.
+                let never_null = first
+                    .schema(self.state)
+                    .is_ok_and(|sch| sch.satisfies(&NULLISH) == Satisfaction::Not);
+                if never_null {
+                    return (args.swap_remove(0), true);
                 }
             }
         }
-        if is_all_null {
-            return (Expression::Literal(LiteralValue::Null), true);
+        if nullish_prefix_len == 0 {
+            return (Expression::ScalarFunction(sf), false);
         }
-        (Expression::ScalarFunction(sf), false)
+        (
+            Expression::ScalarFunction(ScalarFunctionApplication {
+                function: sf.function,
+                is_nullable: sf.is_nullable,
+                args,
+            }),
+            true,
+        )
     }
 
     // Constant folds the merge objects function
@@ -938,6 +1040,54 @@ impl ConstantFoldExprVisitor<'_> {
         (Stage::Filter(filter_stage), false)
     }
 
+    // Folds a group stage with no keys over a non-empty array datasource when every
+    // aggregation is a non-distinct COUNT(*). The number of rows is known statically, so
+    // the group is replaced by a project of the literal counts over a single-row array.
+    // Empty arrays are not folded, since a keyless group over no rows produces no rows.
+    fn fold_group_stage(&mut self, group_stage: Group) -> (Stage, bool) {
+        use crate::util::unique_linked_hash_map::UniqueLinkedHashMap;
+        let (alias, count) = match &*group_stage.source {
+            Stage::Array(ArraySource { array, alias, .. })
+                if !array.is_empty()
+                    && group_stage.keys.is_empty()
+                    && !group_stage.aggregations.is_empty()
+                    && group_stage
+                        .aggregations
+                        .iter()
+                        .all(|agg| agg.agg_expr == AggregationExpr::CountStar(false)) =>
+            {
+                (alias.clone(), array.len())
+            }
+            _ => return (Stage::Group(group_stage), false),
+        };
+        let count = i32::try_from(count)
+            .map(LiteralValue::Integer)
+            .unwrap_or(LiteralValue::Long(count as i64));
+        let document: linked_hash_map::LinkedHashMap<_, _> = group_stage
+            .aggregations
+            .into_iter()
+            .map(|agg| (agg.alias, Expression::Literal(count.clone())))
+            .collect();
+        (
+            Stage::Project(Project {
+                is_add_fields: false,
+                source: Box::new(Stage::Array(ArraySource {
+                    array: vec![Expression::Document(DocumentExpr {
+                        document: UniqueLinkedHashMap::new(),
+                    })],
+                    alias,
+                    cache: SchemaCache::new(),
+                })),
+                expression: BindingTuple(map! {
+                    Key::bot(group_stage.scope) =>
+                        Expression::Document(UniqueLinkedHashMap::from(document).into()),
+                }),
+                cache: SchemaCache::new(),
+            }),
+            true,
+        )
+    }
+
     // Folds the offset stage
     fn fold_offset_stage(&mut self, offset_stage: Offset) -> (Stage, bool) {
         if offset_stage.offset == 0 {
@@ -1030,7 +1180,7 @@ impl Visitor for ConstantFoldExprVisitor<'_> {
             Stage::Array(_) => (st, false),
             Stage::Collection(_) => (st, false),
             Stage::Filter(filter) => self.fold_filter_stage(filter),
-            Stage::Group(_) => (st, false),
+            Stage::Group(group) => self.fold_group_stage(group),
             Stage::Join(_) => (st, false),
             Stage::Limit(_) => (st, false),
             Stage::Offset(offset) => self.fold_offset_stage(offset),
@@ -280,6 +280,175 @@ mod constant_folding {
             cache: SchemaCache::new(),
         }),
     );
+    test_constant_fold!(
+        true_or_null_is_true,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Or,
+                vec![
+                    Expression::Literal(LiteralValue::Boolean(true)),
+                    Expression::Literal(LiteralValue::Null)
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        false_and_null_is_false,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::And,
+                vec![
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Boolean(false))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        null_or_null_folds_to_null,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Null)],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Or,
+                vec![
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Null)
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        true_and_true_and_false_is_false,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::And,
+                vec![
+                    Expression::Literal(LiteralValue::Boolean(true)),
+                    Expression::Literal(LiteralValue::Boolean(true)),
+                    Expression::Literal(LiteralValue::Boolean(false))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        false_or_false_or_true_is_true,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Or,
+                vec![
+                    Expression::Literal(LiteralValue::Boolean(false)),
+                    Expression::Literal(LiteralValue::Boolean(false)),
+                    Expression::Literal(LiteralValue::Boolean(true))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        true_and_ref_is_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Reference(("a", 0u16).into())],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::And,
+                vec![
+                    Expression::Literal(LiteralValue::Boolean(true)),
+                    Expression::Reference(("a", 0u16).into())
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        false_or_ref_is_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Reference(("a", 0u16).into())],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Or,
+                vec![
+                    Expression::Reference(("a", 0u16).into()),
+                    Expression::Literal(LiteralValue::Boolean(false))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        and_true_literal_between_refs_is_removed,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::And,
+                vec![
+                    Expression::Reference(("a", 0u16).into()),
+                    Expression::Reference(("b", 0u16).into())
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::And,
+                vec![
+                    Expression::Reference(("a", 0u16).into()),
+                    Expression::Literal(LiteralValue::Boolean(true)),
+                    Expression::Reference(("b", 0u16).into())
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
     test_constant_fold!(
         or_empty,
         expected = Stage::Array(ArraySource {
@@ -590,14 +759,7 @@ mod constant_folding {
         add_different_num_types,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
-            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Add,
-                vec![
-                    Expression::Literal(LiteralValue::Integer(2)),
-                    Expression::Literal(LiteralValue::Long(4)),
-                    Expression::Literal(LiteralValue::Double(6.0))
-                ],
-            ))],
+            array: vec![Expression::Literal(LiteralValue::Double(12.0))],
             cache: SchemaCache::new(),
         }),
         expected_changed = true,
@@ -621,14 +783,7 @@ mod constant_folding {
         mul_different_num_types,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
-            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Mul,
-                vec![
-                    Expression::Literal(LiteralValue::Integer(4)),
-                    Expression::Literal(LiteralValue::Long(9)),
-                    Expression::Literal(LiteralValue::Double(16.0))
-                ],
-            ))],
+            array: vec![Expression::Literal(LiteralValue::Double(576.0))],
             cache: SchemaCache::new(),
         }),
         expected_changed = true,
@@ -648,6 +803,193 @@ mod constant_folding {
             cache: SchemaCache::new(),
         }),
     );
+    test_constant_fold!(
+        add_int_literals,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Integer(3))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::Integer(2))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        mul_double_and_int_literals,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Double(6.0))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Mul,
+                vec![
+                    Expression::Literal(LiteralValue::Double(2.0)),
+                    Expression::Literal(LiteralValue::Integer(3))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        add_int_and_long_literals_promotes_to_long,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Long(3))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::Long(2))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        add_int_literals_overflow_promotes_to_long,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Long(2147483648))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(i32::MAX)),
+                    Expression::Literal(LiteralValue::Integer(1))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        add_literals_with_null_is_null,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Null)],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Long(2))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        sub_long_and_double_literals_promotes_to_double,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Double(0.5))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Sub,
+                vec![
+                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Double(0.5))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        div_long_by_int_literals_promotes_to_long,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Long(3))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Div,
+                vec![
+                    Expression::Literal(LiteralValue::Long(7)),
+                    Expression::Literal(LiteralValue::Integer(2))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        div_literal_by_zero_is_null,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Null)],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Div,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::Integer(0))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        add_long_literals_overflow_is_not_folded,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Long(i64::MAX)),
+                    Expression::Literal(LiteralValue::Long(1))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = false,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Add,
+                vec![
+                    Expression::Literal(LiteralValue::Long(i64::MAX)),
+                    Expression::Literal(LiteralValue::Long(1))
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
     test_constant_fold!(
         sub_ref_by_zero_is_ref,
         expected = Stage::Array(ArraySource {
@@ -982,27 +1324,141 @@ mod constant_folding {
         }),
     );
     test_constant_fold!(
-        is_not_greater_than_equal,
+        is_not_greater_than_equal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Gte,
+                vec![
+                    Expression::Literal(LiteralValue::Double(0.0)),
+                    Expression::Literal(LiteralValue::Double(1.0)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        is_equal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Eq,
+                vec![
+                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Long(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        is_not_equal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Eq,
+                vec![
+                    Expression::Literal(LiteralValue::Long(0)),
+                    Expression::Literal(LiteralValue::Long(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        is_nequal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Neq,
+                vec![
+                    Expression::Literal(LiteralValue::Long(0)),
+                    Expression::Literal(LiteralValue::Long(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        is_not_nequal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Neq,
+                vec![
+                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Long(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold_no_op!(
+        compare_incomparable_datatypes,
+        Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Neq,
+                vec![
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::String("1".into())),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        })
+    );
+    test_constant_fold!(
+        int_less_than_int,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
-            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
             cache: SchemaCache::new(),
         }),
         expected_changed = true,
         input = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Gte,
+                ScalarFunction::Lt,
                 vec![
-                    Expression::Literal(LiteralValue::Double(0.0)),
-                    Expression::Literal(LiteralValue::Double(1.0)),
+                    Expression::Literal(LiteralValue::Integer(1)),
+                    Expression::Literal(LiteralValue::Integer(2)),
                 ],
             ))],
             cache: SchemaCache::new(),
         }),
     );
     test_constant_fold!(
-        is_equal,
+        double_equal_int_compares_by_value,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::Literal(LiteralValue::Boolean(true))],
@@ -1014,15 +1470,15 @@ mod constant_folding {
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
                 ScalarFunction::Eq,
                 vec![
-                    Expression::Literal(LiteralValue::Long(1)),
-                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Double(2.0)),
+                    Expression::Literal(LiteralValue::Integer(2)),
                 ],
             ))],
             cache: SchemaCache::new(),
         }),
     );
     test_constant_fold!(
-        is_not_equal,
+        int_neq_long_compares_by_value,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::Literal(LiteralValue::Boolean(false))],
@@ -1032,9 +1488,9 @@ mod constant_folding {
         input = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Eq,
+                ScalarFunction::Neq,
                 vec![
-                    Expression::Literal(LiteralValue::Long(0)),
+                    Expression::Literal(LiteralValue::Integer(1)),
                     Expression::Literal(LiteralValue::Long(1)),
                 ],
             ))],
@@ -1042,7 +1498,7 @@ mod constant_folding {
         }),
     );
     test_constant_fold!(
-        is_nequal,
+        long_greater_than_double_compares_by_value,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::Literal(LiteralValue::Boolean(true))],
@@ -1052,48 +1508,54 @@ mod constant_folding {
         input = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Neq,
+                ScalarFunction::Gt,
                 vec![
-                    Expression::Literal(LiteralValue::Long(0)),
-                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Long(3)),
+                    Expression::Literal(LiteralValue::Double(2.5)),
                 ],
             ))],
             cache: SchemaCache::new(),
         }),
     );
     test_constant_fold!(
-        is_not_nequal,
+        null_equal_int_is_null,
         expected = Stage::Array(ArraySource {
             alias: "".into(),
-            array: vec![Expression::Literal(LiteralValue::Boolean(false))],
+            array: vec![Expression::Literal(LiteralValue::Null)],
             cache: SchemaCache::new(),
         }),
         expected_changed = true,
         input = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Neq,
+                ScalarFunction::Eq,
                 vec![
-                    Expression::Literal(LiteralValue::Long(1)),
-                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Integer(1)),
                 ],
             ))],
             cache: SchemaCache::new(),
         }),
     );
-    test_constant_fold_no_op!(
-        compare_different_datatypes,
-        Stage::Array(ArraySource {
+    test_constant_fold!(
+        string_less_than_string,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Boolean(true))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
             alias: "".into(),
             array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
-                ScalarFunction::Neq,
+                ScalarFunction::Lt,
                 vec![
-                    Expression::Literal(LiteralValue::Integer(1)),
-                    Expression::Literal(LiteralValue::Long(1)),
+                    Expression::Literal(LiteralValue::String("abc".into())),
+                    Expression::Literal(LiteralValue::String("abd".into())),
                 ],
             ))],
             cache: SchemaCache::new(),
-        })
+        }),
     );
     test_constant_fold!(
         compare_null_is_null,
@@ -1278,6 +1740,95 @@ mod constant_folding {
             cache: SchemaCache::new(),
         }),
     );
+    test_constant_fold!(
+        not_not_ref_is_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Reference(("a", 0u16).into())],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Not,
+                vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                    ScalarFunction::Not,
+                    vec![Expression::Reference(("a", 0u16).into())],
+                ))],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        not_not_not_ref_is_not_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Not,
+                vec![Expression::Reference(("a", 0u16).into())],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Not,
+                vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                    ScalarFunction::Not,
+                    vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                        ScalarFunction::Not,
+                        vec![Expression::Reference(("a", 0u16).into())],
+                    ))],
+                ))],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        not_not_not_not_ref_is_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Reference(("a", 0u16).into())],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Not,
+                vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                    ScalarFunction::Not,
+                    vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                        ScalarFunction::Not,
+                        vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                            ScalarFunction::Not,
+                            vec![Expression::Reference(("a", 0u16).into())],
+                        ))],
+                    ))],
+                ))],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        not_null_is_null,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Null)],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Not,
+                vec![Expression::Literal(LiteralValue::Null)],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
     test_constant_fold!(
         upper_simple,
         expected = Stage::Array(ArraySource {
@@ -1921,6 +2472,105 @@ mod constant_folding {
             cache: SchemaCache::new(),
         }),
     );
+    test_constant_fold!(
+        coalesce_null_then_literal_is_literal,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::Literal(LiteralValue::Integer(5))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Integer(5)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        coalesce_ref_then_literal_is_unchanged,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Reference(("x", 0u16).into()),
+                    Expression::Literal(LiteralValue::Integer(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = false,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Reference(("x", 0u16).into()),
+                    Expression::Literal(LiteralValue::Integer(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        coalesce_drops_leading_nulls_before_ref,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Reference(("x", 0u16).into()),
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Integer(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Reference(("x", 0u16).into()),
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Literal(LiteralValue::Integer(1)),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
+    test_constant_fold!(
+        coalesce_nulls_then_single_ref_keeps_coalesce,
+        expected = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![Expression::Reference(("x", 0u16).into())],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = Stage::Array(ArraySource {
+            alias: "".into(),
+            array: vec![Expression::ScalarFunction(ScalarFunctionApplication::new(
+                ScalarFunction::Coalesce,
+                vec![
+                    Expression::Literal(LiteralValue::Null),
+                    Expression::Reference(("x", 0u16).into()),
+                ],
+            ))],
+            cache: SchemaCache::new(),
+        }),
+    );
     test_constant_fold!(
         merge_objects_simple,
         expected = Stage::Array(ArraySource {
@@ -3215,4 +3865,117 @@ mod constant_folding {
             cache: SchemaCache::new(),
         }),
     );
+
+    fn count_star_group(array: Vec<Expression>, distinct: bool) -> Stage {
+        Stage::Group(Group {
+            source: Box::new(Stage::Array(ArraySource {
+                array,
+                alias: "arr".to_string(),
+                cache: SchemaCache::new(),
+            })),
+            keys: vec![],
+            aggregations: vec![AliasedAggregation {
+                alias: "c".to_string(),
+                agg_expr: AggregationExpr::CountStar(distinct),
+            }],
+            cache: SchemaCache::new(),
+            scope: 0,
+        })
+    }
+
+    fn three_element_array() -> Vec<Expression> {
+        vec![
+            Expression::Document(unchecked_unique_linked_hash_map! {"a".to_string() => Expression::Literal(LiteralValue::Integer(1))}.into()),
+            Expression::Document(unchecked_unique_linked_hash_map! {"a".to_string() => Expression::Literal(LiteralValue::Integer(2))}.into()),
+            Expression::Document(unchecked_unique_linked_hash_map! {"a".to_string() => Expression::Literal(LiteralValue::Integer(2))}.into()),
+        ]
+    }
+
+    test_constant_fold!(
+        count_star_over_array_is_folded,
+        expected = Stage::Project(Project {
+            is_add_fields: false,
+            source: Box::new(Stage::Array(ArraySource {
+                array: vec![Expression::Document(DocumentExpr {
+                    document: unchecked_unique_linked_hash_map! {},
+                })],
+                alias: "arr".to_string(),
+                cache: SchemaCache::new(),
+            })),
+            expression: map! {
+                (Bottom, 0u16).into() => Expression::Document(DocumentExpr {
+                    document: unchecked_unique_linked_hash_map! {
+                        "c".to_string() => Expression::Literal(LiteralValue::Integer(3)),
+                    },
+                }),
+            },
+            cache: SchemaCache::new(),
+        }),
+        expected_changed = true,
+        input = count_star_group(three_element_array(), false),
+    );
+    test_constant_fold_no_op!(
+        count_distinct_star_over_array_is_not_folded,
+        count_star_group(three_element_array(), true)
+    );
+    test_constant_fold_no_op!(
+        count_star_over_empty_array_is_not_folded,
+        count_star_group(vec![], false)
+    );
+    test_constant_fold_no_op!(
+        count_star_over_collection_is_not_folded,
+        Stage::Group(Group {
+            source: Box::new(test_source()),
+            keys: vec![],
+            aggregations: vec![AliasedAggregation {
+                alias: "c".to_string(),
+                agg_expr: AggregationExpr::CountStar(false),
+            }],
+            cache: SchemaCache::new(),
+            scope: 0,
+        })
+    );
+    test_constant_fold_no_op!(
+        count_star_over_filtered_array_is_not_folded,
+        Stage::Group(Group {
+            source: Box::new(Stage::Filter(Filter {
+                source: Box::new(Stage::Array(ArraySource {
+                    array: three_element_array(),
+                    alias: "arr".to_string(),
+                    cache: SchemaCache::new(),
+                })),
+                condition: Expression::Reference(("a", 0u16).into()),
+                cache: SchemaCache::new(),
+            })),
+            keys: vec![],
+            aggregations: vec![AliasedAggregation {
+                alias: "c".to_string(),
+                agg_expr: AggregationExpr::CountStar(false),
+            }],
+            cache: SchemaCache::new(),
+            scope: 0,
+        })
+    );
+    test_constant_fold_no_op!(
+        count_star_with_group_keys_is_not_folded,
+        Stage::Group(Group {
+            source: Box::new(Stage::Array(ArraySource {
+                array: three_element_array(),
+                alias: "arr".to_string(),
+                cache: SchemaCache::new(),
+            })),
+            keys: vec![OptionallyAliasedExpr::Unaliased(Expression::FieldAccess(
+                FieldAccess::new(
+                    Expression::Reference(("arr", 0u16).into()).into(),
+                    "a".into()
+                )
+            ))],
+            aggregations: vec![AliasedAggregation {
+                alias: "c".to_string(),
+                agg_expr: AggregationExpr::CountStar(false),
+            }],
+            cache: SchemaCache::new(),
+            scope: 0,
+        })
+    );
 }
@@ -101,6 +101,7 @@ test_dead_code_elimination!(
                             Box::new(Expression::Reference(("bar", 0u16).into())),
                             "c".to_string(),
                         ))),
+                        separator: None,
                     }),
                 }],
                 scope: 0u16,
@@ -151,6 +152,7 @@ test_dead_code_elimination!(
                         Box::new(Expression::Reference(("foo", 0u16).into())),
                         "c".to_string(),
                     ))),
+                    separator: None,
                 }),
             }],
             scope: 0u16,
@@ -193,6 +195,7 @@ test_dead_code_elimination!(
                             Box::new(Expression::Reference(("bar", 0u16).into())),
                             "c".to_string(),
                         ))),
+                        separator: None,
                     }),
                 }],
                 scope: 0u16,
@@ -253,6 +256,7 @@ test_dead_code_elimination!(
                         Box::new(Expression::Reference(("foo", 0u16).into())),
                         "c".to_string(),
                     ))),
+                    separator: None,
                 }),
             }],
             scope: 0u16,
@@ -308,6 +312,7 @@ test_dead_code_elimination_no_op!(
                     Box::new(Expression::Reference(("bad", 0u16).into())),
                     "c".to_string(),
                 ))),
+                separator: None,
             }),
         }],
         scope: 0u16,
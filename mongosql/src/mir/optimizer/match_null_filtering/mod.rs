@@ -272,9 +272,14 @@ impl ScalarFunction {
             | ScalarFunction::Gt
             | ScalarFunction::Gte
             | ScalarFunction::Between
+            | ScalarFunction::Greatest
+            | ScalarFunction::Least
             | ScalarFunction::Not
             | ScalarFunction::And
-            | ScalarFunction::Or => true,
+            | ScalarFunction::Or
+            | ScalarFunction::IsTrue
+            | ScalarFunction::IsFalse
+            | ScalarFunction::IsUnknown => true,
 
             // These functions all correctly return NULL in MQL, if there is a NULL argument.
             // NullIf is weird in that it can also return NULL if none of the arguments are NULL,
@@ -289,11 +294,18 @@ impl ScalarFunction {
             | ScalarFunction::Sub
             | ScalarFunction::Mul
             | ScalarFunction::Div
+            | ScalarFunction::IntDiv
+            | ScalarFunction::BitAnd
+            | ScalarFunction::BitOr
+            | ScalarFunction::BitXor
             | ScalarFunction::ComputedFieldAccess
             | ScalarFunction::NullIf
             | ScalarFunction::Coalesce
             | ScalarFunction::Slice
             | ScalarFunction::Size
+            | ScalarFunction::ArrayLength
+            | ScalarFunction::ArrayDistinct
+            | ScalarFunction::SortArray
             | ScalarFunction::Position
             | ScalarFunction::CharLength
             | ScalarFunction::OctetLength
@@ -313,6 +325,10 @@ impl ScalarFunction {
             | ScalarFunction::Tan
             | ScalarFunction::Replace
             | ScalarFunction::Substring
+            | ScalarFunction::Left
+            | ScalarFunction::Right
+            | ScalarFunction::LPad
+            | ScalarFunction::RPad
             | ScalarFunction::Upper
             | ScalarFunction::Lower
             | ScalarFunction::BTrim
@@ -332,6 +348,12 @@ impl ScalarFunction {
             | ScalarFunction::DayOfYear
             | ScalarFunction::IsoWeek
             | ScalarFunction::IsoWeekday
+            | ScalarFunction::MonthName
+            | ScalarFunction::DayName
+            | ScalarFunction::UnixTimestamp
+            | ScalarFunction::FromUnixTimestamp
+            | ScalarFunction::DateFormat
+            | ScalarFunction::ParseDate
             | ScalarFunction::MergeObjects => false,
         }
     }
@@ -617,6 +617,7 @@ mod datasource_uses {
                     function: AggregationFunction::Avg,
                     distinct: false,
                     arg: mir_reference("z").into(),
+                    separator: None,
                 }),
             }],
             scope: 0u16,
@@ -746,6 +747,7 @@ mod substitute {
                     function: AggregationFunction::Avg,
                     distinct: false,
                     arg: mir_int_expr(0).into(),
+                    separator: None,
                 }),
             }],
             scope: 0,
@@ -766,6 +768,7 @@ mod substitute {
                     function: AggregationFunction::Avg,
                     distinct: false,
                     arg: mir_reference("z").into(),
+                    separator: None,
                 }),
             }],
             scope: 0,
@@ -1,6 +1,6 @@
 use crate::{
-    mir::binding_tuple,
-    schema::{Satisfaction, Schema},
+    mir::{binding_tuple, DATE_NAME_LOCALES},
+    schema::{Satisfaction, Schema, MAX_DEPTH},
     usererror::{
         util::{generate_suggestion, unsat_check},
         UserError, UserErrorDisplay,
@@ -28,6 +28,7 @@ pub enum Error {
     InvalidBinaryDataType,
     AggregationArgumentMustBeSelfComparable(String, Schema),
     InvalidComparison(&'static str, Schema, Schema),
+    IncompatibleCaseBranchTypes(&'static str, Schema, Schema),
     CannotMergeObjects(Schema, Schema, Satisfaction),
     AccessMissingField(String, Option<Vec<String>>),
     InvalidSubqueryCardinality,
@@ -35,6 +36,13 @@ pub enum Error {
     GroupKeyNotSelfComparable(usize, Schema),
     UnwindIndexNameConflict(String),
     CollectionNotFound(String, String),
+    MissingOutputColumn(String),
+    // Note: The errors.md file does not mention this error because SortArray has no SQL syntax.
+    InvalidSortArrayDirection,
+    MaxSchemaDepthExceeded,
+    // Note: The errors.md file does not mention this error because MonthName and DayName have
+    // no SQL syntax.
+    InvalidDateNameLocale,
 }
 
 impl UserError for Error {
@@ -53,6 +61,11 @@ impl UserError for Error {
             Error::UnwindIndexNameConflict(_) => 1014,
             Error::CollectionNotFound(_, _) => 1016,
             Error::InvalidBinaryDataType => 1019,
+            Error::IncompatibleCaseBranchTypes(_, _, _) => 1020,
+            Error::MissingOutputColumn(_) => 1021,
+            Error::InvalidSortArrayDirection => 1022,
+            Error::MaxSchemaDepthExceeded => 1023,
+            Error::InvalidDateNameLocale => 1024,
         }
     }
 
@@ -112,6 +125,23 @@ impl UserError for Error {
                     Some(error_msg)
                 }
             }
+            Error::IncompatibleCaseBranchTypes(case, s1, s2) => {
+                let simplified_s1 = Schema::simplify(s1);
+                let simplified_s2 = Schema::simplify(s2);
+
+                if let Some(message) =
+                    unsat_check(vec![simplified_s1.clone(), simplified_s2.clone()])
+                {
+                    Some(message)
+                } else {
+                    let error_msg = format!("Invalid use of `{case}` due to incompatible result types: `{simplified_s1}` cannot be unified with `{simplified_s2}`.");
+                    let error_msg = Self::error_message_with_any_schema_addendum(
+                        error_msg,
+                        vec![simplified_s1, simplified_s2],
+                    );
+                    Some(error_msg)
+                }
+            }
             Error::AccessMissingField(field, found_fields) => {
                 if let Some(possible_fields) = found_fields {
                     let suggestions = generate_suggestion(field, possible_fields);
@@ -186,6 +216,20 @@ impl UserError for Error {
             Error::UnwindIndexNameConflict(_) => None,
             Error::CollectionNotFound(_, _) => None,
             Error::InvalidBinaryDataType => None,
+            Error::MissingOutputColumn(column) => Some(format!(
+                "The column `{column}` will never exist because its value is always missing."
+            )),
+            Error::InvalidSortArrayDirection => Some(
+                "The sort direction of `SortArray` must be the integer literal 1 or -1."
+                    .to_string(),
+            ),
+            Error::MaxSchemaDepthExceeded => Some(format!(
+                "The schema of a collection or expression is nested more than {MAX_DEPTH} levels deep."
+            )),
+            Error::InvalidDateNameLocale => Some(format!(
+                "The locale of `MonthName` and `DayName` must be one of the string literals {}.",
+                DATE_NAME_LOCALES.join(", ")
+            )),
         }
     }
 
@@ -196,6 +240,7 @@ impl UserError for Error {
             Error::SchemaChecking {name, required, found } => format!("schema checking failed for {name}: required {required:?}, found {found:?}"),
             Error::AggregationArgumentMustBeSelfComparable(aggs, schema) => format!("cannot have {0:?} aggregations over the schema: {1:?} as it is not comparable to itself", aggs, schema),
             Error::InvalidComparison(func, s1, s2) => format!("invalid comparison for {0}: {1:?} cannot be compared to {2:?}", func, s1, s2),
+            Error::IncompatibleCaseBranchTypes(case, s1, s2) => format!("incompatible result types for {0}: {1:?} cannot be unified with {2:?}", case, s1, s2),
             Error::CannotMergeObjects(s1, s2, sat) => format!("cannot merge objects {0:?} and {1:?} as they {2:?} have overlapping keys", s1, s2, sat),
             Error::AccessMissingField(field, _) => format!("cannot access field {0} because it does not exist", field),
             Error::InvalidSubqueryCardinality => "cardinality of the subquery's result set may be greater than 1".to_string(),
@@ -204,6 +249,10 @@ impl UserError for Error {
             Error::UnwindIndexNameConflict(name) => format!("UNWIND INDEX name '{0}' conflicts with existing field name", name),
             Error::CollectionNotFound(database, coll) => format!("unknown collection '{1}' in database '{0}'", database, coll),
             Error::InvalidBinaryDataType => "Binary data with subtype 3 found in schema".to_string(),
+            Error::MissingOutputColumn(column) => format!("output column '{0}' is always missing", column),
+            Error::InvalidSortArrayDirection => "sort direction for SortArray must be the integer literal 1 or -1".to_string(),
+            Error::MaxSchemaDepthExceeded => format!("schema exceeds the maximum nesting depth of {MAX_DEPTH}"),
+            Error::InvalidDateNameLocale => format!("locale for MonthName and DayName must be one of the string literals {}", DATE_NAME_LOCALES.join(", ")),
         }
     }
 }
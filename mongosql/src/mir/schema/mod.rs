@@ -3,19 +3,23 @@ use crate::{
     map,
     mir::{
         binding_tuple,
-        schema::util::{lift_array_schemas, merge_bot_any_of_document_schemas, set_field_schema},
+        schema::util::{
+            lift_array_schemas, merge_bot_any_of_document_schemas, narrow_schema_env_for_condition,
+            set_field_schema,
+        },
         *,
     },
     schema::{
         Atomic, Document, ResultSet, Satisfaction, Schema, SchemaEnvironment, ANY_ARRAY,
         ANY_ARRAY_OR_NULLISH, ANY_DOCUMENT, BOOLEAN_OR_NULLISH, DATE_OR_NULLISH, EMPTY_DOCUMENT,
-        INTEGER_LONG_OR_NULLISH, INTEGER_OR_NULLISH, NULLISH, NUMERIC, NUMERIC_OR_NULLISH,
-        STRING_OR_NULLISH,
+        INTEGER_LONG_OR_NULLISH, INTEGER_OR_NULLISH, MAX_DEPTH, NULLISH, NUMERIC,
+        NUMERIC_OR_NULLISH, STRING_OR_NULLISH,
     },
     set,
     util::unique_linked_hash_map::UniqueLinkedHashMap,
 };
 use bson::spec::BinarySubtype;
+use itertools::Itertools;
 use std::{
     cell::RefCell,
     cmp::min,
@@ -210,7 +214,12 @@ impl CachedSchema for Stage {
         match &*cache {
             Some(contents) => contents.result.clone(),
             _ => {
-                let schema_result = self.check_schema(state);
+                let schema_result = match self.check_schema(state) {
+                    Ok(result_set) if exceeds_max_depth(&result_set) => {
+                        Err(Error::MaxSchemaDepthExceeded)
+                    }
+                    schema_result => schema_result,
+                };
                 cache.replace(SchemaCacheContents::new(schema_result.clone()));
                 schema_result
             }
@@ -259,8 +268,13 @@ impl CachedSchema for Stage {
                     });
                 }
 
+                // Rows that pass the filter satisfy its condition, so any IS NOT NULL or
+                // IS <type> predicates can narrow the schemas of the fields they test.
                 Ok(ResultSet {
-                    schema_env: source_result_set.schema_env,
+                    schema_env: narrow_schema_env_for_condition(
+                        &f.condition,
+                        source_result_set.schema_env,
+                    ),
                     min_size: 0,
                     max_size: source_result_set.max_size,
                 })
@@ -281,6 +295,7 @@ impl CachedSchema for Stage {
                                     found: s,
                                 })
                             } else {
+                                check_output_columns(&state, e)?;
                                 Ok((k.clone(), s))
                             }
                         }
@@ -412,9 +427,11 @@ impl CachedSchema for Stage {
                 // document schema.
                 let schema_env = merge_bot_any_of_document_schemas(state.scope_level, schema_env);
 
+                // Any number of source rows may share a group, so a non-empty source
+                // only guarantees a single output row.
                 Ok(ResultSet {
                     schema_env: schema_env.simplify(),
-                    min_size: source_result_set.min_size,
+                    min_size: min(source_result_set.min_size, 1),
                     max_size,
                 })
             }
@@ -566,6 +583,9 @@ impl CachedSchema for Stage {
                 let max_size = left_result_set
                     .max_size
                     .and_then(|l| right_result_set.max_size.map(|r| l + r));
+                // Datasources with the same key on both sides are unioned into an AnyOf. Keys
+                // that appear on only one side are kept as is, since their rows remain
+                // distinguished by datasource.
                 match s.operation {
                     SetOperation::Union => Ok(ResultSet {
                         schema_env: left_result_set
@@ -891,15 +911,15 @@ impl AggregationFunction {
         use Satisfaction::*;
         Ok(match self {
             AddToArray => Schema::Array(Box::new(arg_schema)),
-            Avg | StddevPop | StddevSamp => {
+            Avg | StddevPop | StddevSamp | VarPop | VarSamp => {
                 self.schema_check_fixed_args(
                     state,
                     &[arg_schema.clone()],
                     &[NUMERIC_OR_NULLISH.clone()],
                 )?;
-                // we cannot use get_arithmetic_schema for Avg, StddevPop, StddevSamp
-                // because they never return Long or Integer results, even for Long
-                // or Integer inputs.
+                // we cannot use get_arithmetic_schema for Avg, StddevPop, StddevSamp,
+                // VarPop, or VarSamp because they never return Long or Integer results,
+                // even for Long or Integer inputs.
                 let get_numeric_schema = || match (
                     arg_schema.satisfies(&Schema::Atomic(Atomic::Decimal)),
                     arg_schema.satisfies(&Schema::AnyOf(set![
@@ -929,6 +949,21 @@ impl AggregationFunction {
                 Schema::Atomic(Atomic::Long)
             ]),
             First | Last => arg_schema,
+            GroupConcat => {
+                self.schema_check_fixed_args(
+                    state,
+                    std::slice::from_ref(&arg_schema),
+                    std::slice::from_ref(&STRING_OR_NULLISH),
+                )?;
+                match arg_schema.satisfies(&NULLISH) {
+                    Satisfaction::Not => Schema::Atomic(Atomic::String),
+                    Satisfaction::Must => Schema::Atomic(Atomic::Null),
+                    Satisfaction::May => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::String),
+                        Schema::Atomic(Atomic::Null)
+                    ]),
+                }
+            }
             Min | Max => {
                 if !state.check_self_comparable(&arg_schema) {
                     return Err(Error::AggregationArgumentMustBeSelfComparable(
@@ -1099,6 +1134,34 @@ impl Expression {
     }
 }
 
+/// Catalog schemas, and the schemas inferred for array and document
+/// constructors and array aggregations, are the only places a schema can grow
+/// deeper. Every schema that outlives the stage it is inferred in is part of
+/// that stage's ResultSet, so checking each ResultSet once bounds later
+/// traversals without re-walking a nested literal at every level.
+fn exceeds_max_depth(result_set: &ResultSet) -> bool {
+    result_set
+        .schema_env
+        .iter()
+        .any(|(_, schema)| schema.depth() > MAX_DEPTH)
+}
+
+/// In strict mode, a projected document may not have a field whose schema is
+/// exactly Missing: such an output column would never exist, which is almost
+/// always a mistake in the query.
+fn check_output_columns(state: &SchemaInferenceState, expr: &Expression) -> Result<(), Error> {
+    if let (SchemaCheckingMode::Strict, Expression::Document(DocumentExpr { document, .. })) =
+        (state.schema_checking_mode, expr)
+    {
+        for (name, e) in document.iter() {
+            if Schema::simplify(&e.schema(state)?) == Schema::Missing {
+                return Err(Error::MissingOutputColumn(name.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
 impl Expression {
     /// Recursively schema checks this expression, its arguments, and
     /// all contained expressions/stages. If schema checking succeeds,
@@ -1274,7 +1337,31 @@ impl ScalarFunctionApplication {
             .iter()
             .map(|x| x.schema(state))
             .collect::<Result<Vec<_>, _>>()?;
-        self.function.schema(state, &args)
+        let schema = self.function.schema(state, &args)?;
+        // $sortArray only accepts a constant sort specification, so the direction must be
+        // known when the query is translated.
+        if self.function == ScalarFunction::SortArray
+            && !matches!(
+                self.args[1],
+                Expression::Literal(LiteralValue::Integer(1 | -1))
+            )
+        {
+            return Err(Error::InvalidSortArrayDirection);
+        }
+        // The names for a locale are chosen when the query is translated, so the locale of
+        // MonthName and DayName must be a literal.
+        if matches!(
+            self.function,
+            ScalarFunction::MonthName | ScalarFunction::DayName
+        ) {
+            match self.args.get(1) {
+                None => (),
+                Some(Expression::Literal(LiteralValue::String(locale)))
+                    if DATE_NAME_LOCALES.contains(&locale.as_str()) => {}
+                Some(_) => return Err(Error::InvalidDateNameLocale),
+            }
+        }
+        Ok(schema)
     }
 }
 
@@ -1310,16 +1397,17 @@ pub(crate) fn retain(schemas: &BTreeSet<Schema>) -> Result<Schema, Error> {
                 result = max_numeric(&Schema::Atomic(a1), &Schema::Atomic(*a2)).unwrap();
             }
             (Atomic(at), AnyOf(ao)) => {
-                result = retain_aux(&Schema::Atomic(at), ao);
+                result = retain_aux(&Schema::Atomic(at), &flatten_any_of(ao));
             }
             (AnyOf(ao), Atomic(at)) => {
-                result = retain_aux(&Schema::Atomic(*at), &ao);
+                result = retain_aux(&Schema::Atomic(*at), &flatten_any_of(&ao));
             }
             (AnyOf(ao1), AnyOf(ao2)) => {
                 let mut current_result = <BTreeSet<Schema>>::new();
-                for anyof in ao1.iter() {
+                let ao2 = flatten_any_of(ao2);
+                for anyof in flatten_any_of(&ao1) {
                     if let Atomic(at) = anyof {
-                        current_result.insert(retain_aux(&Schema::Atomic(*at), ao2));
+                        current_result.insert(retain_aux(&Schema::Atomic(at), &ao2));
                     }
                 }
                 result = Schema::simplify(&AnyOf(current_result));
@@ -1330,6 +1418,19 @@ pub(crate) fn retain(schemas: &BTreeSet<Schema>) -> Result<Schema, Error> {
     Ok(result)
 }
 
+/// Recursively flattens nested AnyOf members into a single set of
+/// non-AnyOf schemas, so that numeric priority is computed over every
+/// member regardless of how deeply it is nested.
+fn flatten_any_of(anyof: &BTreeSet<Schema>) -> BTreeSet<Schema> {
+    anyof
+        .iter()
+        .flat_map(|s| match s {
+            Schema::AnyOf(nested) => flatten_any_of(nested),
+            _ => set![s.clone()],
+        })
+        .collect()
+}
+
 /// Compares two atomics and returns max numeric type
 pub(crate) fn max_numeric(a1: &Schema, a2: &Schema) -> Result<Schema, Error> {
     use schema::{Atomic::*, Schema::*};
@@ -1342,6 +1443,33 @@ pub(crate) fn max_numeric(a1: &Schema, a2: &Schema) -> Result<Schema, Error> {
     })
 }
 
+/// Returns the widest numeric type that any of the provided schemas may have, or None when
+/// some schema may be non-numeric or when every schema shares a single numeric type. GREATEST
+/// and LEAST convert their result to this type, so that they widen mixed numeric arguments
+/// the same way arithmetic does.
+pub(crate) fn widened_numeric_schema(arg_schemas: &[Schema]) -> Option<Schema> {
+    if !arg_schemas
+        .iter()
+        .all(|s| s.satisfies(&NUMERIC_OR_NULLISH) == Satisfaction::Must)
+    {
+        return None;
+    }
+    let numeric_schemas = arg_schemas
+        .iter()
+        .flat_map(|s| match Schema::simplify(s) {
+            Schema::AnyOf(ao) => flatten_any_of(&ao),
+            s => set![s],
+        })
+        .filter(|s| matches!(s, Schema::Atomic(a) if a.is_numeric()))
+        .collect::<BTreeSet<_>>();
+    if numeric_schemas.len() < 2 {
+        return None;
+    }
+    numeric_schemas
+        .into_iter()
+        .reduce(|s1, s2| max_numeric(&s1, &s2).unwrap())
+}
+
 trait SQLFunction {
     /// Returns the schema for an arithmetic function, maximizing the Numeric type
     /// based on the total order: Integer < Long < Double < Decimal.
@@ -1365,7 +1493,8 @@ trait SQLFunction {
                 .filter(|s| !matches!(s, Missing) && !matches!(s, Atomic(Null)))
                 .map(|s| match s {
                     AnyOf(ao) => AnyOf(
-                        ao.into_iter()
+                        flatten_any_of(&ao)
+                            .into_iter()
                             .filter(|sch| sch.satisfies(&NUMERIC.clone()) == Satisfaction::Must)
                             .collect::<BTreeSet<_>>(),
                     ),
@@ -1552,7 +1681,7 @@ impl DateFunction {
     ) -> Result<Schema, Error> {
         use DateFunction::*;
         match self {
-            Add => {
+            Add | Sub => {
                 self.ensure_arg_count(arg_schemas.len(), 2)?;
                 self.propagate_fixed_null_arguments(
                     state,
@@ -1614,6 +1743,35 @@ impl ScalarFunction {
                 self.ensure_arg_count(arg_schemas.len(), 2)?;
                 self.get_arithmetic_schema(state, arg_schemas)
             }
+            // Integer division only accepts whole numbers, and a divisor of 0 results in NULL.
+            IntDiv => match self.schema_check_fixed_args(
+                state,
+                arg_schemas,
+                &[
+                    INTEGER_LONG_OR_NULLISH.clone(),
+                    INTEGER_LONG_OR_NULLISH.clone(),
+                ],
+            )? {
+                Satisfaction::Must => Ok(Schema::Atomic(Atomic::Null)),
+                Satisfaction::May | Satisfaction::Not => {
+                    Ok(Schema::simplify(&Schema::AnyOf(set![
+                        self.get_arithmetic_schema(state, arg_schemas)?,
+                        Schema::Atomic(Atomic::Null),
+                    ])))
+                }
+            },
+            // Bitwise operators only accept whole numbers and widen to the larger integral type.
+            BitAnd | BitOr | BitXor => {
+                self.schema_check_fixed_args(
+                    state,
+                    arg_schemas,
+                    &[
+                        INTEGER_LONG_OR_NULLISH.clone(),
+                        INTEGER_LONG_OR_NULLISH.clone(),
+                    ],
+                )?;
+                self.get_arithmetic_schema(state, arg_schemas)
+            }
 
             Round => {
                 self.ensure_arg_count(arg_schemas.len(), 2)?;
@@ -1634,7 +1792,7 @@ impl ScalarFunction {
                 }
             }
 
-            Cos | Degrees | Radians | Sin | Sqrt | Tan => {
+            Cos | Degrees | Radians | Sin | Tan => {
                 self.ensure_arg_count(arg_schemas.len(), 1)?;
                 self.get_arithmetic_schema(
                     state,
@@ -1643,7 +1801,27 @@ impl ScalarFunction {
                 )
             }
 
-            Log | Mod | Pow => {
+            // SQRT always returns a Double, even for Decimal arguments.
+            Sqrt => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                std::slice::from_ref(&NUMERIC_OR_NULLISH),
+                Schema::Atomic(Atomic::Double),
+            ),
+
+            // LOG(x) is the natural logarithm of x, and LOG(x, base) takes an explicit base.
+            Log => {
+                if arg_schemas.len() != 1 {
+                    self.ensure_arg_count(arg_schemas.len(), 2)?;
+                }
+                self.get_arithmetic_schema(
+                    state,
+                    // default the schema to Double in case the actual arg schema is INT or LONG
+                    &[arg_schemas, &[Schema::Atomic(Atomic::Double)][..]].concat(),
+                )
+            }
+
+            Mod | Pow => {
                 self.ensure_arg_count(arg_schemas.len(), 2)?;
                 self.get_arithmetic_schema(
                     state,
@@ -1675,6 +1853,7 @@ impl ScalarFunction {
                     )?,
                 ]))
             }
+            Greatest | Least => self.get_greatest_least_schema(state, arg_schemas),
             // Boolean operators.
             Not => self.propagate_fixed_null_arguments(
                 state,
@@ -1688,6 +1867,15 @@ impl ScalarFunction {
                 BOOLEAN_OR_NULLISH.clone(),
                 Schema::Atomic(Atomic::Boolean),
             ),
+            // Truth value tests treat NULL and MISSING as UNKNOWN, so they never return NULL.
+            IsTrue | IsFalse | IsUnknown => {
+                self.schema_check_fixed_args(
+                    state,
+                    arg_schemas,
+                    std::slice::from_ref(&BOOLEAN_OR_NULLISH),
+                )?;
+                Ok(Schema::Atomic(Atomic::Boolean))
+            }
             // Computed Field Access operator when the field is not known until runtime.
             ComputedFieldAccess => {
                 self.schema_check_fixed_args(
@@ -1714,13 +1902,37 @@ impl ScalarFunction {
                 &[ANY_ARRAY_OR_NULLISH.clone()],
                 Schema::Atomic(Atomic::Integer),
             ),
-            // Numeric value scalar functions.
-            Position => self.propagate_fixed_null_arguments(
+            // Unlike Size, ArrayLength accepts any argument and evaluates to NULL when the
+            // argument is not an array.
+            ArrayLength => {
+                self.ensure_arg_count(arg_schemas.len(), 1)?;
+                Ok(match arg_schemas[0].satisfies(&ANY_ARRAY) {
+                    Satisfaction::Must => Schema::Atomic(Atomic::Integer),
+                    Satisfaction::May => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Integer),
+                        Schema::Atomic(Atomic::Null),
+                    ]),
+                    Satisfaction::Not => Schema::Atomic(Atomic::Null),
+                })
+            }
+            // Removing duplicates and sorting both preserve the element type, so the result has
+            // the same array schema as the first argument.
+            ArrayDistinct => self.propagate_array_argument_schema(
                 state,
                 arg_schemas,
-                &[STRING_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()],
-                Schema::Atomic(Atomic::Integer),
+                std::slice::from_ref(&ANY_ARRAY_OR_NULLISH),
+            ),
+            // The sort direction must be a literal 1 or -1, so it is never nullish.
+            SortArray => self.propagate_array_argument_schema(
+                state,
+                arg_schemas,
+                &[
+                    ANY_ARRAY_OR_NULLISH.clone(),
+                    Schema::Atomic(Atomic::Integer),
+                ],
             ),
+            // Numeric value scalar functions.
+            Position => self.get_position_schema(state, arg_schemas),
             CharLength | OctetLength | BitLength => self.propagate_fixed_null_arguments(
                 state,
                 arg_schemas,
@@ -1734,6 +1946,37 @@ impl ScalarFunction {
                 &[DATE_OR_NULLISH.clone()],
                 Schema::Atomic(Atomic::Integer),
             ),
+            MonthName | DayName => {
+                if arg_schemas.len() != 1 {
+                    self.ensure_arg_count(arg_schemas.len(), 2)?;
+                }
+                self.propagate_fixed_null_arguments(
+                    state,
+                    arg_schemas,
+                    &[DATE_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()][..arg_schemas.len()],
+                    Schema::Atomic(Atomic::String),
+                )
+            }
+            UnixTimestamp => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                std::slice::from_ref(&DATE_OR_NULLISH),
+                Schema::Atomic(Atomic::Long),
+            ),
+            // FromUnixTimestamp takes milliseconds since the epoch.
+            FromUnixTimestamp => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                std::slice::from_ref(&INTEGER_LONG_OR_NULLISH),
+                Schema::Atomic(Atomic::Date),
+            ),
+            DateFormat => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                &[DATE_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()],
+                Schema::Atomic(Atomic::String),
+            ),
+            ParseDate => self.get_parse_date_schema(state, arg_schemas),
             // String value scalar functions.
             Replace => self.propagate_fixed_null_arguments(
                 state,
@@ -1746,6 +1989,29 @@ impl ScalarFunction {
                 Schema::Atomic(Atomic::String),
             ),
             Substring => self.get_substring_schema(state, arg_schemas),
+            Left | Right => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                &[STRING_OR_NULLISH.clone(), INTEGER_OR_NULLISH.clone()],
+                Schema::Atomic(Atomic::String),
+            ),
+            LPad | RPad => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                &[
+                    STRING_OR_NULLISH.clone(),
+                    INTEGER_OR_NULLISH.clone(),
+                    STRING_OR_NULLISH.clone(),
+                ],
+                Schema::Atomic(Atomic::String),
+            ),
+            // The two-argument form of SPLIT returns every token rather than a single one.
+            Split if arg_schemas.len() == 2 => self.propagate_fixed_null_arguments(
+                state,
+                arg_schemas,
+                &[STRING_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()],
+                Schema::Array(Box::new(Schema::Atomic(Atomic::String))),
+            ),
             Split => self.propagate_fixed_null_arguments(
                 state,
                 arg_schemas,
@@ -1858,6 +2124,72 @@ impl ScalarFunction {
         ))
     }
 
+    /// Returns the schema for the `POSITION()` function. The optional third argument is the
+    /// code point index at which to start the search.
+    fn get_position_schema(
+        &self,
+        state: &SchemaInferenceState,
+        arg_schemas: &[Schema],
+    ) -> Result<Schema, Error> {
+        let required_schemas = if arg_schemas.len() == 3 {
+            vec![
+                STRING_OR_NULLISH.clone(),
+                STRING_OR_NULLISH.clone(),
+                INTEGER_OR_NULLISH.clone(),
+            ]
+        } else {
+            vec![STRING_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()]
+        };
+        Ok(self.propagate_null_arguments_helper(
+            self.schema_check_fixed_args(state, arg_schemas, &required_schemas)?,
+            Schema::Atomic(Atomic::Integer),
+        ))
+    }
+
+    /// Returns the schema for ParseDate, which takes a string and a format, optionally followed
+    /// by on_null and on_error arguments that default to NULL. Null or missing strings evaluate
+    /// to on_null, null or missing formats evaluate to NULL, and strings that cannot be parsed
+    /// with the format evaluate to on_error.
+    fn get_parse_date_schema(
+        &self,
+        state: &SchemaInferenceState,
+        arg_schemas: &[Schema],
+    ) -> Result<Schema, Error> {
+        let (on_null, on_error) = match arg_schemas {
+            [_, _, on_null, on_error] => (on_null.clone(), on_error.clone()),
+            _ => (Schema::Atomic(Atomic::Null), Schema::Atomic(Atomic::Null)),
+        };
+        let required_schemas = if arg_schemas.len() == 4 {
+            vec![
+                STRING_OR_NULLISH.clone(),
+                STRING_OR_NULLISH.clone(),
+                Schema::Any,
+                Schema::Any,
+            ]
+        } else {
+            vec![STRING_OR_NULLISH.clone(), STRING_OR_NULLISH.clone()]
+        };
+        self.schema_check_fixed_args(state, arg_schemas, &required_schemas)?;
+        let string_sat = arg_schemas[0].satisfies(&NULLISH);
+        let format_sat = arg_schemas[1].satisfies(&NULLISH);
+        if string_sat == Satisfaction::Must {
+            return Ok(on_null);
+        }
+        let mut schemas: BTreeSet<Schema> = match format_sat {
+            Satisfaction::Must => set![Schema::Atomic(Atomic::Null)],
+            Satisfaction::May => set![
+                Schema::Atomic(Atomic::Date),
+                Schema::Atomic(Atomic::Null),
+                on_error
+            ],
+            Satisfaction::Not => set![Schema::Atomic(Atomic::Date), on_error],
+        };
+        if string_sat == Satisfaction::May {
+            schemas.insert(on_null);
+        }
+        Ok(Schema::simplify(&Schema::AnyOf(schemas)))
+    }
+
     /// Returns the schema for the `COALESCE()` function, or an error if no arguments are provided.
     /// If there is a certainly non-nullish argument, then the result schema will be the set of
     /// all non-nullish schema possibilities for the arguments up to and including the first
@@ -1887,6 +2219,62 @@ impl ScalarFunction {
         Ok(schema)
     }
 
+    /// Returns the schema for the greatest and least functions, which is any of the argument
+    /// schemas or null. Every pair of arguments must be comparable.
+    fn get_greatest_least_schema(
+        &self,
+        state: &SchemaInferenceState,
+        arg_schemas: &[Schema],
+    ) -> Result<Schema, Error> {
+        if arg_schemas.is_empty() {
+            return Err(Error::IncorrectArgumentCount {
+                name: self.as_str(),
+                required: 1,
+                found: 0,
+            });
+        }
+        for (i, s1) in arg_schemas.iter().enumerate() {
+            for s2 in arg_schemas.iter().skip(i + 1) {
+                if !state.check_comparable_with(s1, s2) {
+                    return Err(Error::InvalidComparison(
+                        self.as_str(),
+                        s1.clone(),
+                        s2.clone(),
+                    ));
+                }
+            }
+        }
+        // mixed numeric arguments are widened to the highest-priority numeric type among
+        // them, as in arithmetic
+        let result_schemas = match widened_numeric_schema(arg_schemas) {
+            Some(widened) => vec![widened],
+            None => arg_schemas.to_vec(),
+        };
+        let schema = Schema::AnyOf(
+            result_schemas
+                .into_iter()
+                .chain(std::iter::once(Schema::Atomic(Atomic::Null)))
+                .collect(),
+        );
+        Ok(Schema::simplify(&schema.upconvert_missing_to_null()))
+    }
+
+    /// Schema checks a function whose result has the same array schema as its first argument,
+    /// such as ArrayDistinct. `NULL` arguments propagate as in
+    /// [`propagate_fixed_null_arguments`](Self::propagate_fixed_null_arguments).
+    fn propagate_array_argument_schema(
+        &self,
+        state: &SchemaInferenceState,
+        arg_schemas: &[Schema],
+        required_schemas: &[Schema],
+    ) -> Result<Schema, Error> {
+        let sat = self.schema_check_fixed_args(state, arg_schemas, required_schemas)?;
+        Ok(self.propagate_null_arguments_helper(
+            sat,
+            Schema::simplify(&arg_schemas[0].clone().subtract_nullish()),
+        ))
+    }
+
     /// Returns the array and/or null schema for the slice function.
     ///
     /// The error checks include special handling for an optional third argument.
@@ -1943,11 +2331,24 @@ impl CastExpr {
             return Ok(on_null_schema);
         }
 
-        // If the original expression is being cast to its own type, return that type.
-        if state.check_satisfies(&expr_schema, &type_schema) {
+        // If the original expression must already be of the type being cast to, the cast
+        // cannot fail, so return that type. This does not depend on the schema checking
+        // mode, since a value that only may be of the type can still hit the on_error case.
+        if expr_schema.satisfies(&type_schema) == Satisfaction::Must {
             return Ok(type_schema);
         }
 
+        // If the original expression is either of the type being cast to or null or missing,
+        // the cast still cannot fail, but it may evaluate to `on_null`.
+        if expr_schema.satisfies(&Schema::AnyOf(set![
+            type_schema.clone(),
+            Schema::Atomic(Atomic::Null),
+            Schema::Missing,
+        ])) == Satisfaction::Must
+        {
+            return Ok(Schema::AnyOf(set![type_schema, on_null_schema]));
+        }
+
         Ok(Schema::AnyOf(set![
             type_schema,
             on_null_schema,
@@ -1962,6 +2363,7 @@ impl CastExpr {
 trait SchemaCheckCaseExpr {
     fn schema(&self, state: &SchemaInferenceState) -> Result<Schema, Error>;
     fn schema_aux(
+        name: &'static str,
         state: &SchemaInferenceState,
         when_branches: &[WhenBranch],
         else_branch: &Expression,
@@ -1981,6 +2383,22 @@ trait SchemaCheckCaseExpr {
         // The resulting schema for a case expression is AnyOf the THEN results
         // from each when_branch, along with the ELSE branch result.
         schemas.insert(else_branch.schema(state)?);
+
+        // In strict mode, the results must be unifiable: no two of them may be
+        // incomparable (e.g. a string and a document). Relaxed mode permits the AnyOf.
+        if let SchemaCheckingMode::Strict = state.schema_checking_mode {
+            if let Some((s1, s2)) = schemas
+                .iter()
+                .tuple_combinations()
+                .find(|(s1, s2)| s1.is_comparable_with(s2) == Satisfaction::Not)
+            {
+                return Err(Error::IncompatibleCaseBranchTypes(
+                    name,
+                    s1.clone(),
+                    s2.clone(),
+                ));
+            }
+        }
         Ok(Schema::AnyOf(schemas))
     }
 }
@@ -2000,6 +2418,7 @@ impl SchemaCheckCaseExpr for SearchedCaseExpr {
         };
 
         Self::schema_aux(
+            "SearchedCase",
             state,
             &self.when_branch,
             &self.else_branch,
@@ -2024,6 +2443,7 @@ impl SchemaCheckCaseExpr for SimpleCaseExpr {
         };
 
         Self::schema_aux(
+            "SimpleCase",
             state,
             &self.when_branch,
             &self.else_branch,
@@ -1,7 +1,7 @@
 use crate::{
     map,
     mir::{schema::Error as mir_error, *},
-    schema::{Atomic, Document, Schema, ANY_DOCUMENT, NUMERIC_OR_NULLISH},
+    schema::{Atomic, Document, Schema, ANY_DOCUMENT, NUMERIC_OR_NULLISH, STRING_OR_NULLISH},
     set, test_schema,
 };
 use std::sync::LazyLock;
@@ -26,6 +26,7 @@ mod add_to_array {
             function: AggregationFunction::AddToArray,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -43,6 +44,7 @@ mod add_to_array {
             function: AggregationFunction::AddToArray,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -69,6 +71,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -83,6 +86,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -100,6 +104,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -117,6 +122,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Decimal),
@@ -134,6 +140,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Long),
@@ -154,6 +161,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Decimal),
@@ -169,6 +177,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -182,6 +191,7 @@ mod avg {
             function: AggregationFunction::Avg,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Decimal),
@@ -204,6 +214,7 @@ mod count {
             function: AggregationFunction::Count,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -217,6 +228,21 @@ mod count {
         input = AggregationExpr::CountStar(false),
     );
 
+    test_schema!(
+        count_distinct_expr_is_int,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Long)
+        ])),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::Count,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: true,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Integer)},
+    );
+
     test_schema!(
         count_expr_is_int,
         expected = Ok(Schema::AnyOf(set![
@@ -227,6 +253,7 @@ mod count {
             function: AggregationFunction::Count,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -249,6 +276,7 @@ mod first {
             function: AggregationFunction::First,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -263,6 +291,7 @@ mod first {
             function: AggregationFunction::First,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -271,6 +300,62 @@ mod first {
     );
 }
 
+mod group_concat {
+    use super::*;
+
+    fn group_concat(distinct: bool) -> AggregationExpr {
+        AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::GroupConcat,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct,
+            separator: Some(";".into()),
+        })
+    }
+
+    test_schema!(
+        group_concat_of_string_is_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = group_concat(false),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        group_concat_of_nullable_string_is_string_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = group_concat(true),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null),
+        ])},
+    );
+
+    test_schema!(
+        arg_to_group_concat_must_be_string,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "GroupConcat",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = group_concat(false),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Integer)},
+    );
+
+    test_schema!(
+        distinct_group_concat_args_must_be_comparable,
+        expected_error_code = 1003,
+        expected = Err(mir_error::AggregationArgumentMustBeSelfComparable(
+            "GroupConcat DISTINCT".into(),
+            NON_SELF_COMPARABLE_SCHEMA.clone()
+        )),
+        input = group_concat(true),
+        schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
+    );
+}
+
 mod last {
     use super::*;
 
@@ -285,6 +370,7 @@ mod last {
             function: AggregationFunction::Last,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -299,6 +385,7 @@ mod last {
             function: AggregationFunction::Last,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -321,6 +408,7 @@ mod max {
             function: AggregationFunction::Max,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -336,6 +424,7 @@ mod max {
             function: AggregationFunction::Max,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -350,6 +439,7 @@ mod max {
             function: AggregationFunction::Max,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -376,6 +466,7 @@ mod merge_documents {
             function: AggregationFunction::MergeDocuments,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -395,6 +486,7 @@ mod merge_documents {
             function: AggregationFunction::MergeDocuments,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::Document(Document {
         keys: map!{"foo".into() => Schema::Atomic(Atomic::Integer)},
@@ -419,6 +511,7 @@ mod min {
             function: AggregationFunction::Min,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -434,6 +527,7 @@ mod min {
             function: AggregationFunction::Min,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -448,6 +542,7 @@ mod min {
             function: AggregationFunction::Min,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -470,6 +565,7 @@ mod stddev_pop {
             function: AggregationFunction::StddevPop,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -489,6 +585,7 @@ mod stddev_pop {
             function: AggregationFunction::StddevPop,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -503,6 +600,7 @@ mod stddev_pop {
             function: AggregationFunction::StddevPop,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -520,6 +618,7 @@ mod stddev_pop {
             function: AggregationFunction::StddevPop,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -534,6 +633,7 @@ mod stddev_pop {
             function: AggregationFunction::StddevPop,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Decimal),
@@ -555,6 +655,7 @@ mod stddev_samp {
             function: AggregationFunction::StddevSamp,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -574,6 +675,7 @@ mod stddev_samp {
             function: AggregationFunction::StddevSamp,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -588,6 +690,7 @@ mod stddev_samp {
             function: AggregationFunction::StddevSamp,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -605,6 +708,7 @@ mod stddev_samp {
             function: AggregationFunction::StddevSamp,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -619,6 +723,7 @@ mod stddev_samp {
             function: AggregationFunction::StddevSamp,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Decimal),
@@ -640,6 +745,7 @@ mod sum {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
     );
@@ -659,6 +765,7 @@ mod sum {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -676,6 +783,7 @@ mod sum {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -693,6 +801,7 @@ mod sum {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -710,6 +819,158 @@ mod sum {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("bar", 0u16).into())),
             distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Decimal),
+        ])},
+    );
+}
+
+mod var_pop {
+    use super::*;
+
+    test_schema!(
+        distinct_varpop_args_must_be_comparable,
+        expected_error_code = 1003,
+        expected = Err(mir_error::AggregationArgumentMustBeSelfComparable(
+            "VarPop DISTINCT".into(),
+            NON_SELF_COMPARABLE_SCHEMA.clone()
+        )),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarPop,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: true,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
+    );
+
+    test_schema!(
+        arg_to_var_pop_must_be_numeric,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "VarPop",
+            required: NUMERIC_OR_NULLISH.clone(),
+            found: Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Integer),
+                Schema::Atomic(Atomic::String),
+            ]),
+        }),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarPop,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        ])},
+    );
+
+    test_schema!(
+        var_pop_of_integer_and_long_is_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarPop,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Long),
+        ])},
+    );
+
+    test_schema!(
+        var_pop_of_long_and_null_is_double_and_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarPop,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])},
+    );
+
+    test_schema!(
+        var_pop_of_decimal_is_decimal,
+        expected = Ok(Schema::Atomic(Atomic::Decimal)),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarPop,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Decimal),
+        ])},
+    );
+}
+
+mod var_samp {
+    use super::*;
+
+    test_schema!(
+        distinct_varsamp_args_must_be_comparable,
+        expected_error_code = 1003,
+        expected = Err(mir_error::AggregationArgumentMustBeSelfComparable(
+            "VarSamp DISTINCT".into(),
+            NON_SELF_COMPARABLE_SCHEMA.clone()
+        )),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarSamp,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: true,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => NON_SELF_COMPARABLE_SCHEMA.clone()},
+    );
+
+    test_schema!(
+        arg_to_var_samp_must_be_numeric,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "VarSamp",
+            required: NUMERIC_OR_NULLISH.clone(),
+            found: Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Integer),
+                Schema::Atomic(Atomic::String),
+            ]),
+        }),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarSamp,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        ])},
+    );
+
+    test_schema!(
+        var_samp_of_integer_and_decimal_is_double_and_decimal,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Decimal),
+        ])),
+        input = AggregationExpr::Function(AggregationFunctionApplication {
+            function: AggregationFunction::VarSamp,
+            arg: Box::new(Expression::Reference(("bar", 0u16).into())),
+            distinct: false,
+            separator: None,
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
             Schema::Atomic(Atomic::Integer),
@@ -1,5 +1,11 @@
 use crate::{
-    mir::{schema::Error as mir_error, *},
+    mir::{
+        schema::{
+            test::{test_document_a, TEST_DOCUMENT_SCHEMA_A},
+            Error as mir_error,
+        },
+        *,
+    },
     schema::{Atomic, Schema},
     set, test_schema,
 };
@@ -64,6 +70,43 @@ mod searched {
             is_nullable: false,
         }),
     );
+
+    test_schema!(
+        searched_case_incompatible_branch_types_error_in_strict_mode,
+        expected_error_code = 1020,
+        expected = Err(mir_error::IncompatibleCaseBranchTypes(
+            "SearchedCase",
+            Schema::Atomic(Atomic::String),
+            TEST_DOCUMENT_SCHEMA_A.clone(),
+        )),
+        input = Expression::SearchedCase(SearchedCaseExpr {
+            when_branch: vec![WhenBranch {
+                when: Box::new(Expression::Literal(LiteralValue::Boolean(true))),
+                then: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+                is_nullable: false,
+            }],
+            else_branch: Box::new(test_document_a()),
+            is_nullable: false,
+        }),
+    );
+
+    test_schema!(
+        searched_case_incompatible_branch_types_allowed_in_relaxed_mode,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            TEST_DOCUMENT_SCHEMA_A.clone(),
+        ])),
+        input = Expression::SearchedCase(SearchedCaseExpr {
+            when_branch: vec![WhenBranch {
+                when: Box::new(Expression::Literal(LiteralValue::Boolean(true))),
+                then: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+                is_nullable: false,
+            }],
+            else_branch: Box::new(test_document_a()),
+            is_nullable: false,
+        }),
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
 }
 
 mod simple {
@@ -125,4 +168,43 @@ mod simple {
             is_nullable: false,
         }),
     );
+
+    test_schema!(
+        simple_case_incompatible_branch_types_error_in_strict_mode,
+        expected_error_code = 1020,
+        expected = Err(mir_error::IncompatibleCaseBranchTypes(
+            "SimpleCase",
+            Schema::Atomic(Atomic::String),
+            TEST_DOCUMENT_SCHEMA_A.clone(),
+        )),
+        input = Expression::SimpleCase(SimpleCaseExpr {
+            expr: Box::new(Expression::Literal(LiteralValue::Integer(1))),
+            when_branch: vec![WhenBranch {
+                when: Box::new(Expression::Literal(LiteralValue::Integer(2))),
+                then: Box::new(test_document_a()),
+                is_nullable: false,
+            }],
+            else_branch: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+            is_nullable: false,
+        }),
+    );
+
+    test_schema!(
+        simple_case_incompatible_branch_types_allowed_in_relaxed_mode,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            TEST_DOCUMENT_SCHEMA_A.clone(),
+        ])),
+        input = Expression::SimpleCase(SimpleCaseExpr {
+            expr: Box::new(Expression::Literal(LiteralValue::Integer(1))),
+            when_branch: vec![WhenBranch {
+                when: Box::new(Expression::Literal(LiteralValue::Integer(2))),
+                then: Box::new(test_document_a()),
+                is_nullable: false,
+            }],
+            else_branch: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+            is_nullable: false,
+        }),
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
 }
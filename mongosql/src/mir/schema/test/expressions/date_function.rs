@@ -0,0 +1,96 @@
+use crate::{
+    map,
+    mir::{schema::Error as mir_error, *},
+    schema::{Atomic, Schema, DATE_OR_NULLISH, INTEGER_LONG_OR_NULLISH},
+    set, test_schema,
+};
+
+mod date_add {
+    use super::*;
+
+    test_schema!(
+        date_add_days_is_date,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::DateFunction(DateFunctionApplication::new(
+            DateFunction::Add,
+            DatePart::Day,
+            vec![
+                Expression::Literal(LiteralValue::Integer(3)),
+                Expression::Reference(("d", 0u16).into()),
+            ],
+        )),
+        schema_env = map! { ("d", 0u16).into() => Schema::Atomic(Atomic::Date) },
+    );
+
+    test_schema!(
+        date_add_requires_date_to_add_to,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "DateAdd",
+            required: DATE_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::DateFunction(DateFunctionApplication::new(
+            DateFunction::Add,
+            DatePart::Day,
+            vec![
+                Expression::Literal(LiteralValue::Integer(3)),
+                Expression::Literal(LiteralValue::String("2024-01-01".to_string())),
+            ],
+        )),
+    );
+}
+
+mod date_sub {
+    use super::*;
+
+    test_schema!(
+        date_sub_months_is_date,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::DateFunction(DateFunctionApplication::new(
+            DateFunction::Sub,
+            DatePart::Month,
+            vec![
+                Expression::Literal(LiteralValue::Long(2)),
+                Expression::Reference(("d", 0u16).into()),
+            ],
+        )),
+        schema_env = map! { ("d", 0u16).into() => Schema::Atomic(Atomic::Date) },
+    );
+
+    test_schema!(
+        date_sub_from_nullable_date_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::DateFunction(DateFunctionApplication::new(
+            DateFunction::Sub,
+            DatePart::Month,
+            vec![
+                Expression::Literal(LiteralValue::Integer(2)),
+                Expression::Reference(("d", 0u16).into()),
+            ],
+        )),
+        schema_env = map! { ("d", 0u16).into() => DATE_OR_NULLISH.clone() },
+    );
+
+    test_schema!(
+        date_sub_requires_integer_amount,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "DateSub",
+            required: INTEGER_LONG_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::DateFunction(DateFunctionApplication::new(
+            DateFunction::Sub,
+            DatePart::Month,
+            vec![
+                Expression::Literal(LiteralValue::Double(2.5)),
+                Expression::Reference(("d", 0u16).into()),
+            ],
+        )),
+        schema_env = map! { ("d", 0u16).into() => Schema::Atomic(Atomic::Date) },
+    );
+}
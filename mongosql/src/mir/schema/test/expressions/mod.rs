@@ -1,5 +1,6 @@
 mod aggregate; // mir::Aggregate
 mod case; // mir::Expression::{SearchedCase, SimpleCase}
+mod date_function; // mir::Expression::DateFunction
 mod field_access; // mir::Expression::{FieldAccess, Reference (implicit)}
 mod like; // mir::Expression::Like
 mod literal; // mir::Expression::{Array, Document, Literal}
@@ -3,7 +3,8 @@ use crate::{
     mir::{schema::Error as mir_error, *},
     schema::{
         Atomic, Document, Satisfaction, Schema, ANY_ARRAY, ANY_DOCUMENT, BOOLEAN_OR_NULLISH,
-        INTEGER_OR_NULLISH, NON_NULLISH, NUMERIC_OR_NULLISH, STRING_OR_NULLISH,
+        INTEGER_LONG_OR_NULLISH, INTEGER_OR_NULLISH, NON_NULLISH, NUMERIC_OR_NULLISH,
+        STRING_OR_NULLISH,
     },
     set, test_schema,
 };
@@ -380,6 +381,72 @@ mod not {
         )),
         schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Null)},
     );
+
+    test_schema!(
+        not_true_literal_is_bool,
+        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Not,
+            vec![Expression::Literal(LiteralValue::Boolean(true))],
+        )),
+    );
+
+    test_schema!(
+        not_null_literal_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Not,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+}
+
+mod truth_value {
+    use super::*;
+
+    test_schema!(
+        is_true_arg_is_not_bool_is_error,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "IsTrue",
+            required: BOOLEAN_OR_NULLISH.clone(),
+            found: NUMERIC_OR_NULLISH.clone(),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IsTrue,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => NUMERIC_OR_NULLISH.clone()},
+    );
+
+    test_schema!(
+        is_true_of_nullable_is_bool,
+        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IsTrue,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => BOOLEAN_OR_NULLISH.clone()},
+    );
+
+    test_schema!(
+        is_false_of_null_is_bool,
+        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IsFalse,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+
+    test_schema!(
+        is_unknown_of_missing_is_bool,
+        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IsUnknown,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Missing},
+    );
 }
 
 mod trim {
@@ -722,53 +789,1097 @@ mod upper {
     );
 }
 
-mod year {
+mod replace {
+    use super::*;
+
+    test_schema!(
+        replace_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("L".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        replace_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("L".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        replace_may_be_missing,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("L".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        replace_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("L".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Null)},
+    );
+
+    test_schema!(
+        replace_must_be_null_if_replacement_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::Null),
+            ],
+        )),
+    );
+
+    test_schema!(
+        replace_requires_three_args,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "Replace",
+            required: 3,
+            found: 2,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::String("l".into())),
+            ],
+        )),
+    );
+
+    test_schema!(
+        replace_requires_string_or_nullish_args,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Replace",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Replace,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::String("L".into())),
+            ],
+        )),
+    );
+}
+
+mod left {
+    use super::*;
+
+    test_schema!(
+        left_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Left,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        left_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Left,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        left_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Left,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Null)
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        left_requires_string_or_nullish_first_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Left",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Left,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+    );
+
+    test_schema!(
+        left_requires_integer_or_nullish_second_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Left",
+            required: INTEGER_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Left,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Double(2.5))
+            ],
+        )),
+    );
+}
+
+mod right {
     use super::*;
 
     test_schema!(
-        year_must_be_string,
+        right_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Right,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        right_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Right,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        right_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Right,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Null)
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        right_requires_string_or_nullish_first_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Right",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Right,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+    );
+
+    test_schema!(
+        right_requires_integer_or_nullish_second_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Right",
+            required: INTEGER_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Right,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Double(2.5))
+            ],
+        )),
+    );
+}
+
+mod position {
+    use super::*;
+
+    test_schema!(
+        position_must_be_integer,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Reference(("bar", 0u16).into())
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        position_with_start_must_be_integer,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(3))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        position_with_start_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Reference(("bar", 0u16).into())
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Integer), Schema::Missing])},
+    );
+
+    test_schema!(
+        position_with_start_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Null)
+            ],
+        )),
+    );
+
+    test_schema!(
+        position_requires_integer_or_nullish_start,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Position",
+            required: INTEGER_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![
+                Expression::Literal(LiteralValue::String("l".into())),
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::String("1".into()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        position_requires_two_or_three_args,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "Position",
+            required: 2,
+            found: 1,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Position,
+            vec![Expression::Literal(LiteralValue::String("l".into()))],
+        )),
+    );
+}
+
+mod lpad {
+    use super::*;
+
+    test_schema!(
+        lpad_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        lpad_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Reference(("bar", 0u16).into())
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        lpad_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        lpad_requires_string_or_nullish_first_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "LPad",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        lpad_requires_integer_or_nullish_second_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "LPad",
+            required: INTEGER_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Double(5.5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        lpad_requires_string_or_nullish_third_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "LPad",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::Integer(0))
+            ],
+        )),
+    );
+
+    test_schema!(
+        lpad_requires_three_args,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "LPad",
+            required: 3,
+            found: 2,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::LPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5))
+            ],
+        )),
+    );
+}
+
+mod rpad {
+    use super::*;
+
+    test_schema!(
+        rpad_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        rpad_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Reference(("bar", 0u16).into())
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        rpad_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        rpad_requires_string_or_nullish_first_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "RPad",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        rpad_requires_integer_or_nullish_second_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "RPad",
+            required: INTEGER_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Double(5.5)),
+                Expression::Literal(LiteralValue::String("*".into()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        rpad_requires_string_or_nullish_third_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "RPad",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::Integer(0))
+            ],
+        )),
+    );
+
+    test_schema!(
+        rpad_requires_three_args,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "RPad",
+            required: 3,
+            found: 2,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::RPad,
+            vec![
+                Expression::Literal(LiteralValue::String("hello".into())),
+                Expression::Literal(LiteralValue::Integer(5))
+            ],
+        )),
+    );
+}
+
+mod year {
+    use super::*;
+
+    test_schema!(
+        year_must_be_string,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Year,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        year_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Year,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        year_may_be_missing,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Year,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Missing])},
+    );
+
+    test_schema!(
+        year_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Year,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Null)},
+    );
+}
+
+mod month_name_day_name {
+    use super::*;
+
+    test_schema!(
+        month_name_returns_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::MonthName,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        day_name_returns_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DayName,
+            vec![Expression::Literal(LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))],
+        )),
+    );
+
+    test_schema!(
+        month_name_date_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::MonthName,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Missing])},
+    );
+
+    test_schema!(
+        day_name_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DayName,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+
+    test_schema!(
+        month_name_requires_date,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "MonthName",
+            required: Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Date),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::MonthName,
+            vec![Expression::Literal(LiteralValue::String(
+                "2024-01-01".into()
+            ))],
+        )),
+    );
+
+    test_schema!(
+        month_name_with_locale_returns_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::MonthName,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("de_DE".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        day_name_unsupported_locale,
+        expected_error_code = 1024,
+        expected = Err(mir_error::InvalidDateNameLocale),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DayName,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("xx_XX".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        day_name_non_literal_locale,
+        expected_error_code = 1024,
+        expected = Err(mir_error::InvalidDateNameLocale),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DayName,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Reference(("baz", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::Atomic(Atomic::Date),
+            ("baz", 0u16).into() => Schema::Atomic(Atomic::String),
+        },
+    );
+
+    test_schema!(
+        month_name_too_many_args,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "MonthName",
+            required: 2,
+            found: 3
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::MonthName,
+            vec![
+                Expression::Literal(LiteralValue::DateTime(bson::DateTime::MIN)),
+                Expression::Literal(LiteralValue::String("en_US".into())),
+                Expression::Literal(LiteralValue::String("en_US".into())),
+            ],
+        )),
+    );
+}
+
+mod unix_timestamp {
+    use super::*;
+
+    test_schema!(
+        unix_timestamp_returns_long,
+        expected = Ok(Schema::Atomic(Atomic::Long)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::UnixTimestamp,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        unix_timestamp_date_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::UnixTimestamp,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        unix_timestamp_date_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::UnixTimestamp,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+
+    test_schema!(
+        unix_timestamp_requires_date,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "UnixTimestamp",
+            required: Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Date),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::UnixTimestamp,
+            vec![Expression::Literal(LiteralValue::Integer(1))],
+        )),
+    );
+}
+
+mod from_unix_timestamp {
+    use super::*;
+
+    test_schema!(
+        from_unix_timestamp_of_long_returns_date,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::FromUnixTimestamp,
+            vec![Expression::Literal(LiteralValue::Long(1_700_000_000_000))],
+        )),
+    );
+
+    test_schema!(
+        from_unix_timestamp_of_integer_returns_date,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::FromUnixTimestamp,
+            vec![Expression::Literal(LiteralValue::Integer(0))],
+        )),
+    );
+
+    test_schema!(
+        from_unix_timestamp_arg_may_be_missing,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::FromUnixTimestamp,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Long), Schema::Missing])},
+    );
+
+    test_schema!(
+        from_unix_timestamp_arg_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::FromUnixTimestamp,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+
+    test_schema!(
+        from_unix_timestamp_requires_integer_or_long,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "FromUnixTimestamp",
+            required: Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Integer),
+                Schema::Atomic(Atomic::Long),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::FromUnixTimestamp,
+            vec![Expression::Literal(LiteralValue::String("0".into()))],
+        )),
+    );
+}
+
+mod date_format {
+    use super::*;
+
+    test_schema!(
+        date_format_returns_string,
+        expected = Ok(Schema::Atomic(Atomic::String)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DateFormat,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        date_format_date_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DateFormat,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Missing])},
+    );
+
+    test_schema!(
+        date_format_format_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DateFormat,
+            vec![
+                Expression::Literal(LiteralValue::DateTime(bson::DateTime::MIN)),
+                Expression::Reference(("bar", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        date_format_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DateFormat,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
+        )),
+    );
+
+    test_schema!(
+        date_format_format_must_be_string,
+        expected = Err(mir_error::SchemaChecking {
+            name: "DateFormat",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::DateFormat,
+            vec![
+                Expression::Literal(LiteralValue::DateTime(bson::DateTime::MIN)),
+                Expression::Literal(LiteralValue::Integer(1)),
+            ],
+        )),
+    );
+}
+
+mod parse_date {
+    use super::*;
+
+    test_schema!(
+        parse_date_may_be_null_on_error,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        parse_date_string_may_be_missing,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        parse_date_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Literal(LiteralValue::String("2024-01-01".into())),
+                Expression::Literal(LiteralValue::Null),
+            ],
+        )),
+    );
+
+    test_schema!(
+        parse_date_nullish_string_is_on_null,
         expected = Ok(Schema::Atomic(Atomic::Integer)),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Year,
-            vec![Expression::Reference(("bar", 0u16).into()),],
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::String("error".into())),
+            ],
         )),
-        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
     );
 
     test_schema!(
-        year_may_be_null,
+        parse_date_with_on_null_and_on_error,
         expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
             Schema::Atomic(Atomic::Integer),
-            Schema::Atomic(Atomic::Null)
+            Schema::Atomic(Atomic::String),
         ])),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Year,
-            vec![Expression::Reference(("bar", 0u16).into()),],
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::String("error".into())),
+            ],
         )),
-        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Atomic(Atomic::Null)])},
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
     );
 
     test_schema!(
-        year_may_be_missing,
+        parse_date_non_nullish_string_is_not_on_null,
         expected = Ok(Schema::AnyOf(set![
-            Schema::Atomic(Atomic::Integer),
-            Schema::Atomic(Atomic::Null)
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::String),
         ])),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Year,
-            vec![Expression::Reference(("bar", 0u16).into()),],
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Literal(LiteralValue::String("2024-01-01".into())),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::String("error".into())),
+            ],
         )),
-        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Missing])},
     );
 
     test_schema!(
-        year_must_be_null,
-        expected = Ok(Schema::Atomic(Atomic::Null)),
+        parse_date_string_must_be_string,
+        expected = Err(mir_error::SchemaChecking {
+            name: "ParseDate",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Date),
+        }),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Year,
-            vec![Expression::Reference(("bar", 0u16).into()),],
+            ScalarFunction::ParseDate,
+            vec![
+                Expression::Literal(LiteralValue::DateTime(bson::DateTime::MIN)),
+                Expression::Literal(LiteralValue::String("%Y-%m-%d".into())),
+            ],
         )),
-        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Null)},
     );
 }
 
@@ -972,6 +2083,56 @@ mod hour {
     );
 }
 
+mod millisecond {
+    use super::*;
+
+    test_schema!(
+        millisecond_must_be_date,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Millisecond,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
+
+    test_schema!(
+        millisecond_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Millisecond,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        millisecond_may_be_missing,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Millisecond,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Date), Schema::Missing])},
+    );
+
+    test_schema!(
+        millisecond_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Millisecond,
+            vec![Expression::Reference(("bar", 0u16).into()),],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Null)},
+    );
+}
+
 mod arithmetic {
     use super::*;
 
@@ -1104,6 +2265,111 @@ mod arithmetic {
         },
     );
 
+    test_schema!(
+        arithmetic_nested_any_of_widens_like_flattened_any_of,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Double),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Add,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(1)),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Long),
+                ]),
+                Schema::Atomic(Atomic::Double),
+            ]),
+        },
+    );
+
+    test_schema!(
+        arithmetic_deeply_nested_any_of_decimal_takes_priority,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Decimal),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Mul,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Long(2)),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Decimal),
+                    Schema::Atomic(Atomic::Integer),
+                ])]),
+                Schema::Atomic(Atomic::Long),
+            ]),
+        },
+    );
+
+    test_schema!(
+        arithmetic_nested_any_of_args_may_be_nullish,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Sub,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Reference(("foo", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Null),
+                ]),
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Double),
+                    Schema::Missing,
+                ]),
+            ]),
+            ("foo", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![Schema::Atomic(Atomic::Long)]),
+            ]),
+        },
+    );
+
+    test_schema!(
+        arithmetic_nested_any_of_ignores_non_numeric_types_in_relaxed_mode,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Double),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Add,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(1)),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::String),
+                ]),
+                Schema::Atomic(Atomic::Double),
+            ]),
+        },
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
+
     test_schema!(
         arithmetic_decimal_double_takes_priority_in_any_of_must_be_numeric,
         expected = Ok(Schema::AnyOf(set![
@@ -1342,6 +2608,7 @@ mod arithmetic {
             function: AggregationFunction::Sum,
             arg: Box::new(Expression::Reference(("foo", 0u16).into())),
             distinct: true,
+            separator: None,
         }),
         schema_env = map! {
             ("foo", 0u16).into() => Schema::Any,
@@ -1517,6 +2784,145 @@ mod arithmetic {
     }
 }
 
+mod int_div {
+    use super::*;
+
+    test_schema!(
+        int_div_int_by_int_is_int_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IntDiv,
+            vec![
+                Expression::Literal(LiteralValue::Integer(7)),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        int_div_long_by_int_widens_to_long_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IntDiv,
+            vec![
+                Expression::Literal(LiteralValue::Long(7)),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        int_div_null_arg_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IntDiv,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        int_div_double_arg_is_error,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "IntDiv",
+            required: INTEGER_LONG_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::IntDiv,
+            vec![
+                Expression::Literal(LiteralValue::Integer(7)),
+                Expression::Literal(LiteralValue::Double(2.0)),
+            ],
+        )),
+    );
+}
+
+mod bitwise {
+    use super::*;
+
+    test_schema!(
+        bit_and_int_and_int_is_int,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::BitAnd,
+            vec![
+                Expression::Literal(LiteralValue::Integer(6)),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        bit_or_int_and_long_widens_to_long,
+        expected = Ok(Schema::Atomic(Atomic::Long)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::BitOr,
+            vec![
+                Expression::Literal(LiteralValue::Integer(6)),
+                Expression::Literal(LiteralValue::Long(3)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        bit_xor_null_arg_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::BitXor,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        bit_and_nullable_arg_is_nullable,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::BitAnd,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])},
+    );
+
+    test_schema!(
+        bit_or_double_arg_is_error,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "BitOr",
+            required: INTEGER_LONG_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Double),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::BitOr,
+            vec![
+                Expression::Literal(LiteralValue::Integer(6)),
+                Expression::Literal(LiteralValue::Double(3.0)),
+            ],
+        )),
+    );
+}
+
 mod abs {
     use super::*;
 
@@ -1706,6 +3112,69 @@ mod floor {
 mod log {
     use super::*;
 
+    test_schema!(
+        log_one_arg_integer_is_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Log,
+            vec![Expression::Literal(LiteralValue::Integer(10))],
+        )),
+    );
+
+    test_schema!(
+        log_two_args_integer_is_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Log,
+            vec![
+                Expression::Literal(LiteralValue::Integer(8)),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        log_one_arg_nullable_is_nullable_double,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Log,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null),
+        ])},
+    );
+
+    test_schema!(
+        log_two_args_null_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Log,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        log_one_arg_must_be_number_or_nullish,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Log",
+            required: NUMERIC_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Log,
+            vec![Expression::Literal(LiteralValue::String("abc".to_string()))],
+        )),
+    );
+
     test_schema!(
         log_requires_exactly_two_args,
         expected_error_code = 1001,
@@ -1841,6 +3310,46 @@ mod mod_func {
             ],
         )),
     );
+
+    test_schema!(
+        mod_widens_integral_args_to_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Mod,
+            vec![
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::Long(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        mod_divisor_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Mod,
+            vec![
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Reference(("bar", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Integer), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        mod_divisor_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Mod,
+            vec![
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::Null),
+            ],
+        )),
+    );
 }
 
 mod pow {
@@ -1911,6 +3420,31 @@ mod pow {
             ],
         )),
     );
+
+    test_schema!(
+        pow_widens_integral_args_to_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Pow,
+            vec![
+                Expression::Literal(LiteralValue::Integer(2)),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        pow_decimal_arg_is_decimal,
+        expected = Ok(Schema::Atomic(Atomic::Decimal)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Pow,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Decimal)},
+    );
 }
 
 mod round {
@@ -2216,6 +3750,38 @@ mod sqrt {
             vec![Expression::Literal(LiteralValue::String("abc".to_string()))],
         )),
     );
+
+    test_schema!(
+        sqrt_integer_arg_is_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Sqrt,
+            vec![Expression::Literal(LiteralValue::Integer(4))],
+        )),
+    );
+
+    test_schema!(
+        sqrt_decimal_arg_is_double,
+        expected = Ok(Schema::Atomic(Atomic::Double)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Sqrt,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Decimal)},
+    );
+
+    test_schema!(
+        sqrt_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Sqrt,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => NUMERIC_OR_NULLISH.clone()},
+    );
 }
 
 mod comparison {
@@ -2236,61 +3802,192 @@ mod comparison {
     );
 
     test_schema!(
-        comp_op_requires_a_valid_comparison,
-        expected_error_code = 1005,
-        expected = Err(mir_error::InvalidComparison(
-            "Lte",
-            Schema::Atomic(Atomic::Integer),
+        comp_op_requires_a_valid_comparison,
+        expected_error_code = 1005,
+        expected = Err(mir_error::InvalidComparison(
+            "Lte",
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        )),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Lte,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::String("abc".to_string()))
+            ],
+        )),
+    );
+
+    test_schema!(
+        comp_op_returns_boolean_schema_for_non_nullish_comparison,
+        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Eq,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Integer(2))
+            ],
+        )),
+    );
+
+    test_schema!(
+        comp_op_returns_boolean_and_null_schema_for_potentially_nullish_comparison,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Boolean),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Gt,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Reference(("integer_or_null", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {("integer_or_null", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Integer), Schema::Atomic(Atomic::Null)])},
+    );
+
+    test_schema!(
+        comp_op_returns_null_schema_for_nullish_comparison,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Gte,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Null),
+            ],
+        )),
+    );
+}
+
+mod greatest_least {
+    use super::*;
+
+    test_schema!(
+        greatest_widens_mixed_numeric_args_to_double_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Double),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Greatest,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Double(2.5)),
+                Expression::Literal(LiteralValue::Integer(3)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        greatest_of_int_double_decimal_is_decimal_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Decimal),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Greatest,
+            vec![
+                Expression::Reference(("int", 0u16).into()),
+                Expression::Reference(("double", 0u16).into()),
+                Expression::Reference(("decimal", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {
+            ("int", 0u16).into() => Schema::Atomic(Atomic::Integer),
+            ("double", 0u16).into() => Schema::Atomic(Atomic::Double),
+            ("decimal", 0u16).into() => Schema::Atomic(Atomic::Decimal),
+        },
+    );
+
+    test_schema!(
+        least_of_int_and_long_is_long_or_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Least,
+            vec![
+                Expression::Literal(LiteralValue::Integer(1)),
+                Expression::Literal(LiteralValue::Long(2)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        greatest_of_strings_is_string_or_null,
+        expected = Ok(Schema::AnyOf(set![
             Schema::Atomic(Atomic::String),
-        )),
+            Schema::Atomic(Atomic::Null),
+        ])),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Lte,
+            ScalarFunction::Greatest,
             vec![
-                Expression::Literal(LiteralValue::Integer(1)),
-                Expression::Literal(LiteralValue::String("abc".to_string()))
+                Expression::Literal(LiteralValue::String("a".to_string())),
+                Expression::Literal(LiteralValue::String("b".to_string())),
             ],
         )),
     );
 
     test_schema!(
-        comp_op_returns_boolean_schema_for_non_nullish_comparison,
-        expected = Ok(Schema::Atomic(Atomic::Boolean)),
+        least_missing_arg_is_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Eq,
+            ScalarFunction::Least,
             vec![
-                Expression::Literal(LiteralValue::Integer(1)),
-                Expression::Literal(LiteralValue::Integer(2))
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Long(1)),
             ],
         )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Missing},
     );
 
     test_schema!(
-        comp_op_returns_boolean_and_null_schema_for_potentially_nullish_comparison,
-        expected = Ok(Schema::AnyOf(set![
-            Schema::Atomic(Atomic::Boolean),
-            Schema::Atomic(Atomic::Null)
-        ])),
+        greatest_all_null_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Gt,
+            ScalarFunction::Greatest,
             vec![
-                Expression::Literal(LiteralValue::Integer(1)),
-                Expression::Reference(("integer_or_null", 0u16).into()),
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::Null),
             ],
         )),
-        schema_env = map! {("integer_or_null", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::Integer), Schema::Atomic(Atomic::Null)])},
     );
 
     test_schema!(
-        comp_op_returns_null_schema_for_nullish_comparison,
-        expected = Ok(Schema::Atomic(Atomic::Null)),
+        least_requires_comparable_args,
+        expected_error_code = 1005,
+        expected = Err(mir_error::InvalidComparison(
+            "Least",
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        )),
         input = Expression::ScalarFunction(ScalarFunctionApplication::new(
-            ScalarFunction::Gte,
+            ScalarFunction::Least,
             vec![
                 Expression::Literal(LiteralValue::Integer(1)),
-                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::String("abc".to_string())),
+                Expression::Literal(LiteralValue::Integer(2)),
             ],
         )),
     );
+
+    test_schema!(
+        greatest_requires_at_least_one_arg,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "Greatest",
+            required: 1,
+            found: 0,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Greatest,
+            vec![],
+        )),
+    );
 }
 
 mod between {
@@ -3306,6 +5003,221 @@ mod split {
             is_nullable: true,
         }),
     );
+
+    test_schema!(
+        split_without_token_number_must_be_string_array,
+        expected = Ok(Schema::Array(Box::new(Schema::Atomic(Atomic::String)))),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Split,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("-".to_string())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        split_without_token_number_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Array(Box::new(Schema::Atomic(Atomic::String))),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Split,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::String("-".to_string())),
+            ],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![Schema::Atomic(Atomic::String), Schema::Missing])},
+    );
+
+    test_schema!(
+        split_without_token_number_must_be_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Split,
+            vec![
+                Expression::Literal(LiteralValue::String("a-b-c".to_string())),
+                Expression::Literal(LiteralValue::Null),
+            ],
+        )),
+    );
+
+    test_schema!(
+        split_without_token_number_requires_string_or_nullish_args,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "Split",
+            required: STRING_OR_NULLISH.clone(),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Split,
+            vec![
+                Expression::Literal(LiteralValue::Integer(5)),
+                Expression::Literal(LiteralValue::String("-".to_string())),
+            ],
+        )),
+    );
+}
+
+mod array_distinct {
+    use super::*;
+
+    test_schema!(
+        array_distinct_preserves_element_type,
+        expected = Ok(Schema::Array(Box::new(Schema::Atomic(Atomic::Integer)))),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayDistinct,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env =
+            map! {("bar", 0u16).into() => Schema::Array(Box::new(Schema::Atomic(Atomic::Integer)))},
+    );
+
+    test_schema!(
+        array_distinct_preserves_polymorphic_element_type,
+        expected = Ok(Schema::Array(Box::new(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        ])))),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayDistinct,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::Array(Box::new(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        ])))},
+    );
+
+    test_schema!(
+        array_distinct_nullable_arg_is_nullable,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Array(Box::new(Schema::Atomic(Atomic::String))),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayDistinct,
+            vec![Expression::Reference(("bar", 0u16).into())],
+        )),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Array(Box::new(Schema::Atomic(Atomic::String))),
+            Schema::Missing,
+        ])},
+    );
+
+    test_schema!(
+        array_distinct_null_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayDistinct,
+            vec![Expression::Literal(LiteralValue::Null)],
+        )),
+    );
+
+    test_schema!(
+        array_distinct_requires_array_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "ArrayDistinct",
+            required: Schema::AnyOf(set![
+                ANY_ARRAY.clone(),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            found: Schema::Atomic(Atomic::Integer),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayDistinct,
+            vec![Expression::Literal(LiteralValue::Integer(1))],
+        )),
+    );
+}
+
+mod sort_array {
+    use super::*;
+
+    test_schema!(
+        sort_array_preserves_element_type,
+        expected = Ok(Schema::Array(Box::new(Schema::Atomic(Atomic::Double)))),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::SortArray,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(-1)),
+            ],
+        )),
+        schema_env =
+            map! {("bar", 0u16).into() => Schema::Array(Box::new(Schema::Atomic(Atomic::Double)))},
+    );
+
+    test_schema!(
+        sort_array_requires_literal_direction,
+        expected_error_code = 1022,
+        expected = Err(mir_error::InvalidSortArrayDirection),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::SortArray,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Reference(("baz", 0u16).into()),
+            ],
+        )),
+        schema_env = map! {
+            ("bar", 0u16).into() => Schema::Array(Box::new(Schema::Atomic(Atomic::Double))),
+            ("baz", 0u16).into() => Schema::Atomic(Atomic::Integer),
+        },
+    );
+
+    test_schema!(
+        sort_array_requires_direction_of_one_or_negative_one,
+        expected_error_code = 1022,
+        expected = Err(mir_error::InvalidSortArrayDirection),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::SortArray,
+            vec![
+                Expression::Reference(("bar", 0u16).into()),
+                Expression::Literal(LiteralValue::Integer(2)),
+            ],
+        )),
+        schema_env =
+            map! {("bar", 0u16).into() => Schema::Array(Box::new(Schema::Atomic(Atomic::Double)))},
+    );
+
+    test_schema!(
+        sort_array_null_array_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::SortArray,
+            vec![
+                Expression::Literal(LiteralValue::Null),
+                Expression::Literal(LiteralValue::Integer(1)),
+            ],
+        )),
+    );
+
+    test_schema!(
+        sort_array_requires_array_arg,
+        expected_error_code = 1002,
+        expected = Err(mir_error::SchemaChecking {
+            name: "SortArray",
+            required: Schema::AnyOf(set![
+                ANY_ARRAY.clone(),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            found: Schema::Atomic(Atomic::String),
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::SortArray,
+            vec![
+                Expression::Literal(LiteralValue::String("abc".to_string())),
+                Expression::Literal(LiteralValue::Integer(1)),
+            ],
+        )),
+    );
 }
 
 mod size {
@@ -3368,6 +5280,57 @@ mod size {
     );
 }
 
+mod array_length {
+    use super::*;
+
+    test_schema!(
+        array_length_requires_one_arg,
+        expected_error_code = 1001,
+        expected = Err(mir_error::IncorrectArgumentCount {
+            name: "ArrayLength",
+            required: 1,
+            found: 0,
+        }),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayLength,
+            vec![],
+        )),
+    );
+
+    test_schema!(
+        array_length_of_array,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayLength,
+            vec![Expression::Reference(("array", 0u16).into())],
+        )),
+        schema_env = map! { ("array", 0u16).into() => ANY_ARRAY.clone() },
+    );
+
+    test_schema!(
+        array_length_of_non_array_is_null,
+        expected = Ok(Schema::Atomic(Atomic::Null)),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayLength,
+            vec![Expression::Literal(LiteralValue::Integer(1))],
+        )),
+    );
+
+    test_schema!(
+        array_length_of_arg_that_may_be_array_may_be_null,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null)
+        ])),
+        input = Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::ArrayLength,
+            vec![Expression::Reference(("array_or_int", 0u16).into())],
+        )),
+        schema_env = map! { ("array_or_int", 0u16).into() =>
+        Schema::AnyOf(set![ANY_ARRAY.clone(), Schema::Atomic(Atomic::Integer)]) },
+    );
+}
+
 mod pos {
     use super::*;
 
@@ -20,6 +20,60 @@ mod cast {
         }),
     );
 
+    test_schema!(
+        cast_always_integer_reference_to_int32,
+        expected = Ok(Schema::Atomic(Atomic::Integer)),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Int32,
+            on_null: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+            on_error: Box::new(Expression::Literal(LiteralValue::Boolean(true))),
+            is_nullable: false,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Integer)},
+    );
+
+    test_schema!(
+        cast_nullable_integer_reference_to_int32_cannot_error,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+        ])),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Int32,
+            on_null: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+            on_error: Box::new(Expression::Literal(LiteralValue::Boolean(true))),
+            is_nullable: false,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null),
+            Schema::Missing,
+        ])},
+    );
+
+    test_schema!(
+        cast_may_be_integer_reference_to_int32_in_relaxed_mode_may_error,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Boolean),
+        ])),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Int32,
+            on_null: Box::new(Expression::Literal(LiteralValue::String("abc".to_string()))),
+            on_error: Box::new(Expression::Literal(LiteralValue::Boolean(true))),
+            is_nullable: false,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Double),
+        ])},
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
+
     test_schema!(
         cast_expr_to_other_type,
         expected = Ok(Schema::AnyOf(set![
@@ -141,6 +195,85 @@ mod cast {
         }),
         schema_env = map! {("bar", 0u16).into() => Schema::Missing},
     );
+    test_schema!(
+        cast_string_to_date_with_on_error_set,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null),
+            Schema::Atomic(Atomic::String),
+        ])),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Datetime,
+            on_null: Box::new(Expression::Literal(LiteralValue::Null)),
+            on_error: Box::new(Expression::Literal(LiteralValue::String("bad".to_string()))),
+            is_nullable: true,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::String)},
+    );
+
+    test_schema!(
+        cast_long_to_date,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Null),
+        ])),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Literal(LiteralValue::Long(0))),
+            to: Type::Datetime,
+            on_null: Box::new(Expression::Literal(LiteralValue::Null)),
+            on_error: Box::new(Expression::Literal(LiteralValue::Null)),
+            is_nullable: true,
+        }),
+    );
+
+    test_schema!(
+        cast_nullable_long_to_date_with_on_null_set,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Date),
+            Schema::Atomic(Atomic::Boolean),
+        ])),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Datetime,
+            on_null: Box::new(Expression::Literal(LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))),
+            on_error: Box::new(Expression::Literal(LiteralValue::Boolean(false))),
+            is_nullable: false,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Null),
+        ])},
+    );
+
+    test_schema!(
+        cast_null_to_date_is_on_null,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Literal(LiteralValue::Null)),
+            to: Type::Datetime,
+            on_null: Box::new(Expression::Literal(LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))),
+            on_error: Box::new(Expression::Literal(LiteralValue::Null)),
+            is_nullable: true,
+        }),
+    );
+
+    test_schema!(
+        cast_date_to_date_is_date,
+        expected = Ok(Schema::Atomic(Atomic::Date)),
+        input = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Reference(("bar", 0u16).into())),
+            to: Type::Datetime,
+            on_null: Box::new(Expression::Literal(LiteralValue::Null)),
+            on_error: Box::new(Expression::Literal(LiteralValue::Null)),
+            is_nullable: true,
+        }),
+        schema_env = map! {("bar", 0u16).into() => Schema::Atomic(Atomic::Date)},
+    );
 }
 
 mod type_assert {
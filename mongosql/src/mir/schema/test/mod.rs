@@ -212,6 +212,44 @@ mod arithmetic_retain {
             ]),
         ]
     );
+    test_retain!(
+        nested_anyof_atomic_retains_like_flattened_anyof,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Double),
+        ])),
+        input = set![
+            Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Long),
+                ]),
+                Schema::Atomic(Atomic::Double),
+            ]),
+            Schema::Atomic(Atomic::Long),
+        ]
+    );
+    test_retain!(
+        nested_anyof_nested_anyof_retains_dominant_result_of_all_possible_pairs,
+        expected = Ok(Schema::AnyOf(set![
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Long),
+            Schema::Atomic(Atomic::Double),
+        ])),
+        input = set![
+            Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Long),
+                ]),
+                Schema::Atomic(Atomic::Double),
+            ]),
+            Schema::AnyOf(set![Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Integer),
+                Schema::Atomic(Atomic::Long),
+            ])]),
+        ]
+    );
 }
 
 mod max_numeric {
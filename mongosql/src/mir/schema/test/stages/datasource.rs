@@ -37,6 +37,23 @@ mod collection {
         }),
     );
 
+    test_schema!(
+        collection_schema_exceeding_max_depth,
+        expected_error_code = 1023,
+        expected = Err(mir_error::MaxSchemaDepthExceeded),
+        input = Stage::Collection(Collection {
+            db: "foo".into(),
+            collection: "bar".into(),
+            cache: SchemaCache::new(),
+        }),
+        catalog = Catalog::new(map! {
+            Namespace {db: "foo".into(), collection: "bar".into()} =>
+                (0..crate::schema::MAX_DEPTH).fold(Schema::Atomic(Atomic::Integer), |s, _| {
+                    Schema::Array(Box::new(s))
+                })
+        }),
+    );
+
     test_schema!(
         collection_schema_namespace_in_catalog,
         expected = Ok(ResultSet {
@@ -5,7 +5,7 @@ use crate::{
         schema::{Error as mir_error, SchemaCache},
         *,
     },
-    schema::{Atomic, ResultSet, Schema, ANY_DOCUMENT},
+    schema::{Atomic, Document, ResultSet, Schema, ANY_DOCUMENT},
     set, test_schema, unchecked_unique_linked_hash_map,
 };
 
@@ -159,3 +159,111 @@ test_schema!(
         cache: SchemaCache::new(),
     }),
 );
+
+fn nullable_x_document() -> Schema {
+    Schema::Document(Document {
+        keys: map! {
+            "x".into() => Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Integer),
+                Schema::Atomic(Atomic::String),
+                Schema::Atomic(Atomic::Null),
+            ]),
+        },
+        required: set! {},
+        additional_properties: false,
+        jaccard_index: None,
+    })
+}
+
+fn foo_x() -> Expression {
+    Expression::FieldAccess(FieldAccess::new(
+        Expression::Reference(("foo", 0u16).into()).into(),
+        "x".into(),
+    ))
+}
+
+test_schema!(
+    is_not_null_condition_narrows_field_schema,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::Document(Document {
+                keys: map! {
+                    "x".into() => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Integer),
+                        Schema::Atomic(Atomic::String),
+                    ]),
+                },
+                required: set! {"x".into()},
+                additional_properties: false,
+                jaccard_index: None,
+            }),
+        },
+        min_size: 0,
+        max_size: None,
+    }),
+    input = Stage::Filter(Filter {
+        source: Box::new(test_source()),
+        condition: Expression::ScalarFunction(ScalarFunctionApplication::new(
+            ScalarFunction::Not,
+            vec![Expression::Is(IsExpr {
+                expr: Box::new(foo_x()),
+                target_type: TypeOrMissing::Type(Type::Null),
+            })],
+        )),
+        cache: SchemaCache::new(),
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "foo".into()} => nullable_x_document(),
+    }),
+);
+
+test_schema!(
+    is_type_condition_narrows_field_schema,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::Document(Document {
+                keys: map! {
+                    "x".into() => Schema::Atomic(Atomic::String),
+                },
+                required: set! {"x".into()},
+                additional_properties: false,
+                jaccard_index: None,
+            }),
+        },
+        min_size: 0,
+        max_size: None,
+    }),
+    input = Stage::Filter(Filter {
+        source: Box::new(test_source()),
+        condition: Expression::Is(IsExpr {
+            expr: Box::new(foo_x()),
+            target_type: TypeOrMissing::Type(Type::String),
+        }),
+        cache: SchemaCache::new(),
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "foo".into()} => nullable_x_document(),
+    }),
+);
+
+test_schema!(
+    is_null_condition_does_not_narrow_field_schema,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => nullable_x_document(),
+        },
+        min_size: 0,
+        max_size: None,
+    }),
+    input = Stage::Filter(Filter {
+        source: Box::new(test_source()),
+        condition: Expression::Is(IsExpr {
+            expr: Box::new(foo_x()),
+            target_type: TypeOrMissing::Type(Type::Null),
+        }),
+        cache: SchemaCache::new(),
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "foo".into()} => nullable_x_document(),
+    }),
+);
@@ -2,10 +2,13 @@ use crate::{
     catalog::Namespace,
     map,
     mir::{
-        schema::{Atomic, Document, Error as mir_error, Group, ResultSet, SchemaCache},
+        schema::{
+            test::test_document_a, Atomic, Document, Error as mir_error, Group, ResultSet,
+            SchemaCache,
+        },
         AggregationExpr, AggregationFunction, AggregationFunctionApplication, AliasedAggregation,
-        AliasedExpr, Collection, Expression, FieldAccess, LiteralValue, OptionallyAliasedExpr,
-        Stage,
+        AliasedExpr, ArraySource, Collection, Expression, FieldAccess, LiteralValue,
+        OptionallyAliasedExpr, Stage,
     },
     schema::{Schema, ANY_DOCUMENT},
     set, test_schema,
@@ -285,6 +288,7 @@ test_schema!(
                     function: AggregationFunction::First,
                     distinct: false,
                     arg: Expression::Literal(LiteralValue::Boolean(true)).into(),
+                    separator: None,
                 }),
             },
             AliasedAggregation {
@@ -293,12 +297,192 @@ test_schema!(
                     function: AggregationFunction::First,
                     distinct: false,
                     arg: Expression::Literal(LiteralValue::String("abc".into())).into(),
+                    separator: None,
+                }),
+            },
+        ],
+        cache: SchemaCache::new(),
+        scope: 0,
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
+    }),
+);
+
+test_schema!(
+    null_key_count_groups_all_rows_together,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            Key::bot(0u16) => Schema::Document(Document {
+                keys: map! {
+                    "k".into() => Schema::Atomic(Atomic::Null),
+                    "c".into() => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Integer),
+                        Schema::Atomic(Atomic::Long),
+                    ]),
+                },
+                required: set! { "k".into(), "c".into() },
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+        min_size: 1,
+        max_size: Some(1),
+    }),
+    input = Stage::Group(Group {
+        source: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a(), test_document_a(), test_document_a()],
+            alias: "arr".into(),
+            cache: SchemaCache::new(),
+        })),
+        keys: vec![OptionallyAliasedExpr::Aliased(AliasedExpr {
+            alias: "k".into(),
+            expr: Expression::Literal(LiteralValue::Null),
+        })],
+        aggregations: vec![AliasedAggregation {
+            alias: "c".to_string(),
+            agg_expr: AggregationExpr::CountStar(false),
+        }],
+        cache: SchemaCache::new(),
+        scope: 0,
+    }),
+);
+
+test_schema!(
+    no_keys_over_non_empty_source_is_single_row,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            Key::bot(0u16) => Schema::Document(Document {
+                keys: map! {
+                    "c".into() => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Integer),
+                        Schema::Atomic(Atomic::Long),
+                    ]),
+                },
+                required: set! { "c".into() },
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+        min_size: 1,
+        max_size: Some(1),
+    }),
+    input = Stage::Group(Group {
+        source: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a(), test_document_a()],
+            alias: "arr".into(),
+            cache: SchemaCache::new(),
+        })),
+        keys: vec![],
+        aggregations: vec![AliasedAggregation {
+            alias: "c".to_string(),
+            agg_expr: AggregationExpr::CountStar(false),
+        }],
+        cache: SchemaCache::new(),
+        scope: 0,
+    }),
+);
+
+test_schema!(
+    no_keys_over_collection_is_at_most_one_row,
+    expected_pat = Ok(ResultSet {
+        min_size: 0,
+        max_size: Some(1),
+        ..
+    }),
+    input = Stage::Group(Group {
+        source: Box::new(Stage::Collection(Collection {
+            db: "test".into(),
+            collection: "bar".into(),
+            cache: SchemaCache::new(),
+        })),
+        keys: vec![],
+        aggregations: vec![AliasedAggregation {
+            alias: "c".to_string(),
+            agg_expr: AggregationExpr::CountStar(false),
+        }],
+        cache: SchemaCache::new(),
+        scope: 0,
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
+    }),
+);
+
+test_schema!(
+    grouped_sum_and_avg,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            Key::bot(0u16) => Schema::Document(Document {
+                keys: map! {
+                    "A".into() => Schema::Atomic(Atomic::String),
+                    "total".into() => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Integer),
+                        Schema::Atomic(Atomic::Null),
+                    ]),
+                    "mean".into() => Schema::AnyOf(set![
+                        Schema::Atomic(Atomic::Double),
+                        Schema::Atomic(Atomic::Null),
+                    ]),
+                },
+                required: set! { "A".into(), "total".into(), "mean".into() },
+                additional_properties: false,
+                ..Default::default()
+            }),
+        },
+        min_size: 0,
+        max_size: None,
+    }),
+    input = Stage::Group(Group {
+        source: Box::new(Stage::Collection(Collection {
+            db: "test".into(),
+            collection: "bar".into(),
+            cache: SchemaCache::new(),
+        })),
+        keys: vec![group_aliased_ref()],
+        aggregations: vec![
+            AliasedAggregation {
+                alias: "total".to_string(),
+                agg_expr: AggregationExpr::Function(AggregationFunctionApplication {
+                    function: AggregationFunction::Sum,
+                    distinct: false,
+                    arg: Box::new(Expression::FieldAccess(FieldAccess::new(
+                        Box::new(Expression::Reference(("foo", 0u16).into())),
+                        "b".into(),
+                    ))),
+                    separator: None,
+                }),
+            },
+            AliasedAggregation {
+                alias: "mean".to_string(),
+                agg_expr: AggregationExpr::Function(AggregationFunctionApplication {
+                    function: AggregationFunction::Avg,
+                    distinct: false,
+                    arg: Box::new(Expression::FieldAccess(FieldAccess::new(
+                        Box::new(Expression::Reference(("foo", 0u16).into())),
+                        "b".into(),
+                    ))),
+                    separator: None,
                 }),
             },
         ],
         cache: SchemaCache::new(),
         scope: 0,
     }),
+    schema_env = map! {
+        ("foo", 0u16).into() => Schema::Document(Document {
+            keys: map! {
+                "a".into() => Schema::Atomic(Atomic::String),
+                "b".into() => Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Null),
+                ]),
+            },
+            required: set! { "a".into(), "b".into() },
+            additional_properties: false,
+            ..Default::default()
+        }),
+    },
     catalog = Catalog::new(map! {
         Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
     }),
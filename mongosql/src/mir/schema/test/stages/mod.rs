@@ -115,9 +115,12 @@ mod limit {
 
 mod project {
     use crate::{
-        catalog::Namespace,
+        catalog::{Catalog, Namespace},
         map,
-        mir::{schema::SchemaCache, *},
+        mir::{
+            schema::{Error as mir_error, SchemaCache},
+            *,
+        },
         schema::{Atomic, Document, ResultSet, Schema, ANY_DOCUMENT},
         set, test_schema, unchecked_unique_linked_hash_map,
         util::mir_collection,
@@ -233,6 +236,75 @@ mod project {
             Namespace {db: "db".into(), collection: "foo".into()} => ANY_DOCUMENT.clone(),
         }),
     );
+
+    test_schema!(
+        project_array_literal_exceeding_max_depth,
+        expected_error_code = 1023,
+        expected = Err(mir_error::MaxSchemaDepthExceeded),
+        input = Stage::Project(Project {
+            is_add_fields: false,
+            source: mir_collection("db", "foo"),
+            expression: map! {
+                Key::bot(0) => Expression::Document(unchecked_unique_linked_hash_map! {
+                    "a".into() => Expression::Array(vec![Expression::Reference(("a", 0u16).into())].into()),
+                }.into()),
+            },
+            cache: SchemaCache::new(),
+        }),
+        schema_env = map! {("a", 0u16).into() =>
+            (1..crate::schema::MAX_DEPTH).fold(Schema::Atomic(Atomic::Integer), |s, _| {
+                Schema::Array(Box::new(s))
+            }),
+        },
+        catalog = Catalog::new(map! {
+            Namespace {db: "db".into(), collection: "foo".into()} => ANY_DOCUMENT.clone(),
+        }),
+    );
+
+    fn project_missing_field() -> Stage {
+        Stage::Project(Project {
+            is_add_fields: false,
+            source: mir_collection("db", "foo"),
+            expression: map! {
+                Key::bot(0) => Expression::Document(unchecked_unique_linked_hash_map! {
+                    "x".into() => Expression::FieldAccess(FieldAccess::new(
+                        Box::new(Expression::Reference(("foo", 0u16).into())),
+                        "m".into(),
+                    )),
+                }.into()),
+            },
+            cache: SchemaCache::new(),
+        })
+    }
+
+    fn missing_field_catalog() -> Catalog {
+        Catalog::new(map! {
+            Namespace {db: "db".into(), collection: "foo".into()} => Schema::Document(Document {
+                keys: map! {
+                    "m".into() => Schema::Missing,
+                },
+                required: set! {},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        })
+    }
+
+    test_schema!(
+        always_missing_column_is_error_in_strict_mode,
+        expected_error_code = 1021,
+        expected = Err(mir_error::MissingOutputColumn("x".into())),
+        input = project_missing_field(),
+        catalog = missing_field_catalog(),
+    );
+
+    test_schema!(
+        always_missing_column_is_allowed_in_relaxed_mode,
+        expected_pat = Ok(ResultSet { .. }),
+        input = project_missing_field(),
+        catalog = missing_field_catalog(),
+        schema_checking_mode = SchemaCheckingMode::Relaxed,
+    );
 }
 
 mod offset {
@@ -341,3 +413,92 @@ mod offset {
         }),
     );
 }
+
+mod limit_offset_filter {
+    use crate::{
+        mir::{
+            schema::{test::test_document_a, SchemaCache},
+            *,
+        },
+        schema::ResultSet,
+        test_schema,
+    };
+
+    fn array_of_five() -> Box<Stage> {
+        Box::new(Stage::Array(ArraySource {
+            array: vec![
+                test_document_a(),
+                test_document_a(),
+                test_document_a(),
+                test_document_a(),
+                test_document_a(),
+            ],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        }))
+    }
+
+    fn filter(source: Box<Stage>) -> Box<Stage> {
+        Box::new(Stage::Filter(Filter {
+            source,
+            condition: Expression::Literal(LiteralValue::Boolean(true)),
+            cache: SchemaCache::new(),
+        }))
+    }
+
+    fn offset(source: Box<Stage>, offset: i64) -> Box<Stage> {
+        Box::new(Stage::Offset(Offset {
+            source,
+            offset,
+            cache: SchemaCache::new(),
+        }))
+    }
+
+    fn limit(source: Box<Stage>, limit: u64) -> Stage {
+        Stage::Limit(Limit {
+            source,
+            limit,
+            cache: SchemaCache::new(),
+        })
+    }
+
+    test_schema!(
+        offset_then_limit_keeps_exact_size,
+        expected_pat = Ok(ResultSet {
+            min_size: 2,
+            max_size: Some(2),
+            ..
+        }),
+        input = limit(offset(array_of_five(), 1), 2),
+    );
+
+    test_schema!(
+        filter_then_offset_then_limit,
+        expected_pat = Ok(ResultSet {
+            min_size: 0,
+            max_size: Some(2),
+            ..
+        }),
+        input = limit(offset(filter(array_of_five()), 1), 2),
+    );
+
+    test_schema!(
+        filter_then_offset_leaves_fewer_rows_than_limit,
+        expected_pat = Ok(ResultSet {
+            min_size: 0,
+            max_size: Some(1),
+            ..
+        }),
+        input = limit(offset(filter(array_of_five()), 4), 2),
+    );
+
+    test_schema!(
+        filter_then_offset_past_end_then_limit,
+        expected_pat = Ok(ResultSet {
+            min_size: 0,
+            max_size: Some(0),
+            ..
+        }),
+        input = limit(offset(filter(array_of_five()), 10), 2),
+    );
+}
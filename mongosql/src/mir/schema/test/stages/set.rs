@@ -1,4 +1,5 @@
 use crate::{
+    catalog::Namespace,
     map,
     mir::{
         schema::{
@@ -10,7 +11,7 @@ use crate::{
         },
         *,
     },
-    schema::{ResultSet, Schema},
+    schema::{ResultSet, Schema, ANY_DOCUMENT},
     set, test_schema,
 };
 
@@ -155,3 +156,125 @@ test_schema!(
         cache: SchemaCache::new(),
     }),
 );
+
+fn project_to_bottom(array: Vec<Expression>, alias: &str) -> Stage {
+    Stage::Project(Project {
+        is_add_fields: false,
+        source: Box::new(Stage::Array(ArraySource {
+            array,
+            alias: alias.into(),
+            cache: SchemaCache::new(),
+        })),
+        expression: map! {
+            binding_tuple::Key::bot(0u16) => Expression::Reference((alias, 0u16).into()),
+        },
+        cache: SchemaCache::new(),
+    })
+}
+
+test_schema!(
+    set_unionall_bottom_datasources_unioned,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            binding_tuple::Key::bot(0u16) => Schema::AnyOf(set![
+                TEST_DOCUMENT_SCHEMA_A.clone(),
+                TEST_DOCUMENT_SCHEMA_B.clone(),
+            ]),
+        },
+        min_size: 2,
+        max_size: Some(2),
+    }),
+    input = Stage::Set(Set {
+        operation: SetOperation::UnionAll,
+        left: Box::new(project_to_bottom(vec![test_document_a()], "foo")),
+        right: Box::new(project_to_bottom(vec![test_document_b()], "bar")),
+        cache: SchemaCache::new(),
+    }),
+);
+
+test_schema!(
+    set_unionall_identical_document_shapes,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![TEST_DOCUMENT_SCHEMA_A.clone()]),
+            ]),
+        },
+        min_size: 3,
+        max_size: Some(3),
+    }),
+    input = Stage::Set(Set {
+        operation: SetOperation::UnionAll,
+        left: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a(), test_document_a()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        right: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        cache: SchemaCache::new(),
+    }),
+);
+
+test_schema!(
+    set_unionall_differing_document_shapes,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::AnyOf(set![
+                Schema::AnyOf(set![TEST_DOCUMENT_SCHEMA_A.clone()]),
+                Schema::AnyOf(set![
+                    TEST_DOCUMENT_SCHEMA_B.clone(),
+                    TEST_DOCUMENT_SCHEMA_C.clone(),
+                ]),
+            ]),
+        },
+        min_size: 3,
+        max_size: Some(3),
+    }),
+    input = Stage::Set(Set {
+        operation: SetOperation::UnionAll,
+        left: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        right: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_b(), test_document_c()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        cache: SchemaCache::new(),
+    }),
+);
+
+test_schema!(
+    set_unionall_unbounded_side_has_no_max_size,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("bar", 0u16).into() => ANY_DOCUMENT.clone(),
+            ("foo", 0u16).into() => Schema::AnyOf(set![TEST_DOCUMENT_SCHEMA_A.clone()]),
+        },
+        min_size: 1,
+        max_size: None,
+    }),
+    input = Stage::Set(Set {
+        operation: SetOperation::UnionAll,
+        left: Box::new(Stage::Collection(Collection {
+            db: "test".into(),
+            collection: "bar".into(),
+            cache: SchemaCache::new(),
+        })),
+        right: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        cache: SchemaCache::new(),
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
+    }),
+);
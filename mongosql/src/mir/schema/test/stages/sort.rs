@@ -2,7 +2,10 @@ use crate::{
     catalog::Namespace,
     map,
     mir::{
-        schema::{Error as mir_error, SchemaCache},
+        schema::{
+            test::{test_document_a, TEST_DOCUMENT_SCHEMA_A},
+            Error as mir_error, SchemaCache,
+        },
         *,
     },
     schema::*,
@@ -138,3 +141,71 @@ test_schema!(
         Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
     }),
 );
+
+test_schema!(
+    sort_key_datasource_not_found,
+    expected_error_code = 1000,
+    expected = Err(mir_error::DatasourceNotFoundInSchemaEnv(
+        ("abc", 0u16).into()
+    )),
+    input = Stage::Sort(Sort {
+        source: Box::new(Stage::Collection(Collection {
+            db: "test".into(),
+            collection: "bar".into(),
+            cache: SchemaCache::new(),
+        })),
+        specs: vec![SortSpecification::Asc(FieldPath {
+            key: ("abc", 0u16).into(),
+            fields: vec!["a".to_string()],
+            is_nullable: false,
+        })],
+        cache: SchemaCache::new(),
+    }),
+    catalog = Catalog::new(map! {
+        Namespace {db: "test".into(), collection: "bar".into()} => ANY_DOCUMENT.clone(),
+    }),
+);
+
+test_schema!(
+    sort_preserves_array_datasource_sizes,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::AnyOf(set![TEST_DOCUMENT_SCHEMA_A.clone()]),
+        },
+        min_size: 3,
+        max_size: Some(3),
+    }),
+    input = Stage::Sort(Sort {
+        source: Box::new(Stage::Array(ArraySource {
+            array: vec![test_document_a(), test_document_a(), test_document_a()],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        specs: vec![SortSpecification::Asc(FieldPath {
+            key: ("foo", 0u16).into(),
+            fields: vec!["a".to_string()],
+            is_nullable: false,
+        })],
+        cache: SchemaCache::new(),
+    }),
+);
+
+test_schema!(
+    sort_preserves_empty_array_datasource_sizes,
+    expected = Ok(ResultSet {
+        schema_env: map! {
+            ("foo", 0u16).into() => Schema::AnyOf(set![]),
+        },
+        min_size: 0,
+        max_size: Some(0),
+    }),
+    input = Stage::Sort(Sort {
+        source: Box::new(Stage::Array(ArraySource {
+            array: vec![],
+            alias: "foo".into(),
+            cache: SchemaCache::new(),
+        })),
+        specs: vec![],
+        cache: SchemaCache::new(),
+    }),
+);
@@ -228,6 +228,20 @@ mod invalid_comparison {
     }
 }
 
+mod incompatible_case_branch_types {
+    use crate::schema::{Atomic, Schema, ANY_DOCUMENT};
+
+    test_user_error_messages! {
+        incompatible_case_branch_types,
+        input = Error::IncompatibleCaseBranchTypes(
+            "SearchedCase",
+            Schema::Atomic(Atomic::String),
+            ANY_DOCUMENT.clone(),
+        ),
+        expected = "Invalid use of `SearchedCase` due to incompatible result types: `string` cannot be unified with `object type`."
+    }
+}
+
 mod sort_key_comparable {
     use crate::{
         schema::{Atomic, Schema},
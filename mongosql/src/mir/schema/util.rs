@@ -145,6 +145,86 @@ pub fn set_field_schema(
     }
 }
 
+/// narrow_schema_env_for_condition tightens the schemas of fields that a Filter condition
+/// guarantees to be non-nullish or of a particular type. Only `IS NOT NULL` and `IS <type>`
+/// predicates over field paths, optionally combined with AND, are considered. Any other
+/// condition leaves the schema environment unchanged.
+pub(crate) fn narrow_schema_env_for_condition(
+    condition: &Expression,
+    schema_env: SchemaEnvironment,
+) -> SchemaEnvironment {
+    match condition {
+        Expression::ScalarFunction(ScalarFunctionApplication {
+            function: ScalarFunction::And,
+            args,
+            ..
+        }) => args.iter().fold(schema_env, |schema_env, arg| {
+            narrow_schema_env_for_condition(arg, schema_env)
+        }),
+        Expression::ScalarFunction(ScalarFunctionApplication {
+            function: ScalarFunction::Not,
+            args,
+            ..
+        }) => match args.as_slice() {
+            [Expression::Is(IsExpr {
+                expr,
+                target_type: TypeOrMissing::Type(Type::Null),
+            })] => narrow_field_schema(schema_env, expr, Schema::subtract_nullish),
+            _ => schema_env,
+        },
+        Expression::Is(IsExpr {
+            expr,
+            target_type: TypeOrMissing::Type(ty),
+        }) if *ty != Type::Null && *ty != Type::Undefined => {
+            let type_schema = Schema::from(*ty);
+            narrow_field_schema(schema_env, expr, |field_schema| {
+                field_schema.intersection(&type_schema)
+            })
+        }
+        _ => schema_env,
+    }
+}
+
+/// narrow_field_schema applies `narrow` to the schema of the field path described by `expr`
+/// and marks the field as required. The schema environment is returned unchanged if `expr`
+/// is not a field path into a datasource in `schema_env`, if the field's schema is unknown
+/// (i.e. Any), or if narrowing would leave no possible values.
+fn narrow_field_schema(
+    mut schema_env: SchemaEnvironment,
+    expr: &Expression,
+    narrow: impl Fn(Schema) -> Schema,
+) -> SchemaEnvironment {
+    let path = match FieldPath::try_from(expr) {
+        Ok(path) => path,
+        Err(_) => return schema_env,
+    };
+    let datasource_schema = match schema_env.get(&path.key) {
+        Some(s) => s.clone(),
+        None => return schema_env,
+    };
+    let field_schema = path
+        .fields
+        .iter()
+        .fold(datasource_schema.clone(), |s, field| {
+            Expression::get_field_schema(&s, field)
+        });
+    if field_schema == Schema::Any {
+        return schema_env;
+    }
+    let narrowed_schema = Schema::simplify(&narrow(field_schema));
+    if narrowed_schema == Schema::Unsat {
+        return schema_env;
+    }
+    let updated_datasource_schema = set_field_schema(
+        datasource_schema,
+        &mut path.fields.into_iter().rev().collect(),
+        narrowed_schema,
+        true,
+    );
+    schema_env.insert(path.key, updated_datasource_schema);
+    schema_env
+}
+
 // When constructing the schema environment for Group and AddFields, calls to union() and
 // union_schema_for_datasource() combine schemas with the same Key under an AnyOf. However, we want
 // group keys and aggregations under bottom to be combined under a single document, and we want
@@ -6,6 +6,12 @@ pub struct SqlOptions {
     pub exclude_namespaces: ExcludeNamespacesOption,
     pub schema_checking_mode: SchemaCheckingMode,
     pub allow_order_by_missing_columns: bool,
+    /// When true, inner joins without a condition between aggregations over the same
+    /// collection are generated as a single $facet stage rather than a $join.
+    pub facet_sibling_aggregations: bool,
+    /// When true, operator applications repeated within a single projected expression are
+    /// evaluated once and bound to a $let variable.
+    pub hoist_common_subexpressions: bool,
 }
 
 impl SqlOptions {
@@ -18,6 +24,8 @@ impl SqlOptions {
             exclude_namespaces,
             schema_checking_mode,
             allow_order_by_missing_columns: true,
+            facet_sibling_aggregations: false,
+            hoist_common_subexpressions: false,
         }
     }
 }
@@ -116,7 +116,9 @@ lazy_static! {
         ("LEFT_CURLY_BRACE", "{"),
         ("LT", "<"),
         ("LTE", "<="),
+        ("MOD", "%"),
         ("NEQ", "<>"),
+        ("NOT_ILIKE", "NOT ILIKE"),
         ("NOT_IN", "NOT IN"),
         ("NOT_LIKE", "NOT LIKE"),
         ("RIGHT_BRACKET", "]"),
@@ -198,7 +198,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()))
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()))
                 )])
             },
             from_clause: None,
@@ -218,7 +218,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -238,7 +238,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -258,7 +258,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("1 + 2".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("1 + 2".into()),)
                 )])
             },
             from_clause: None,
@@ -278,7 +278,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("fo`o``".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("fo`o``".into()),)
                 )])
             },
             from_clause: None,
@@ -298,9 +298,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier(
-                        r#"fo"o"""#.to_string()
-                    ),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier(r#"fo"o"""#.into()),)
                 )])
             },
             from_clause: None,
@@ -320,9 +318,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier(
-                        r#"fo""o"#.to_string()
-                    ),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier(r#"fo""o"#.into()),)
                 )])
             },
             from_clause: None,
@@ -342,7 +338,7 @@ mod select {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("fo``o".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("fo``o".into()),)
                 )])
             },
             from_clause: None,
@@ -384,9 +380,7 @@ mod query {
                     select_clause: SelectClause {
                         set_quantifier: SetQuantifier::All,
                         body: SelectBody::Standard(vec![SelectExpression::Expression(
-                            OptionallyAliasedExpr::Unaliased(Expression::Identifier(
-                                "a".to_string()
-                            ),)
+                            OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".into()),)
                         )])
                     },
                     from_clause: None,
@@ -402,9 +396,7 @@ mod query {
                     select_clause: SelectClause {
                         set_quantifier: SetQuantifier::All,
                         body: SelectBody::Standard(vec![SelectExpression::Expression(
-                            OptionallyAliasedExpr::Unaliased(Expression::Identifier(
-                                "b".to_string()
-                            ),)
+                            OptionallyAliasedExpr::Unaliased(Expression::Identifier("b".into()),)
                         )])
                     },
                     from_clause: None,
@@ -421,7 +413,7 @@ mod query {
                 select_clause: SelectClause {
                     set_quantifier: SetQuantifier::All,
                     body: SelectBody::Standard(vec![SelectExpression::Expression(
-                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("c".to_string()),)
+                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("c".into()),)
                     )])
                 },
                 from_clause: None,
@@ -574,6 +566,17 @@ mod operator {
         expected = false,
         input = "select col1 NOT LIKE 'blah' ESCAPE 'blah'"
     );
+    parsable!(ilike, expected = true, input = "select col1 ILIKE 'A%'");
+    parsable!(
+        not_ilike_escape,
+        expected = true,
+        input = "select col1 NOT  ILIKE '%a!% b' ESCAPE '!'"
+    );
+    parsable!(
+        ilike_escape_multichar,
+        expected = false,
+        input = "select col1 ILIKE 'blah' ESCAPE 'blah'"
+    );
     parsable!(
         where_is,
         expected = true,
@@ -589,7 +592,7 @@ mod operator {
         is_missing_ast,
         method = parse_expression,
         expected = Expression::Is(IsExpr {
-            expr: Box::new(Expression::Identifier("a".to_string())),
+            expr: Box::new(Expression::Identifier("a".into())),
             target_type: TypeOrMissing::Missing,
         }),
         input = "a IS MISSING",
@@ -615,6 +618,27 @@ mod operator {
         }),
         input = "1 IS NOT NUMBER",
     );
+    validate_ast!(
+        is_true_expr,
+        method = parse_expression,
+        expected = Expression::IsTruthValue(IsTruthValueExpr {
+            expr: Box::new(Expression::Identifier("a".into())),
+            value: TruthValue::True,
+        }),
+        input = "a IS TRUE",
+    );
+    validate_ast!(
+        is_not_unknown_expr,
+        method = parse_expression,
+        expected = Expression::Unary(UnaryExpr {
+            op: UnaryOp::Not,
+            expr: Box::new(Expression::IsTruthValue(IsTruthValueExpr {
+                expr: Box::new(Expression::Identifier("a".into())),
+                value: TruthValue::Unknown,
+            })),
+        }),
+        input = "a IS NOT unknown",
+    );
     parsable!(
         between_invalid_binary_op,
         expected = false,
@@ -635,9 +659,10 @@ mod operator {
         between_ast,
         method = parse_expression,
         expected = Expression::Between(BetweenExpr {
-            arg: Box::new(Expression::Identifier("a".to_string())),
-            min: Box::new(Expression::Identifier("b".to_string())),
-            max: Box::new(Expression::Identifier("c".to_string())),
+            arg: Box::new(Expression::Identifier("a".into())),
+            min: Box::new(Expression::Identifier("b".into())),
+            max: Box::new(Expression::Identifier("c".into())),
+            symmetric: false,
         }),
         input = "a between b and c",
     );
@@ -648,14 +673,27 @@ mod operator {
         expected = Expression::Unary(UnaryExpr {
             op: UnaryOp::Not,
             expr: Box::new(Expression::Between(BetweenExpr {
-                arg: Box::new(Expression::Identifier("a".to_string())),
-                min: Box::new(Expression::Identifier("b".to_string())),
-                max: Box::new(Expression::Identifier("c".to_string())),
+                arg: Box::new(Expression::Identifier("a".into())),
+                min: Box::new(Expression::Identifier("b".into())),
+                max: Box::new(Expression::Identifier("c".into())),
+                symmetric: false,
             }))
         }),
         input = "a not between b and c",
     );
 
+    validate_ast!(
+        between_symmetric_ast,
+        method = parse_expression,
+        expected = Expression::Between(BetweenExpr {
+            arg: Box::new(Expression::Identifier("a".into())),
+            min: Box::new(Expression::Identifier("b".into())),
+            max: Box::new(Expression::Identifier("c".into())),
+            symmetric: true,
+        }),
+        input = "a between symmetric b and c",
+    );
+
     validate_ast!(
         case_multiple_when_branches_ast,
         method = parse_expression,
@@ -664,22 +702,22 @@ mod operator {
             when_branch: vec![
                 WhenBranch {
                     when: Box::new(Expression::Binary(BinaryExpr {
-                        left: Box::new(Expression::Identifier("a".to_string())),
+                        left: Box::new(Expression::Identifier("a".into())),
                         op: BinaryOp::Comparison(ComparisonOp::Eq),
-                        right: Box::new(Expression::Identifier("b".to_string()))
+                        right: Box::new(Expression::Identifier("b".into()))
                     })),
-                    then: Box::new(Expression::Identifier("a".to_string()))
+                    then: Box::new(Expression::Identifier("a".into()))
                 },
                 WhenBranch {
                     when: Box::new(Expression::Binary(BinaryExpr {
-                        left: Box::new(Expression::Identifier("c".to_string())),
+                        left: Box::new(Expression::Identifier("c".into())),
                         op: BinaryOp::Comparison(ComparisonOp::Eq),
-                        right: Box::new(Expression::Identifier("d".to_string()))
+                        right: Box::new(Expression::Identifier("d".into()))
                     })),
-                    then: Box::new(Expression::Identifier("c".to_string()))
+                    then: Box::new(Expression::Identifier("c".into()))
                 }
             ],
-            else_branch: Some(Box::new(Expression::Identifier("e".to_string())))
+            else_branch: Some(Box::new(Expression::Identifier("e".into())))
         }),
         input = "case when a=b then a when c=d then c else e end",
     );
@@ -688,16 +726,16 @@ mod operator {
         case_multiple_exprs_ast,
         method = parse_expression,
         expected = Expression::Case(CaseExpr {
-            expr: Some(Box::new(Expression::Identifier("a".to_string()))),
+            expr: Some(Box::new(Expression::Identifier("a".into()))),
             when_branch: vec![WhenBranch {
                 when: Box::new(Expression::Binary(BinaryExpr {
-                    left: Box::new(Expression::Identifier("a".to_string())),
+                    left: Box::new(Expression::Identifier("a".into())),
                     op: BinaryOp::Comparison(ComparisonOp::Eq),
-                    right: Box::new(Expression::Identifier("b".to_string()))
+                    right: Box::new(Expression::Identifier("b".into()))
                 })),
-                then: Box::new(Expression::Identifier("a".to_string()))
+                then: Box::new(Expression::Identifier("a".into()))
             }],
-            else_branch: Some(Box::new(Expression::Identifier("c".to_string())))
+            else_branch: Some(Box::new(Expression::Identifier("c".into())))
         }),
         input = "case a when a=b then a else c end",
     );
@@ -711,12 +749,13 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Between(BetweenExpr {
             arg: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Comparison(ComparisonOp::Eq),
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
-            min: Box::new(Expression::Identifier("c".to_string())),
-            max: Box::new(Expression::Identifier("d".to_string()))
+            min: Box::new(Expression::Identifier("c".into())),
+            max: Box::new(Expression::Identifier("d".into())),
+            symmetric: false,
         }),
         input = "a = b BETWEEN c AND d",
     );
@@ -726,14 +765,15 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Between(BetweenExpr {
-                arg: Box::new(Expression::Identifier("a".to_string())),
-                min: Box::new(Expression::Identifier("b".to_string())),
-                max: Box::new(Expression::Identifier("c".to_string()))
+                arg: Box::new(Expression::Identifier("a".into())),
+                min: Box::new(Expression::Identifier("b".into())),
+                max: Box::new(Expression::Identifier("c".into())),
+                symmetric: false,
             })),
             op: BinaryOp::In,
             right: Box::new(Expression::Tuple(vec![
-                Expression::Identifier("x".to_string()),
-                Expression::Identifier("y".to_string())
+                Expression::Identifier("x".into()),
+                Expression::Identifier("y".into())
             ]))
         }),
         input = "a BETWEEN b AND c IN (x, y)",
@@ -743,13 +783,13 @@ mod operator_precedence {
         in_binds_more_tightly_than_like,
         method = parse_expression,
         expected = Expression::Like(LikeExpr {
-            expr: Box::new(Expression::Identifier("a".to_string())),
+            expr: Box::new(Expression::Identifier("a".into())),
             pattern: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("x".to_string())),
+                left: Box::new(Expression::Identifier("x".into())),
                 op: BinaryOp::In,
                 right: Box::new(Expression::Tuple(vec![
-                    Expression::Identifier("y".to_string()),
-                    Expression::Identifier("z".to_string()),
+                    Expression::Identifier("y".into()),
+                    Expression::Identifier("z".into()),
                 ]))
             })),
             escape: None
@@ -757,13 +797,24 @@ mod operator_precedence {
         input = "a LIKE x IN (y, z)",
     );
 
+    validate_ast!(
+        ilike_with_escape_is_like,
+        method = parse_expression,
+        expected = Expression::Like(LikeExpr {
+            expr: Box::new(Expression::Identifier("a".into())),
+            pattern: Box::new(Expression::StringConstructor("a!_b".to_string())),
+            escape: Some('!'),
+        }),
+        input = "a ILIKE 'a!_b' ESCAPE '!'",
+    );
+
     validate_ast!(
         like_binds_more_tightly_than_is,
         method = parse_expression,
         expected = Expression::Is(IsExpr {
             expr: Box::new(Expression::Like(LikeExpr {
-                expr: Box::new(Expression::Identifier("a".to_string())),
-                pattern: Box::new(Expression::Identifier("b".to_string())),
+                expr: Box::new(Expression::Identifier("a".into())),
+                pattern: Box::new(Expression::Identifier("b".into())),
                 escape: None,
             })),
             target_type: TypeOrMissing::Type(Type::Null)
@@ -777,7 +828,7 @@ mod operator_precedence {
         expected = Expression::Unary(UnaryExpr {
             op: UnaryOp::Not,
             expr: Box::new(Expression::Is(IsExpr {
-                expr: Box::new(Expression::Identifier("a".to_string())),
+                expr: Box::new(Expression::Identifier("a".into())),
                 target_type: TypeOrMissing::Type(Type::Null)
             }))
         }),
@@ -790,10 +841,10 @@ mod operator_precedence {
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Unary(UnaryExpr {
                 op: UnaryOp::Not,
-                expr: Box::new(Expression::Identifier("a".to_string())),
+                expr: Box::new(Expression::Identifier("a".into())),
             })),
             op: BinaryOp::And,
-            right: Box::new(Expression::Identifier("b".to_string()))
+            right: Box::new(Expression::Identifier("b".into()))
         }),
         input = "NOT a AND b",
     );
@@ -803,12 +854,12 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::And,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
             op: BinaryOp::Or,
-            right: Box::new(Expression::Identifier("c".to_string()))
+            right: Box::new(Expression::Identifier("c".into()))
         }),
         input = "a AND b OR c",
     );
@@ -819,9 +870,9 @@ mod operator_precedence {
         expected = Expression::Unary(UnaryExpr {
             op: UnaryOp::Not,
             expr: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Mul,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             }))
         }),
         input = "NOT a * b",
@@ -831,11 +882,11 @@ mod operator_precedence {
         unary_binds_more_tightly_than_binary_sub,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
-            left: Box::new(Expression::Identifier("b".to_string())),
+            left: Box::new(Expression::Identifier("b".into())),
             op: BinaryOp::Sub,
             right: Box::new(Expression::Unary(UnaryExpr {
                 op: UnaryOp::Neg,
-                expr: Box::new(Expression::Identifier("a".to_string()))
+                expr: Box::new(Expression::Identifier("a".into()))
             }))
         }),
         input = "b- -a",
@@ -847,10 +898,10 @@ mod operator_precedence {
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Unary(UnaryExpr {
                 op: UnaryOp::Neg,
-                expr: Box::new(Expression::Identifier("a".to_string()))
+                expr: Box::new(Expression::Identifier("a".into()))
             })),
             op: BinaryOp::Div,
-            right: Box::new(Expression::Identifier("b".to_string()))
+            right: Box::new(Expression::Identifier("b".into()))
         }),
         input = "-a/b",
     );
@@ -860,15 +911,15 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Mul,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
             op: BinaryOp::Add,
             right: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("x".to_string())),
+                left: Box::new(Expression::Identifier("x".into())),
                 op: BinaryOp::Mul,
-                right: Box::new(Expression::Identifier("y".to_string()))
+                right: Box::new(Expression::Identifier("y".into()))
             }))
         }),
         input = "a*b+x*y",
@@ -879,31 +930,50 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Div,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
             op: BinaryOp::Sub,
             right: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("x".to_string())),
+                left: Box::new(Expression::Identifier("x".into())),
                 op: BinaryOp::Div,
-                right: Box::new(Expression::Identifier("y".to_string()))
+                right: Box::new(Expression::Identifier("y".into()))
             }))
         }),
         input = "a/b-x/y",
     );
 
+    validate_ast!(
+        binary_mod_sub_ast,
+        method = parse_expression,
+        expected = Expression::Binary(BinaryExpr {
+            left: Box::new(Expression::Binary(BinaryExpr {
+                left: Box::new(Expression::Identifier("a".into())),
+                op: BinaryOp::Mod,
+                right: Box::new(Expression::Identifier("b".into()))
+            })),
+            op: BinaryOp::Sub,
+            right: Box::new(Expression::Binary(BinaryExpr {
+                left: Box::new(Expression::Identifier("x".into())),
+                op: BinaryOp::Mod,
+                right: Box::new(Expression::Identifier("y".into()))
+            }))
+        }),
+        input = "a%b-x%y",
+    );
+
     validate_ast!(
         binary_add_concat_ast,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Add,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
             op: BinaryOp::Concat,
-            right: Box::new(Expression::Identifier("c".to_string()))
+            right: Box::new(Expression::Identifier("c".into()))
         }),
         input = "a+b||c",
     );
@@ -912,12 +982,12 @@ mod operator_precedence {
         binary_concat_compare_ast,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
-            left: Box::new(Expression::Identifier("c".to_string())),
+            left: Box::new(Expression::Identifier("c".into())),
             op: BinaryOp::Comparison(ComparisonOp::Gt),
             right: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Concat,
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             }))
         }),
         input = "c>a||b",
@@ -928,12 +998,12 @@ mod operator_precedence {
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
             left: Box::new(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Comparison(ComparisonOp::Lt),
-                right: Box::new(Expression::Identifier("b".to_string()))
+                right: Box::new(Expression::Identifier("b".into()))
             })),
             op: BinaryOp::And,
-            right: Box::new(Expression::Identifier("c".to_string()))
+            right: Box::new(Expression::Identifier("c".into()))
         }),
         input = "a<b AND c",
     );
@@ -942,11 +1012,12 @@ mod operator_precedence {
         cast_precedence_binary,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
-            left: Box::new(Expression::Identifier("a".to_string())),
+            left: Box::new(Expression::Identifier("a".into())),
             op: BinaryOp::Mul,
             right: Box::new(Expression::Cast(CastExpr {
-                expr: Box::new(Expression::Identifier("b".to_string())),
+                expr: Box::new(Expression::Identifier("b".into())),
                 to: Type::Int32,
+                format: None,
                 on_null: None,
                 on_error: None,
             }))
@@ -959,8 +1030,9 @@ mod operator_precedence {
         expected = Expression::Unary(UnaryExpr {
             op: UnaryOp::Not,
             expr: Box::new(Expression::Cast(CastExpr {
-                expr: Box::new(Expression::Identifier("a".to_string())),
+                expr: Box::new(Expression::Identifier("a".into())),
                 to: Type::Boolean,
+                format: None,
                 on_null: None,
                 on_error: None,
             }))
@@ -1038,15 +1110,13 @@ mod group_by {
             where_clause: None,
             group_by_clause: Some(GroupByClause {
                 keys: vec![
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".to_string())),
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("b".to_string()))
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".into())),
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("b".into()))
                 ],
                 aggregations: vec![AliasedExpr {
                     expr: Expression::Function(FunctionExpr {
                         function: FunctionName::Sum,
-                        args: FunctionArguments::Args(vec![Expression::Identifier(
-                            "b".to_string()
-                        )]),
+                        args: FunctionArguments::Args(vec![Expression::Identifier("b".into())]),
                         set_quantifier: Some(SetQuantifier::Distinct),
                     }),
                     alias: "c".to_string(),
@@ -1088,14 +1158,14 @@ mod having {
             where_clause: None,
             group_by_clause: Some(GroupByClause {
                 keys: vec![OptionallyAliasedExpr::Unaliased(Expression::Identifier(
-                    "a".to_string()
+                    "a".into()
                 ),)],
                 aggregations: vec![]
             }),
             having_clause: Some(Expression::Binary(BinaryExpr {
                 left: Box::new(Expression::Function(FunctionExpr {
                     function: FunctionName::Sum,
-                    args: FunctionArguments::Args(vec![Expression::Identifier("a".to_string())]),
+                    args: FunctionArguments::Args(vec![Expression::Identifier("a".into())]),
                     set_quantifier: Some(SetQuantifier::Distinct),
                 })),
                 op: BinaryOp::Comparison(ComparisonOp::Gt),
@@ -1155,7 +1225,7 @@ mod order_by {
             having_clause: None,
             order_by_clause: Some(OrderByClause {
                 sort_specs: vec![SortSpec {
-                    key: SortKey::Simple(Expression::Identifier("a".to_string())),
+                    key: SortKey::Simple(Expression::Identifier("a".into())),
                     direction: SortDirection::Asc
                 }]
             }),
@@ -1164,6 +1234,38 @@ mod order_by {
         }),
         input = "select * order by a",
     );
+    validate_ast!(
+        computed_sort_key,
+        method = parse_query,
+        expected = Query::Select(SelectQuery {
+            select_clause: SelectClause {
+                set_quantifier: SetQuantifier::All,
+                body: SelectBody::Standard(vec![SelectExpression::Star])
+            },
+            from_clause: None,
+            where_clause: None,
+            group_by_clause: None,
+            having_clause: None,
+            order_by_clause: Some(OrderByClause {
+                sort_specs: vec![SortSpec {
+                    key: SortKey::Simple(Expression::Binary(BinaryExpr {
+                        left: Box::new(Expression::Identifier("b".into())),
+                        op: BinaryOp::Add,
+                        right: Box::new(Expression::Identifier("c".into())),
+                    })),
+                    direction: SortDirection::Desc
+                }]
+            }),
+            limit: None,
+            offset: None,
+        }),
+        input = "select * order by b + c desc",
+    );
+    parsable!(
+        string_literal_sort_key,
+        expected = false,
+        input = "select * order by 'a'"
+    );
 }
 
 mod limit_offset {
@@ -1611,6 +1713,21 @@ mod scalar_function {
         expected = true,
         input = "select position(1*2 IN 2)"
     );
+    parsable!(
+        position_from,
+        expected = true,
+        input = "select position('b' IN 'abc' FROM 2)"
+    );
+    parsable!(
+        position_comma_with_start,
+        expected = true,
+        input = "select position('b', 'abc', 2)"
+    );
+    parsable!(
+        position_comma_too_many_args,
+        expected = false,
+        input = "select position('b', 'abc', 2, 3)"
+    );
     parsable!(
         position_is_op,
         expected = true,
@@ -1636,6 +1753,16 @@ mod scalar_function {
         expected = true,
         input = "select position(expected = true IN a < b)"
     );
+    parsable!(
+        position_comma,
+        expected = true,
+        input = "select position('b', 'abc')"
+    );
+    parsable!(
+        position_comma_binary_or,
+        expected = true,
+        input = "select position(a OR b, c)"
+    );
     parsable!(
         char_length,
         expected = true,
@@ -1682,6 +1809,11 @@ mod scalar_function {
         expected = true,
         input = "select extract(second from a)"
     );
+    parsable!(
+        extract_millisecond,
+        expected = true,
+        input = "select extract(millisecond from a)"
+    );
     parsable!(
         extract_week,
         expected = true,
@@ -1732,6 +1864,16 @@ mod scalar_function {
         expected = true,
         input = "select SUBSTRING(str, start)"
     );
+    parsable!(left, expected = true, input = "select left(str, 2)");
+    parsable!(right, expected = true, input = "select RIGHT(str, 2)");
+    parsable!(
+        left_in_join_condition,
+        expected = true,
+        input = "select * from foo left join bar on left(foo.a, 1) = right(bar.a, 1)"
+    );
+    parsable!(lpad, expected = true, input = "select lpad(str, 5, '*')");
+    parsable!(rpad, expected = true, input = "select RPAD(str, 5, '*')");
+    parsable!(instr, expected = true, input = "select instr(str, 'b', 2)");
     parsable!(fold_upper, expected = true, input = "select upper(a)");
     parsable!(fold_lower, expected = true, input = "select lower(a)");
     parsable!(
@@ -1817,6 +1959,20 @@ mod scalar_function {
         input = "select { FN CEILing(str) }"
     );
 
+    validate_ast!(
+        position_comma_ast,
+        method = parse_expression,
+        expected = Expression::Function(FunctionExpr {
+            function: FunctionName::Position,
+            args: FunctionArguments::Args(vec![
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into()),
+            ]),
+            set_quantifier: None,
+        }),
+        input = "position(a, b)",
+    );
+
     validate_ast!(
         position_ast,
         method = parse_expression,
@@ -1824,15 +1980,15 @@ mod scalar_function {
             function: FunctionName::Position,
             args: FunctionArguments::Args(vec![
                 Expression::Tuple(vec![Expression::Binary(BinaryExpr {
-                    left: Box::new(Expression::Identifier("a".to_string())),
+                    left: Box::new(Expression::Identifier("a".into())),
                     op: BinaryOp::Add,
                     right: Box::new(Expression::Binary(BinaryExpr {
-                        left: Box::new(Expression::Identifier("b".to_string())),
+                        left: Box::new(Expression::Identifier("b".into())),
                         op: BinaryOp::Mul,
-                        right: Box::new(Expression::Identifier("c".to_string()))
+                        right: Box::new(Expression::Identifier("c".into()))
                     }))
                 })]),
-                Expression::Identifier("d".to_string()),
+                Expression::Identifier("d".into()),
             ]),
             set_quantifier: None,
         }),
@@ -1843,19 +1999,122 @@ mod scalar_function {
         method = parse_expression,
         expected = Expression::Extract(ExtractExpr {
             extract_spec: DatePart::Year,
-            arg: Box::new(Expression::Identifier("a".to_string()))
+            arg: Box::new(Expression::Identifier("a".into()))
         }),
         input = "extract(year from a)",
     );
+    validate_ast!(
+        extract_epoch_ast,
+        method = parse_expression,
+        expected = Expression::Extract(ExtractExpr {
+            extract_spec: DatePart::Epoch,
+            arg: Box::new(Expression::Identifier("a".into()))
+        }),
+        input = "extract(epoch from a)",
+    );
+    validate_ast!(
+        ifnull_ast,
+        method = parse_expression,
+        expected = Expression::Function(FunctionExpr {
+            function: FunctionName::IfNull,
+            args: FunctionArguments::Args(vec![
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into())
+            ]),
+            set_quantifier: None,
+        }),
+        input = "ifnull(a, b)",
+    );
+    validate_ast!(
+        nvl_ast,
+        method = parse_expression,
+        expected = Expression::Function(FunctionExpr {
+            function: FunctionName::IfNull,
+            args: FunctionArguments::Args(vec![
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into())
+            ]),
+            set_quantifier: None,
+        }),
+        input = "NVL(a, b)",
+    );
+    validate_ast!(
+        isnull_ast,
+        method = parse_expression,
+        expected = Expression::Function(FunctionExpr {
+            function: FunctionName::IsNull,
+            args: FunctionArguments::Args(vec![
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into())
+            ]),
+            set_quantifier: None,
+        }),
+        input = "isNull(a, b)",
+    );
+    validate_ast!(
+        add_interval_ast,
+        method = parse_expression,
+        expected = Expression::Binary(BinaryExpr {
+            left: Box::new(Expression::Identifier("a".into())),
+            op: BinaryOp::Add,
+            right: Box::new(Expression::Interval(IntervalExpr {
+                amount: Box::new(Expression::Literal(Literal::Integer(3))),
+                date_part: DatePart::Day,
+            })),
+        }),
+        input = "a + INTERVAL 3 DAY",
+    );
+    validate_ast!(
+        sub_interval_ast,
+        method = parse_expression,
+        expected = Expression::Binary(BinaryExpr {
+            left: Box::new(Expression::Identifier("a".into())),
+            op: BinaryOp::Sub,
+            right: Box::new(Expression::Interval(IntervalExpr {
+                amount: Box::new(Expression::Literal(Literal::Integer(2))),
+                date_part: DatePart::Month,
+            })),
+        }),
+        input = "a - interval 2 month",
+    );
+    validate_ast!(
+        interval_is_not_reserved,
+        method = parse_expression,
+        expected = Expression::Binary(BinaryExpr {
+            left: Box::new(Expression::Identifier("interval".into())),
+            op: BinaryOp::Add,
+            right: Box::new(Expression::Literal(Literal::Integer(1))),
+        }),
+        input = "interval + 1",
+    );
+    parsable!(
+        interval_with_non_literal_amount,
+        expected = false,
+        input = "select a + INTERVAL n DAY"
+    );
+    parsable!(
+        interval_with_non_interval_keyword,
+        expected = false,
+        expected_error_tech_msg = "expected INTERVAL, found: `intervals`",
+        expected_error_code = 2000,
+        input = "select a + intervals 1 DAY"
+    );
+    parsable!(
+        interval_with_unsupported_date_part,
+        expected = false,
+        expected_error_tech_msg = "iso_week is not a valid INTERVAL date part",
+        expected_error_code = 2000,
+        input = "select a + INTERVAL 1 iso_week"
+    );
     validate_ast!(
         dateadd_ast,
         method = parse_expression,
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::DateAdd,
             args: FunctionArguments::Args(vec![
-                Expression::Identifier("year".to_string()),
+                Expression::Identifier("year".into()),
                 Expression::Literal(Literal::Integer(5)),
-                Expression::Identifier("a".to_string())
+                Expression::Identifier("a".into())
             ]),
             set_quantifier: None,
         }),
@@ -1867,9 +2126,9 @@ mod scalar_function {
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::DateDiff,
             args: FunctionArguments::Args(vec![
-                Expression::Identifier("year".to_string()),
-                Expression::Identifier("a".to_string()),
-                Expression::Identifier("b".to_string()),
+                Expression::Identifier("year".into()),
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into()),
             ]),
             set_quantifier: None,
         }),
@@ -1881,10 +2140,10 @@ mod scalar_function {
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::DateDiff,
             args: FunctionArguments::Args(vec![
-                Expression::Identifier("year".to_string()),
-                Expression::Identifier("a".to_string()),
-                Expression::Identifier("b".to_string()),
-                Expression::Identifier("wednesday".to_string())
+                Expression::Identifier("year".into()),
+                Expression::Identifier("a".into()),
+                Expression::Identifier("b".into()),
+                Expression::Identifier("wednesday".into())
             ]),
             set_quantifier: None,
         }),
@@ -1896,8 +2155,8 @@ mod scalar_function {
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::DateTrunc,
             args: FunctionArguments::Args(vec![
-                Expression::Identifier("year".to_string()),
-                Expression::Identifier("a".to_string()),
+                Expression::Identifier("year".into()),
+                Expression::Identifier("a".into()),
             ]),
             set_quantifier: None,
         }),
@@ -1909,9 +2168,9 @@ mod scalar_function {
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::DateTrunc,
             args: FunctionArguments::Args(vec![
-                Expression::Identifier("year".to_string()),
-                Expression::Identifier("a".to_string()),
-                Expression::Identifier("wednesday".to_string())
+                Expression::Identifier("year".into()),
+                Expression::Identifier("a".into()),
+                Expression::Identifier("wednesday".into())
             ]),
             set_quantifier: None,
         }),
@@ -1922,8 +2181,8 @@ mod scalar_function {
         method = parse_expression,
         expected = Expression::Trim(TrimExpr {
             trim_spec: TrimSpec::Both,
-            trim_chars: Box::new(Expression::Identifier("substr".into())),
-            arg: Box::new(Expression::Identifier("str".to_string())),
+            trim_chars: Some(Box::new(Expression::Identifier("substr".into()))),
+            arg: Box::new(Expression::Identifier("str".into())),
         }),
         input = "trim(substr FROM str)",
     );
@@ -1932,8 +2191,8 @@ mod scalar_function {
         method = parse_expression,
         expected = Expression::Trim(TrimExpr {
             trim_spec: TrimSpec::Leading,
-            trim_chars: Box::new(Expression::StringConstructor(" ".into())),
-            arg: Box::new(Expression::Identifier("str".to_string())),
+            trim_chars: None,
+            arg: Box::new(Expression::Identifier("str".into())),
         }),
         input = "trim(leading FROM str)",
     );
@@ -1942,8 +2201,8 @@ mod scalar_function {
         method = parse_expression,
         expected = Expression::Trim(TrimExpr {
             trim_spec: TrimSpec::Both,
-            trim_chars: Box::new(Expression::StringConstructor(" ".into())),
-            arg: Box::new(Expression::Identifier("str".to_string())),
+            trim_chars: None,
+            arg: Box::new(Expression::Identifier("str".into())),
         }),
         input = "trim(str)",
     );
@@ -1952,7 +2211,7 @@ mod scalar_function {
         method = parse_expression,
         expected = Expression::Function(FunctionExpr {
             function: FunctionName::Upper,
-            args: FunctionArguments::Args(vec![Expression::Identifier("a".to_string())]),
+            args: FunctionArguments::Args(vec![Expression::Identifier("a".into())]),
             set_quantifier: None,
         }),
         input = "upper(a)",
@@ -2446,7 +2705,7 @@ mod where_test {
             },
             from_clause: None,
             where_clause: Some(Expression::Binary(BinaryExpr {
-                left: Box::new(Expression::Identifier("a".to_string())),
+                left: Box::new(Expression::Identifier("a".into())),
                 op: BinaryOp::Comparison(ComparisonOp::Gte),
                 right: Box::new(Expression::Literal(Literal::Integer(2)))
             })),
@@ -2888,13 +3147,50 @@ mod type_conversion {
         cast_to_decimal_ast,
         method = parse_expression,
         expected = Expression::Cast(CastExpr {
-            expr: Box::new(Expression::Identifier("v".to_string())),
+            expr: Box::new(Expression::Identifier("v".into())),
             to: Type::Decimal128,
+            format: None,
             on_null: Some(Box::new(Expression::StringConstructor("null".to_string()))),
             on_error: Some(Box::new(Expression::StringConstructor("error".to_string()))),
         }),
         input = "CAST(v AS DECIMAL(1), 'null' ON NULL, 'error' ON ERROR)",
     );
+    validate_ast!(
+        cast_to_date_with_format_ast,
+        method = parse_expression,
+        expected = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Identifier("v".into())),
+            to: Type::Date,
+            format: Some("%Y-%m-%d".to_string()),
+            on_null: None,
+            on_error: Some(Box::new(Expression::StringConstructor("error".to_string()))),
+        }),
+        input = "CAST(v AS DATE FORMAT '%Y-%m-%d', 'error' ON ERROR)",
+    );
+    parsable!(
+        cast_format_without_string_literal,
+        expected = false,
+        input = "select CAST(v AS DATE FORMAT f)"
+    );
+    parsable!(
+        cast_format_with_other_keyword,
+        expected = false,
+        expected_error_tech_msg = "expected FORMAT, found: `formats`",
+        expected_error_code = 2000,
+        input = "select CAST(v AS DATE formats '%Y')"
+    );
+    validate_ast!(
+        format_is_not_reserved,
+        method = parse_expression,
+        expected = Expression::Cast(CastExpr {
+            expr: Box::new(Expression::Identifier("format".into())),
+            to: Type::Date,
+            format: Some("%Y".to_string()),
+            on_null: None,
+            on_error: None,
+        }),
+        input = "CAST(format AS DATE format '%Y')",
+    );
 }
 
 mod subquery {
@@ -2929,6 +3225,26 @@ mod subquery {
         expected = true,
         input = "SELECT x = ALL (SELECT a)"
     );
+    parsable!(
+        any_value_list,
+        expected = true,
+        input = "SELECT x = ANY (1, 2, 3)"
+    );
+    parsable!(
+        some_value_list,
+        expected = true,
+        input = "SELECT x = SOME (a, b)"
+    );
+    parsable!(
+        all_value_list,
+        expected = true,
+        input = "SELECT x > ALL (1, 2, 3)"
+    );
+    parsable!(
+        all_single_value_list,
+        expected = true,
+        input = "SELECT x > ALL (1)"
+    );
     parsable!(
         in_tuple_subquery,
         expected = true,
@@ -2953,14 +3269,14 @@ mod subquery {
         some_subquery,
         method = parse_expression,
         expected = Expression::SubqueryComparison(SubqueryComparisonExpr {
-            expr: Box::new(Expression::Identifier("x".to_string())),
+            expr: Box::new(Expression::Identifier("x".into())),
             op: ComparisonOp::Neq,
             quantifier: SubqueryQuantifier::Any,
             subquery: Box::new(Query::Select(SelectQuery {
                 select_clause: SelectClause {
                     set_quantifier: SetQuantifier::All,
                     body: SelectBody::Standard(vec![SelectExpression::Expression(
-                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".to_string()),)
+                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".into()),)
                     )])
                 },
                 from_clause: None,
@@ -2979,13 +3295,13 @@ mod subquery {
         in_subquery,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
-            left: Box::new(Expression::Identifier("x".to_string())),
+            left: Box::new(Expression::Identifier("x".into())),
             op: BinaryOp::In,
             right: Box::new(Expression::Subquery(Box::new(Query::Select(SelectQuery {
                 select_clause: SelectClause {
                     set_quantifier: SetQuantifier::All,
                     body: SelectBody::Standard(vec![SelectExpression::Expression(
-                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".to_string()),)
+                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".into()),)
                     )])
                 },
                 from_clause: None,
@@ -3004,13 +3320,13 @@ mod subquery {
         not_in_subquery,
         method = parse_expression,
         expected = Expression::Binary(BinaryExpr {
-            left: Box::new(Expression::Identifier("x".to_string())),
+            left: Box::new(Expression::Identifier("x".into())),
             op: BinaryOp::NotIn,
             right: Box::new(Expression::Subquery(Box::new(Query::Select(SelectQuery {
                 select_clause: SelectClause {
                     set_quantifier: SetQuantifier::All,
                     body: SelectBody::Standard(vec![SelectExpression::Expression(
-                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".to_string()),)
+                        OptionallyAliasedExpr::Unaliased(Expression::Identifier("a".into()),)
                     )])
                 },
                 from_clause: None,
@@ -3120,7 +3436,7 @@ mod document {
         expected = Expression::Subpath(SubpathExpr {
             expr: Box::new(Expression::Access(AccessExpr {
                 expr: Box::new(Expression::Subpath(SubpathExpr {
-                    expr: Box::new(Expression::Identifier("a".to_string())),
+                    expr: Box::new(Expression::Identifier("a".into())),
                     subpath: "b".to_string()
                 })),
                 subfield: Box::new(Expression::StringConstructor("c".to_string())),
@@ -3183,7 +3499,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3204,7 +3520,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3226,7 +3542,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3247,7 +3563,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3270,7 +3586,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3292,7 +3608,7 @@ mod comments {
             select_clause: SelectClause {
                 set_quantifier: SetQuantifier::All,
                 body: SelectBody::Standard(vec![SelectExpression::Expression(
-                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".to_string()),)
+                    OptionallyAliasedExpr::Unaliased(Expression::Identifier("foo".into()),)
                 )])
             },
             from_clause: None,
@@ -3604,7 +3920,7 @@ mod unrecognized_token_suggestion {
     parsable!(
         nothing_close_to_recommend,
         expected = false,
-        expected_error_user_msg = "Unrecognized token `=>`, expected: `+`, `AND`, `AS`, `BETWEEN`, `,`, `||`, `CROSS`, ```, `\"`, `/`, `.`, `::`, `=`, `>`, `>=`, `ID`, `IN`, `INNER`, `IS`, `JOIN`, `LEFT`, `[`, `(`, `LIKE`, `<`, `<=`, `<>`, `NOT`, `NOT IN`, `NOT LIKE`, `OR`, `RIGHT`, `)`, `*`, `-`, `::!`, `WITH`",
+        expected_error_user_msg = "Unrecognized token `=>`, expected: `+`, `AND`, `AS`, `BETWEEN`, `,`, `||`, `CROSS`, ```, `\"`, `/`, `.`, `::`, `=`, `>`, `>=`, `ID`, `ILIKE`, `IN`, `INNER`, `INT`, `IS`, `JOIN`, `LEFT`, `[`, `(`, `LIKE`, `<`, `<=`, `%`, `<>`, `NOT`, `NOT ILIKE`, `NOT IN`, `NOT LIKE`, `OR`, `RIGHT`, `)`, `*`, `-`, `::!`, `WITH`",
         input = "select * from UNWIND(foo => foo)"
     );
 
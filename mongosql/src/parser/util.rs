@@ -18,7 +18,12 @@ pub fn process_delimited_ident(value: &str) -> String {
     }
 }
 
-pub fn parse_position_func(e: Expression) -> Result<FunctionExpr, LalrpopError<'static>> {
+/// parse_position_func builds a POSITION function from a `substring IN string`
+/// expression and an optional start position.
+pub fn parse_position_func(
+    e: Expression,
+    start: Option<Expression>,
+) -> Result<FunctionExpr, LalrpopError<'static>> {
     match e {
         Expression::Binary(BinaryExpr { left, op, right }) => {
             if op != BinaryOp::In {
@@ -28,7 +33,9 @@ pub fn parse_position_func(e: Expression) -> Result<FunctionExpr, LalrpopError<'
             } else {
                 Ok(FunctionExpr {
                     function: FunctionName::Position,
-                    args: FunctionArguments::Args(vec![*left, *right]),
+                    args: FunctionArguments::Args(
+                        vec![*left, *right].into_iter().chain(start).collect(),
+                    ),
                     set_quantifier: None,
                 })
             }
@@ -37,13 +44,12 @@ pub fn parse_position_func(e: Expression) -> Result<FunctionExpr, LalrpopError<'
     }
 }
 
+/// parse_sort_key builds an ORDER BY sort key. An integer literal is a
+/// positional reference to a select expression, and any other expression is
+/// sorted by its value. Other literals, optionally signed, are rejected since
+/// they are neither positions nor meaningful sort keys.
 pub fn parse_sort_key(e: Expression) -> Result<SortKey, LalrpopError<'static>> {
     match e {
-        Expression::Identifier(_) => Ok(SortKey::Simple(e)),
-        Expression::Subpath(SubpathExpr {
-            expr: _,
-            subpath: _,
-        }) => Ok(SortKey::Simple(e)),
         Expression::Literal(Literal::Integer(i)) => {
             let u: Result<u32, LalrpopError> = u32::from_str(i.to_string().as_str())
                 .map_err(|_| LalrpopError::from("failed to convert number to u32".to_string()));
@@ -52,9 +58,65 @@ pub fn parse_sort_key(e: Expression) -> Result<SortKey, LalrpopError<'static>> {
                 Err(x) => Err(x),
             }
         }
-        _ => Err(LalrpopError::from(
+        Expression::Literal(_) | Expression::StringConstructor(_) => Err(LalrpopError::from(
+            "failed to parse ORDER BY sort key".to_string(),
+        )),
+        Expression::Unary(UnaryExpr {
+            op: UnaryOp::Neg | UnaryOp::Pos,
+            ref expr,
+        }) if matches!(**expr, Expression::Literal(_)) => Err(LalrpopError::from(
             "failed to parse ORDER BY sort key".to_string(),
         )),
+        _ => Ok(SortKey::Simple(e)),
+    }
+}
+
+/// parse_cast_format checks that the keyword before a CAST format string is
+/// FORMAT, which is not a reserved word and so arrives as an identifier.
+pub fn parse_cast_format(keyword: &str, format: String) -> Result<String, LalrpopError<'static>> {
+    if !keyword.eq_ignore_ascii_case("format") {
+        return Err(LalrpopError::from(format!(
+            "expected FORMAT, found: `{keyword}`"
+        )));
+    }
+    Ok(format)
+}
+
+/// parse_interval builds an INTERVAL from its leading keyword, amount, and date
+/// part. INTERVAL is not a reserved word, so the keyword arrives as an identifier
+/// and is checked here. Only the date parts that can be added to or subtracted
+/// from a date are accepted.
+pub fn parse_interval(
+    keyword: &str,
+    amount: Expression,
+    name: &str,
+) -> Result<IntervalExpr, LalrpopError<'static>> {
+    if !keyword.eq_ignore_ascii_case("interval") {
+        return Err(LalrpopError::from(format!(
+            "expected INTERVAL, found: `{keyword}`"
+        )));
+    }
+    let date_part = DatePart::try_from(name)?;
+    match date_part {
+        DatePart::Year
+        | DatePart::Quarter
+        | DatePart::Month
+        | DatePart::Week
+        | DatePart::Day
+        | DatePart::Hour
+        | DatePart::Minute
+        | DatePart::Second
+        | DatePart::Millisecond => Ok(IntervalExpr {
+            amount: Box::new(amount),
+            date_part,
+        }),
+        DatePart::DayOfYear
+        | DatePart::DayOfWeek
+        | DatePart::IsoWeek
+        | DatePart::IsoWeekday
+        | DatePart::Epoch => Err(LalrpopError::from(format!(
+            "{name} is not a valid INTERVAL date part"
+        ))),
     }
 }
 
@@ -73,7 +135,9 @@ pub fn parse_simple_datasource(
 ) -> Result<Datasource, LalrpopError<'static>> {
     let (expr, alias) = ae.take_fields();
     match expr {
-        Expression::Identifier(collection) => Ok(Datasource::Collection(CollectionSource {
+        Expression::Identifier(Identifier {
+            name: collection, ..
+        }) => Ok(Datasource::Collection(CollectionSource {
             database: None,
             collection,
             alias,
@@ -116,7 +180,7 @@ impl Expression {
 
     pub fn take_identifier_name(self) -> Option<String> {
         match self {
-            Expression::Identifier(s) => Some(s),
+            Expression::Identifier(i) => Some(i.name),
             _ => None,
         }
     }
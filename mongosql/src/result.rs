@@ -9,6 +9,8 @@ pub enum Error {
     Parse(#[from] parser::Error),
     #[error("rewrite error: {0}")]
     Rewrite(#[from] ast::rewrites::Error),
+    #[error("pretty print error: {0}")]
+    PrettyPrint(#[from] ast::pretty_print::Error),
     #[error("algebrize error: {0}")]
     Algebrize(#[from] algebrizer::Error),
     #[error("schema inference error: {0}")]
@@ -26,3 +28,13 @@ pub enum Error {
     #[error("catalog error: {0}")]
     Catalog(String),
 }
+
+impl Error {
+    /// Returns the span of the query text this error refers to, if known.
+    pub fn span(&self) -> Option<ast::Span> {
+        match self {
+            Error::Algebrize(e) => e.span(),
+            _ => None,
+        }
+    }
+}
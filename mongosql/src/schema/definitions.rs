@@ -40,6 +40,20 @@ pub enum Error {
     JsonSchemaFailure,
     #[error("bson failure: {0}")]
     BsonFailure(#[from] json_schema::Error),
+    #[error("schema exceeds the maximum nesting depth of {MAX_DEPTH}")]
+    MaxDepthExceeded,
+}
+
+/// The maximum nesting depth of a schema. Schema conversion, satisfaction, and
+/// simplification are all recursive, so deeper schemas are rejected up front.
+pub const MAX_DEPTH: usize = json_schema::MAX_DEPTH;
+
+/// Rejects JSON schemas nested more deeply than MAX_DEPTH.
+fn check_json_schema_depth(v: &json_schema::Schema) -> Result<(), Error> {
+    if v.depth() > MAX_DEPTH {
+        return Err(Error::MaxDepthExceeded);
+    }
+    Ok(())
 }
 
 impl From<user_schema_error::Error> for Error {
@@ -308,6 +322,62 @@ impl ResultSet {
             false
         }
     }
+
+    /// is_union_compatible determines if this ResultSet can be combined
+    /// with `other` by a set operation. Datasources that appear on only one
+    /// side are always compatible, since their rows remain distinguished by
+    /// datasource. For datasources on both sides with document schemas, every
+    /// column must be allowed on both sides and have comparable types. Schemas
+    /// are simplified first, so an AnyOf of documents, such as the schema of
+    /// an array datasource, is compared as a single document. If any column
+    /// mismatches, returns an Err describing every mismatch.
+    pub fn is_union_compatible(&self, other: &ResultSet) -> Result<(), String> {
+        let mut mismatches = Vec::new();
+        for (key, left) in self.schema_env.iter() {
+            let right = other.schema_env.get(key).map(Schema::simplify);
+            let (left, right) = match (Schema::simplify(left), right) {
+                (Schema::Document(left), Some(Schema::Document(right))) => (left, right),
+                _ => continue,
+            };
+            let column_name = |field: &str| match &key.datasource {
+                DatasourceName::Bottom => field.to_string(),
+                DatasourceName::Named(name) => format!("{name}.{field}"),
+            };
+            for (field, left_schema) in left.keys.iter() {
+                match right.keys.get(field) {
+                    Some(right_schema)
+                        if left_schema.is_comparable_with(right_schema) == Satisfaction::Not =>
+                    {
+                        mismatches.push(format!(
+                            "column `{}` has incompatible types: {} and {}",
+                            column_name(field),
+                            left_schema,
+                            right_schema
+                        ))
+                    }
+                    Some(_) => {}
+                    None if !right.additional_properties => mismatches.push(format!(
+                        "column `{}` is missing from the right side",
+                        column_name(field)
+                    )),
+                    None => {}
+                }
+            }
+            if !left.additional_properties {
+                for field in right.keys.keys().filter(|f| !left.keys.contains_key(*f)) {
+                    mismatches.push(format!(
+                        "column `{}` is missing from the left side",
+                        column_name(field)
+                    ));
+                }
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches.join("; "))
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Default)]
@@ -583,17 +653,25 @@ impl TryFrom<json_schema::Schema> for Document {
     type Error = Error;
 
     /// try_from tries to construct a Schema::Document from the passed-in JSON schema,
-    /// and returns an error if the call to Schema::try_from fails.
+    /// and returns an error if it is nested more deeply than MAX_DEPTH or if the
+    /// conversion of any of its properties fails.
     fn try_from(v: json_schema::Schema) -> Result<Self, Self::Error> {
+        check_json_schema_depth(&v)?;
+        Document::from_json_schema(v)
+    }
+}
+
+impl Document {
+    fn from_json_schema(v: json_schema::Schema) -> Result<Self, Error> {
         Ok(Document {
             keys: v
                 .properties
                 .unwrap_or_default()
                 .into_iter()
                 .map(|(key, schema)| {
-                    Ok::<(std::string::String, Schema), Self::Error>((
+                    Ok::<(std::string::String, Schema), Error>((
                         key,
-                        Schema::try_from(schema)?,
+                        Schema::from_json_schema(schema)?,
                     ))
                 })
                 .collect::<Result<_, _>>()?,
@@ -932,6 +1010,24 @@ impl Satisfaction {
 }
 
 impl Schema {
+    /// Returns the nesting depth of this schema, counting each Document key,
+    /// Array item, and AnyOf branch as one level. Like json_schema::Schema::depth,
+    /// this is computed iteratively so it is safe to call on any schema.
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((schema, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            match schema {
+                Schema::Document(d) => stack.extend(d.keys.values().map(|s| (s, depth + 1))),
+                Schema::Array(a) => stack.push((a.as_ref(), depth + 1)),
+                Schema::AnyOf(a) => stack.extend(a.iter().map(|s| (s, depth + 1))),
+                _ => (),
+            }
+        }
+        max_depth
+    }
+
     /// returns the available schema field names.
     pub fn keys(&self) -> Vec<String> {
         match self {
@@ -1737,7 +1833,17 @@ impl From<TypeOrMissing> for Schema {
 impl TryFrom<json_schema::Schema> for Schema {
     type Error = Error;
 
-    /// from converts a json schema into a MongoSQL schema by following these rules:
+    /// try_from rejects JSON schemas nested more deeply than MAX_DEPTH before
+    /// converting them, since the conversion and every later traversal of the
+    /// resulting Schema are recursive.
+    fn try_from(v: json_schema::Schema) -> Result<Self, Self::Error> {
+        check_json_schema_depth(&v)?;
+        Schema::from_json_schema(v)
+    }
+}
+
+impl Schema {
+    /// from_json_schema converts a json schema into a MongoSQL schema by following these rules:
     ///      - BsonType::Single => Schema::Atomic
     ///      - BsonType::Multiple => Schema::AnyOf
     ///      - properties, required, and additional_properties => Schema::Document
@@ -1745,7 +1851,7 @@ impl TryFrom<json_schema::Schema> for Schema {
     ///      - any_of => Schema::AnyOf
     ///
     /// any_of and one_of are the only fields that are mutually exclusive with the rest.
-    fn try_from(v: json_schema::Schema) -> Result<Self, Self::Error> {
+    fn from_json_schema(v: json_schema::Schema) -> Result<Self, Error> {
         // Explicitly match the valid combinations of JSON schema fields
         match v {
             // The empty JSON schema is equivalent to `Any`. This would
@@ -1788,7 +1894,7 @@ impl TryFrom<json_schema::Schema> for Schema {
                         Ok(Schema::Array(Box::new(match items {
                             // The single-schema variant of the `items`
                             // field constrains all elements of the array.
-                            Some(json_schema::Items::Single(i)) => Schema::try_from(*i)?,
+                            Some(json_schema::Items::Single(i)) => Schema::from_json_schema(*i)?,
                             // The multiple-schema variant of the `items`
                             // field only asserts the schemas for the
                             // array items at specified indexes, and
@@ -1808,27 +1914,27 @@ impl TryFrom<json_schema::Schema> for Schema {
                             }
                         })))
                     }
-                    json_schema::BsonType::Single(json_schema::BsonTypeName::Object) => {
-                        Ok(Schema::Document(Document::try_from(json_schema::Schema {
+                    json_schema::BsonType::Single(json_schema::BsonTypeName::Object) => Ok(
+                        Schema::Document(Document::from_json_schema(json_schema::Schema {
                             properties,
                             required,
                             additional_properties,
                             ..Default::default()
-                        })?))
-                    }
+                        })?),
+                    ),
                     json_schema::BsonType::Single(typ) => {
                         Ok(Schema::Atomic(Atomic::try_from(typ)?))
                     }
                     json_schema::BsonType::Multiple(m) => {
                         // For each value in `bson_type`, construct a json_schema::Schema that only
                         // contains the single type and any relevant fields and recursively call
-                        // Schema::try_from on it. Then, wrap the resulting vector in a Schema::AnyOf
+                        // Schema::from_json_schema on it. Then, wrap the resulting vector in a Schema::AnyOf
                         // and call `simplify` in order to remove any unnecessary AnyOf wrappings.
                         Ok(Schema::simplify(&AnyOf(
                             m.into_iter()
                                 .map(|bson_type| match bson_type {
                                     json_schema::BsonTypeName::Array => {
-                                        Schema::try_from(json_schema::Schema {
+                                        Schema::from_json_schema(json_schema::Schema {
                                             bson_type: Some(json_schema::BsonType::Single(
                                                 bson_type,
                                             )),
@@ -1838,7 +1944,7 @@ impl TryFrom<json_schema::Schema> for Schema {
                                         })
                                     }
                                     json_schema::BsonTypeName::Object => {
-                                        Schema::try_from(json_schema::Schema {
+                                        Schema::from_json_schema(json_schema::Schema {
                                             bson_type: Some(json_schema::BsonType::Single(
                                                 bson_type,
                                             )),
@@ -1848,7 +1954,7 @@ impl TryFrom<json_schema::Schema> for Schema {
                                             ..Default::default()
                                         })
                                     }
-                                    _ => Schema::try_from(json_schema::Schema {
+                                    _ => Schema::from_json_schema(json_schema::Schema {
                                         bson_type: Some(json_schema::BsonType::Single(bson_type)),
                                         ..Default::default()
                                     }),
@@ -1870,7 +1976,7 @@ impl TryFrom<json_schema::Schema> for Schema {
             } => Ok(Schema::AnyOf(
                 any_of
                     .into_iter()
-                    .map(Schema::try_from)
+                    .map(Schema::from_json_schema)
                     .collect::<Result<BTreeSet<Schema>, _>>()?,
             )),
             json_schema::Schema {
@@ -1886,7 +1992,7 @@ impl TryFrom<json_schema::Schema> for Schema {
             } => Ok(Schema::AnyOf(
                 one_of
                     .into_iter()
-                    .map(Schema::try_from)
+                    .map(Schema::from_json_schema)
                     .collect::<Result<BTreeSet<Schema>, _>>()?,
             )),
             _ => Err(Error::InvalidCombinationOfFields()),
@@ -50,6 +50,69 @@ mod schema_ord {
     }
 }
 
+mod any_of_eq {
+    use crate::{
+        schema::{Atomic, Document, Schema},
+        set,
+    };
+
+    // AnyOf members are stored in a BTreeSet, so member order never affects equality.
+    #[test]
+    fn reordered_any_of_members_are_equal() {
+        assert_eq!(
+            Schema::AnyOf(set![
+                Schema::Atomic(Atomic::String),
+                Schema::Atomic(Atomic::Null),
+                Schema::Missing,
+            ]),
+            Schema::AnyOf(set![
+                Schema::Missing,
+                Schema::Atomic(Atomic::Null),
+                Schema::Atomic(Atomic::String),
+            ]),
+        );
+    }
+
+    #[test]
+    fn reordered_nested_any_of_members_are_equal() {
+        assert_eq!(
+            Schema::Array(Box::new(Schema::AnyOf(set![
+                Schema::Document(Document::empty()),
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Integer),
+                    Schema::Atomic(Atomic::Double)
+                ]),
+            ]))),
+            Schema::Array(Box::new(Schema::AnyOf(set![
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Double),
+                    Schema::Atomic(Atomic::Integer)
+                ]),
+                Schema::Document(Document::empty()),
+            ]))),
+        );
+    }
+
+    // Differently nested AnyOfs with the same members are only equal once simplified.
+    #[test]
+    fn differently_nested_any_of_members_are_equal_after_simplify() {
+        let nested = Schema::AnyOf(set![
+            Schema::AnyOf(set![
+                Schema::Atomic(Atomic::Null),
+                Schema::Atomic(Atomic::Integer)
+            ]),
+            Schema::Atomic(Atomic::String),
+        ]);
+        let flat = Schema::AnyOf(set![
+            Schema::Atomic(Atomic::String),
+            Schema::Atomic(Atomic::Integer),
+            Schema::Atomic(Atomic::Null),
+        ]);
+        assert_ne!(nested, flat);
+        assert_eq!(Schema::simplify(&nested), Schema::simplify(&flat));
+    }
+}
+
 mod union_schemata {
     use crate::{
         map,
@@ -394,6 +457,38 @@ mod from_json {
         }
     );
 
+    test_from_json_schema!(
+        reject_json_schema_exceeding_max_depth,
+        schema_schema = Err(Error::MaxDepthExceeded),
+        json_schema = (0..schema::MAX_DEPTH).fold(
+            json_schema::Schema {
+                bson_type: Some(BsonType::Single(BsonTypeName::Int)),
+                ..Default::default()
+            },
+            |s, _| json_schema::Schema {
+                bson_type: Some(BsonType::Single(BsonTypeName::Array)),
+                items: Some(Items::Single(Box::new(s))),
+                ..Default::default()
+            }
+        )
+    );
+
+    test_from_json_schema!(
+        accept_json_schema_at_max_depth,
+        schema_schema = Ok((1..schema::MAX_DEPTH).fold(Atomic(Integer), |s, _| Array(Box::new(s)))),
+        json_schema = (1..schema::MAX_DEPTH).fold(
+            json_schema::Schema {
+                bson_type: Some(BsonType::Single(BsonTypeName::Int)),
+                ..Default::default()
+            },
+            |s, _| json_schema::Schema {
+                bson_type: Some(BsonType::Single(BsonTypeName::Array)),
+                items: Some(Items::Single(Box::new(s))),
+                ..Default::default()
+            }
+        )
+    );
+
     test_from_json_schema!(
         convert_empty_schema_to_any,
         schema_schema = Ok(Any),
@@ -1972,6 +2067,18 @@ mod is_comparable_with {
         _self = AnyOf(set![Atomic(String), Atomic(Boolean)]),
         other = AnyOf(set![Atomic(Date), Atomic(Integer)]),
     );
+    test_is_comparable_with!(
+        a_set_of_numerics_not_comparable_with_non_numeric_atomic,
+        expected = Not,
+        _self = AnyOf(set![Atomic(Integer), Atomic(Double)]),
+        other = Atomic(String),
+    );
+    test_is_comparable_with!(
+        a_set_of_numerics_or_nullish_must_be_comparable_with_numeric,
+        expected = Must,
+        _self = AnyOf(set![Atomic(Integer), Atomic(Null), Missing]),
+        other = Atomic(Decimal),
+    );
     test_is_comparable_with!(
         a_set_containing_array_maybe_comparable_with_set_containing_array,
         expected = May,
@@ -2201,6 +2308,27 @@ mod simplify {
             AnyOf(set![Atomic(Integer), Atomic(Null)])
         ])
     );
+    test_simplify!(
+        flatten_deeply_nested_any_of,
+        expected = AnyOf(set![Atomic(String), Atomic(Integer), Atomic(Double)]),
+        input = AnyOf(set![
+            AnyOf(set![
+                AnyOf(set![Atomic(Integer), Atomic(Double)]),
+                Atomic(String)
+            ]),
+            Atomic(Integer)
+        ])
+    );
+    test_simplify!(
+        flatten_nested_any_of_of_one_schema,
+        expected = Atomic(Integer),
+        input = AnyOf(set![AnyOf(set![AnyOf(set![Atomic(Integer)])])])
+    );
+    test_simplify!(
+        missing_is_distinct_from_null,
+        expected = AnyOf(set![Missing, Atomic(Null)]),
+        input = AnyOf(set![AnyOf(set![Missing, Atomic(Null)]), Atomic(Null)])
+    );
     test_simplify!(
         flatten_any_of_containing_array,
         expected = Array(Box::new(AnyOf(set![Atomic(String), Atomic(Integer)]))),
@@ -3586,3 +3714,125 @@ mod cartesian_product {
         other = Any,
     );
 }
+
+mod is_union_compatible {
+    use crate::{
+        map,
+        mir::binding_tuple::Key,
+        schema::{Atomic, Document, ResultSet, Schema, SchemaEnvironment},
+        set,
+    };
+
+    macro_rules! test_is_union_compatible {
+        ($func_name:ident, expected = $expected:expr, left = $left:expr, right = $right:expr $(,)?) => {
+            #[test]
+            fn $func_name() {
+                assert_eq!(
+                    $expected,
+                    result_set($left).is_union_compatible(&result_set($right))
+                );
+            }
+        };
+    }
+
+    fn result_set(schema_env: SchemaEnvironment) -> ResultSet {
+        ResultSet {
+            schema_env,
+            min_size: 0,
+            max_size: None,
+        }
+    }
+
+    fn document(keys: Vec<(&str, Schema)>, additional_properties: bool) -> Schema {
+        Schema::Document(Document {
+            keys: keys.into_iter().map(|(k, s)| (k.to_string(), s)).collect(),
+            required: set![],
+            additional_properties,
+            ..Default::default()
+        })
+    }
+
+    test_is_union_compatible!(
+        comparable_columns_are_compatible,
+        expected = Ok(()),
+        left = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+        right = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Double))], false),
+        },
+    );
+
+    test_is_union_compatible!(
+        distinct_datasources_are_compatible,
+        expected = Ok(()),
+        left = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+        right = map! {
+            Key::named("bar", 0u16) => document(vec![("b", Schema::Atomic(Atomic::String))], false),
+        },
+    );
+
+    test_is_union_compatible!(
+        column_allowed_by_additional_properties_is_compatible,
+        expected = Ok(()),
+        left = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+        right = map! {
+            Key::named("foo", 0u16) => document(vec![], true),
+        },
+    );
+
+    test_is_union_compatible!(
+        missing_columns_are_reported,
+        expected = Err(
+            "column `foo.a` is missing from the right side; column `foo.b` is missing from the left side"
+                .to_string()
+        ),
+        left = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+        right = map! {
+            Key::named("foo", 0u16) => document(vec![("b", Schema::Atomic(Atomic::Integer))], false),
+        },
+    );
+
+    test_is_union_compatible!(
+        any_of_document_columns_are_compared,
+        expected = Err("column `arr.a` has incompatible types: int and string".to_string()),
+        left = map! {
+            Key::named("arr", 0u16) => Schema::AnyOf(set![
+                document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+            ]),
+        },
+        right = map! {
+            Key::named("arr", 0u16) => Schema::AnyOf(set![
+                document(vec![("a", Schema::Atomic(Atomic::String))], false),
+            ]),
+        },
+    );
+
+    test_is_union_compatible!(
+        non_document_datasources_are_not_compared,
+        expected = Ok(()),
+        left = map! {
+            Key::named("foo", 0u16) => Schema::Any,
+        },
+        right = map! {
+            Key::named("foo", 0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+    );
+
+    test_is_union_compatible!(
+        incompatible_types_are_reported,
+        expected = Err("column `a` has incompatible types: int and string".to_string()),
+        left = map! {
+            Key::bot(0u16) => document(vec![("a", Schema::Atomic(Atomic::Integer))], false),
+        },
+        right = map! {
+            Key::bot(0u16) => document(vec![("a", Schema::Atomic(Atomic::String))], false),
+        },
+    );
+}
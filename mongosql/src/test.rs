@@ -398,6 +398,8 @@ mod select_list_order {
                         schema_checking_mode: SchemaCheckingMode::default(),
                         exclude_namespaces: $exclude_namespaces,
                         allow_order_by_missing_columns: false,
+                        facet_sibling_aggregations: false,
+                        hoist_common_subexpressions: false,
                     },
                 );
                 assert!(translation.is_ok());
@@ -574,3 +576,562 @@ mod select_list_order {
         expected = vec![vec!["a".to_string()], vec!["b".to_string()]]
     );
 }
+
+mod explain {
+    use crate::{
+        catalog::{Catalog, Namespace},
+        explain, map,
+        options::{ExcludeNamespacesOption, SqlOptions},
+        result::Error,
+        schema::{Atomic, Document, Schema},
+        set, SchemaCheckingMode,
+    };
+
+    fn catalog() -> Catalog {
+        Catalog::new(map! {
+            Namespace {db: "test".to_string(), collection: "foo".to_string()} => Schema::Document(Document {
+                keys: map! {
+                    "a".to_string() => Schema::Atomic(Atomic::Integer),
+                },
+                required: set! {"a".to_string()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+            Namespace {db: "test".to_string(), collection: "bar".to_string()} => Schema::Document(Document {
+                keys: map! {
+                    "b".to_string() => Schema::Atomic(Atomic::Integer),
+                },
+                required: set! {"b".to_string()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        })
+    }
+
+    #[test]
+    fn subquery_populates_all_sections() {
+        let output = explain(
+            "test",
+            "SELECT * FROM foo WHERE EXISTS(SELECT * FROM bar)",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert!(output.ir.contains("Exists"), "{}", output.ir);
+        assert!(
+            output.desugared_pipeline.contains("Lookup"),
+            "{}",
+            output.desugared_pipeline
+        );
+        assert!(output.mql.contains("$lookup"), "{}", output.mql);
+    }
+
+    #[test]
+    fn in_subquery_is_eq_any_comparison() {
+        let output = explain(
+            "test",
+            "SELECT * FROM foo WHERE a IN (SELECT b FROM bar)",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert!(output.ir.contains("SubqueryComparison"), "{}", output.ir);
+        assert!(output.ir.contains("operator: Eq"), "{}", output.ir);
+        assert!(output.ir.contains("modifier: Any"), "{}", output.ir);
+        assert!(output.mql.contains("$lookup"), "{}", output.mql);
+    }
+
+    #[test]
+    fn not_in_subquery_is_neq_all_comparison() {
+        let output = explain(
+            "test",
+            "SELECT * FROM foo WHERE a NOT IN (SELECT b FROM bar)",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert!(output.ir.contains("SubqueryComparison"), "{}", output.ir);
+        assert!(output.ir.contains("operator: Neq"), "{}", output.ir);
+        assert!(output.ir.contains("modifier: All"), "{}", output.ir);
+        assert!(output.mql.contains("$lookup"), "{}", output.mql);
+    }
+
+    #[test]
+    fn formatted_date_cast_is_date_from_string() {
+        let output = explain(
+            "test",
+            "SELECT CAST('2024-03-01' AS DATE FORMAT '%Y-%m-%d') AS d FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert!(output.ir.contains("ParseDate"), "{}", output.ir);
+        assert!(output.mql.contains("$dateFromString"), "{}", output.mql);
+        assert!(output.mql.contains("%Y-%m-%d"), "{}", output.mql);
+    }
+
+    #[test]
+    fn rewritten_query_is_pretty_printed() {
+        let output = explain(
+            "test",
+            "SELECT a FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            "SELECT VALUE {'a': a} FROM foo AS foo",
+            output.rewritten_query
+        );
+    }
+
+    #[test]
+    fn non_namespaced_collisions_are_rejected() {
+        let output = explain(
+            "test",
+            "SELECT * FROM foo AS f1 CROSS JOIN foo AS f2",
+            &catalog(),
+            SqlOptions::new(
+                ExcludeNamespacesOption::ExcludeNamespaces,
+                SchemaCheckingMode::default(),
+            ),
+        );
+
+        assert!(matches!(output, Err(Error::Schema(_))), "{output:?}");
+    }
+}
+
+mod schema_of_sql {
+    use crate::{
+        ast::Span,
+        catalog::{Catalog, Namespace},
+        map,
+        options::SqlOptions,
+        result::Error,
+        schema::{Atomic, Document, ResultSet, Schema},
+        schema_of_sql, set,
+        usererror::UserError,
+    };
+    use mongosql_datastructures::binding_tuple::Key;
+
+    fn foo_schema() -> Schema {
+        Schema::Document(Document {
+            keys: map! {
+                "a".to_string() => Schema::Atomic(Atomic::Integer),
+            },
+            required: set! {"a".to_string()},
+            additional_properties: false,
+            ..Default::default()
+        })
+    }
+
+    fn bar_schema() -> Schema {
+        Schema::Document(Document {
+            keys: map! {
+                "s".to_string() => Schema::Atomic(Atomic::String),
+            },
+            required: set! {"s".to_string()},
+            additional_properties: false,
+            ..Default::default()
+        })
+    }
+
+    fn catalog() -> Catalog {
+        Catalog::new(map! {
+            Namespace {db: "test".to_string(), collection: "foo".to_string()} => foo_schema(),
+            Namespace {db: "test".to_string(), collection: "bar".to_string()} => bar_schema(),
+        })
+    }
+
+    fn column(name: &str, schema: Schema) -> ResultSet {
+        ResultSet {
+            schema_env: map! {
+                Key::bot(0u16) => Schema::Document(Document {
+                    keys: map! {
+                        name.to_string() => schema,
+                    },
+                    required: set! {name.to_string()},
+                    additional_properties: false,
+                    ..Default::default()
+                }),
+            },
+            min_size: 0,
+            max_size: None,
+        }
+    }
+
+    #[test]
+    fn select_star() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT * FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Ok(ResultSet {
+                schema_env: map! {
+                    ("foo", 0u16).into() => foo_schema(),
+                },
+                min_size: 0,
+                max_size: None,
+            }),
+            actual
+        );
+    }
+
+    #[test]
+    fn computed_columns() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT a + 1 AS b, 'x' AS c FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Ok(ResultSet {
+                schema_env: map! {
+                    Key::bot(0u16) => Schema::Document(Document {
+                        keys: map! {
+                            "b".to_string() => Schema::Atomic(Atomic::Integer),
+                            "c".to_string() => Schema::Atomic(Atomic::String),
+                        },
+                        required: set! {"b".to_string(), "c".to_string()},
+                        additional_properties: false,
+                        ..Default::default()
+                    }),
+                },
+                min_size: 0,
+                max_size: None,
+            }),
+            actual
+        );
+    }
+
+    #[test]
+    fn count_star_is_at_most_one_row() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT COUNT(*) FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!((0, Some(1)), (actual.min_size, actual.max_size));
+    }
+
+    #[test]
+    fn count_star_over_non_empty_array_is_one_row() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT COUNT(*) FROM [{'a': 1}, {'a': 2}] AS arr",
+            &catalog(),
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!((1, Some(1)), (actual.min_size, actual.max_size));
+    }
+
+    #[test]
+    fn numeric_in_list_is_boolean() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT a IN (1, 2.5) AS b FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(Ok(column("b", Schema::Atomic(Atomic::Boolean))), actual);
+    }
+
+    #[test]
+    fn string_not_in_list_is_boolean() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT s NOT IN ('x', 'y') AS b FROM bar",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(Ok(column("b", Schema::Atomic(Atomic::Boolean))), actual);
+    }
+
+    #[test]
+    fn formatted_date_cast_may_be_null() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT CAST(s AS DATE FORMAT '%Y-%m-%d') AS d FROM bar",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Ok(column(
+                "d",
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Date),
+                    Schema::Atomic(Atomic::Null),
+                ])
+            )),
+            actual
+        );
+    }
+
+    #[test]
+    fn formatted_date_cast_with_fallbacks_is_date_or_fallback() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT CAST(s AS DATE FORMAT '%Y-%m-%d', 'none' ON NULL, 'bad' ON ERROR) AS d FROM bar",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Ok(column(
+                "d",
+                Schema::AnyOf(set![
+                    Schema::Atomic(Atomic::Date),
+                    Schema::Atomic(Atomic::String),
+                ])
+            )),
+            actual
+        );
+    }
+
+    #[test]
+    fn incomparable_in_list_member_is_an_error() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT a IN (1, 'x') AS b FROM foo",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        match actual {
+            Err(Error::Algebrize(e)) => assert_eq!(1005, e.code()),
+            _ => panic!("expected an invalid comparison, got {actual:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_query_is_an_error() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT * FROM baz",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn missing_field_reports_span() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT * FROM foo WHERE b > 1",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Some(Span { start: 24, end: 25 }),
+            actual.unwrap_err().span()
+        );
+    }
+
+    #[test]
+    fn missing_delimited_field_span_includes_delimiters() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT * FROM foo WHERE `b` > 1",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Some(Span { start: 24, end: 27 }),
+            actual.unwrap_err().span()
+        );
+    }
+
+    #[test]
+    fn missing_field_span_is_that_of_the_failing_identifier() {
+        let actual = schema_of_sql(
+            "test",
+            "SELECT * FROM foo AS b WHERE b.a > 1 AND b > 1",
+            &catalog(),
+            SqlOptions::default(),
+        );
+
+        assert_eq!(
+            Some(Span { start: 41, end: 42 }),
+            actual.unwrap_err().span()
+        );
+    }
+}
+
+mod translate_sql {
+    use crate::{
+        catalog::{Catalog, Namespace},
+        map,
+        options::SqlOptions,
+        schema::{Atomic, Document, Schema},
+        set, translate_sql,
+    };
+    use bson::bson;
+
+    #[test]
+    fn select_star_from_qualified_collection() {
+        let catalog = Catalog::new(map! {
+            Namespace {db: "db".to_string(), collection: "coll".to_string()} => Schema::Document(Document {
+                keys: map! {
+                    "a".to_string() => Schema::Atomic(Atomic::Integer),
+                },
+                required: set! {"a".to_string()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        });
+
+        let translation = translate_sql(
+            "test",
+            "SELECT * FROM db.coll AS c",
+            &catalog,
+            SqlOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!("db", translation.target_db);
+        assert_eq!(Some("coll".to_string()), translation.target_collection);
+        assert_eq!(
+            bson!([{"$project": {"c": "$$ROOT", "_id": 0}}]),
+            translation.pipeline
+        );
+    }
+
+    fn foo_catalog() -> Catalog {
+        Catalog::new(map! {
+            Namespace {db: "test".to_string(), collection: "foo".to_string()} => Schema::Document(Document {
+                keys: map! {
+                    "a".to_string() => Schema::Atomic(Atomic::Integer),
+                },
+                required: set! {"a".to_string()},
+                additional_properties: false,
+                ..Default::default()
+            }),
+        })
+    }
+
+    fn stages(sql: &str) -> Vec<bson::Document> {
+        let translation =
+            translate_sql("test", sql, &foo_catalog(), SqlOptions::default()).unwrap();
+        match translation.pipeline {
+            bson::Bson::Array(stages) => stages
+                .into_iter()
+                .map(|stage| stage.as_document().unwrap().clone())
+                .collect(),
+            pipeline => panic!("expected an array pipeline, got {pipeline:?}"),
+        }
+    }
+
+    fn position(stages: &[bson::Document], name: &str) -> usize {
+        stages
+            .iter()
+            .position(|stage| stage.contains_key(name))
+            .unwrap_or_else(|| panic!("no {name} stage in {stages:?}"))
+    }
+
+    #[test]
+    fn hoist_common_subexpressions_option_binds_repeated_expression() {
+        let sql = "SELECT (a + 1) * (a + 1) AS x FROM foo";
+        let hoisted = translate_sql(
+            "test",
+            sql,
+            &foo_catalog(),
+            SqlOptions {
+                hoist_common_subexpressions: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let default = translate_sql("test", sql, &foo_catalog(), SqlOptions::default()).unwrap();
+
+        assert!(
+            hoisted.pipeline.to_string().contains("$$cse0"),
+            "{}",
+            hoisted.pipeline
+        );
+        assert!(
+            !default.pipeline.to_string().contains("$$cse0"),
+            "{}",
+            default.pipeline
+        );
+    }
+
+    #[test]
+    fn order_by_computed_key_sorts_on_added_field() {
+        let stages = stages("SELECT a FROM foo ORDER BY a + 1");
+
+        let add_fields = position(&stages, "$addFields");
+        let sort = position(&stages, "$sort");
+        assert!(add_fields < sort, "{stages:?}");
+        assert!(
+            stages[add_fields]
+                .get_document("$addFields")
+                .unwrap()
+                .get_document("__sortKeys")
+                .unwrap()
+                .contains_key("key0"),
+            "{stages:?}"
+        );
+        assert_eq!(
+            Some(&bson::Bson::Int32(1)),
+            stages[sort]
+                .get_document("$sort")
+                .unwrap()
+                .get("__sortKeys.key0"),
+            "{stages:?}"
+        );
+
+        let project = stages
+            .iter()
+            .rposition(|stage| stage.contains_key("$project"))
+            .unwrap();
+        assert!(sort < project, "{stages:?}");
+        assert!(
+            !stages[project]
+                .get_document("$project")
+                .unwrap()
+                .contains_key("__sortKeys"),
+            "{stages:?}"
+        );
+    }
+
+    #[test]
+    fn order_by_reference_sorts_without_added_field() {
+        let stages = stages("SELECT a FROM foo ORDER BY a");
+
+        assert!(
+            stages.iter().all(|stage| !stage.contains_key("$addFields")),
+            "{stages:?}"
+        );
+        assert!(
+            stages[position(&stages, "$sort")]
+                .get_document("$sort")
+                .unwrap()
+                .keys()
+                .all(|key| !key.starts_with("__sortKeys")),
+            "{stages:?}"
+        );
+    }
+}
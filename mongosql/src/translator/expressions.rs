@@ -10,6 +10,109 @@ use crate::{
 };
 use mongosql_datastructures::{binding_tuple::Key, unique_linked_hash_map::UniqueLinkedHashMap};
 
+// The month and day names for each of mir::DATE_NAME_LOCALES. Day names are ordered to match
+// $dayOfWeek, which returns 1 for Sunday.
+const DATE_NAMES: [(&str, [&str; 12], [&str; 7]); 4] = [
+    (
+        "en_US",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+    ),
+    (
+        "de_DE",
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+    ),
+    (
+        "es_ES",
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+    ),
+    (
+        "fr_FR",
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+    ),
+];
+
 impl MqlTranslator {
     pub fn translate_expression(&self, mir_expression: mir::Expression) -> Result<air::Expression> {
         match mir_expression {
@@ -330,31 +433,183 @@ impl MqlTranslator {
             scalar_function_to_scalar_function_type(scalar_func.is_nullable, scalar_func.function);
 
         match op {
+            // $size errors on non-array arguments, so ArrayLength guards it with $isArray and
+            // evaluates to null otherwise.
+            ScalarFunctionType::ArrayLength => Ok(air::Expression::MQLSemanticOperator(
+                air::MQLSemanticOperator {
+                    op: MQLOperator::Cond,
+                    args: vec![
+                        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                            op: MQLOperator::IsArray,
+                            args: vec![args[0].clone()],
+                        }),
+                        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                            op: MQLOperator::Size,
+                            args: vec![args[0].clone()],
+                        }),
+                        air::Expression::Literal(air::LiteralValue::Null),
+                    ],
+                },
+            )),
             ScalarFunctionType::Divide => Ok(air::Expression::SqlDivide(air::SqlDivide {
                 dividend: Box::new(args[0].clone()),
                 divisor: Box::new(args[1].clone()),
                 on_error: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
             })),
+            // Integer division truncates the result of a null-on-error divide, so a divisor of 0
+            // results in NULL just like Div. The dividend is converted to a decimal, since
+            // dividing two integers with $divide returns a double, which cannot hold every long
+            // quotient exactly. The truncated quotient is converted back to a long if either
+            // operand is a long, and to an int otherwise.
+            ScalarFunctionType::IntDiv => {
+                let convert = |input, to| {
+                    air::Expression::Convert(air::Convert {
+                        input: Box::new(input),
+                        to,
+                        on_null: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+                        on_error: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+                    })
+                };
+                let quotient = air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                    op: MQLOperator::Trunc,
+                    args: vec![air::Expression::SqlDivide(air::SqlDivide {
+                        dividend: Box::new(convert(args[0].clone(), air::Type::Decimal128)),
+                        divisor: Box::new(args[1].clone()),
+                        on_error: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+                    })],
+                });
+                let is_long = |arg: &air::Expression| {
+                    air::Expression::Is(air::Is {
+                        expr: Box::new(arg.clone()),
+                        target_type: air::TypeOrMissing::Type(air::Type::Int64),
+                    })
+                };
+                Ok(air::Expression::MQLSemanticOperator(
+                    air::MQLSemanticOperator {
+                        op: MQLOperator::Cond,
+                        args: vec![
+                            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                                op: MQLOperator::Or,
+                                args: vec![is_long(&args[0]), is_long(&args[1])],
+                            }),
+                            convert(quotient.clone(), air::Type::Int64),
+                            convert(quotient, air::Type::Int32),
+                        ],
+                    },
+                ))
+            }
+            // $eq does not propagate NULL, so comparing with a boolean literal is FALSE for NULL
+            // and MISSING arguments, as IS TRUE and IS FALSE require.
+            ScalarFunctionType::EqBoolean(b) => Ok(air::Expression::MQLSemanticOperator(
+                air::MQLSemanticOperator {
+                    op: MQLOperator::Eq,
+                    args: vec![
+                        args[0].clone(),
+                        air::Expression::Literal(air::LiteralValue::Boolean(b)),
+                    ],
+                },
+            )),
+            // IS NULL is TRUE for both NULL and MISSING values.
+            ScalarFunctionType::IsNullish => Ok(air::Expression::Is(air::Is {
+                expr: Box::new(args[0].clone()),
+                target_type: air::TypeOrMissing::Type(air::Type::Null),
+            })),
+            // UnixTimestamp counts the seconds from the epoch to the date with $dateDiff, which
+            // returns a long and evaluates to null when the date is null or missing.
+            ScalarFunctionType::UnixTimestamp => Ok(air::Expression::DateFunction(
+                air::DateFunctionApplication {
+                    function: air::DateFunction::Diff,
+                    unit: air::DatePart::Second,
+                    args: vec![
+                        air::Expression::Literal(air::LiteralValue::DateTime(
+                            bson::DateTime::from_millis(0),
+                        )),
+                        args[0].clone(),
+                        air::Expression::Literal(air::LiteralValue::String("sunday".to_string())),
+                    ],
+                },
+            )),
+            ScalarFunctionType::MonthName | ScalarFunctionType::DayName => {
+                Self::translate_date_name(op, args)
+            }
             ScalarFunctionType::Trim(op) => Ok(air::Expression::Trim(air::Trim {
                 op,
                 input: Box::new(args[1].clone()),
                 chars: Box::new(args[0].clone()),
             })),
-            // SQLOperator::IndexOfCP has reversed arguments
+            // The two-argument form of SPLIT returns the full token array, which is what $split
+            // produces. $split errors on an empty delimiter, so that case evaluates to null, as
+            // it does for the three-argument form.
+            ScalarFunctionType::Sql(SQLOperator::Split) if args.len() == 2 => Ok(
+                air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                    op: MQLOperator::Cond,
+                    args: vec![
+                        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                            op: MQLOperator::Eq,
+                            args: vec![
+                                args[1].clone(),
+                                air::Expression::Literal(air::LiteralValue::String("".to_string())),
+                            ],
+                        }),
+                        air::Expression::Literal(air::LiteralValue::Null),
+                        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                            op: MQLOperator::Split,
+                            args,
+                        }),
+                    ],
+                }),
+            ),
+            // The one-argument form of LOG is the natural logarithm, so it uses $sqlLog with
+            // base e to get the same handling of invalid arguments as the two-argument form.
+            ScalarFunctionType::Sql(SQLOperator::Log) if args.len() == 1 => Ok(
+                air::Expression::SQLSemanticOperator(air::SQLSemanticOperator {
+                    op: SQLOperator::Log,
+                    args: vec![
+                        args[0].clone(),
+                        air::Expression::Literal(air::LiteralValue::Double(std::f64::consts::E)),
+                    ],
+                }),
+            ),
+            // $dateFromString throws on strings that do not match the format, but ParseDate
+            // evaluates to null instead, so we always pass an onError argument. ParseDate's
+            // optional on_null and on_error arguments are in the opposite order of
+            // $dateFromString's onError and onNull.
+            ScalarFunctionType::Mql(MQLOperator::DateFromString) => {
+                let mut args = args.into_iter();
+                let (date_string, format) = (args.next(), args.next());
+                let (on_null, on_error) = (args.next(), args.next());
+                Ok(air::Expression::MQLSemanticOperator(
+                    air::MQLSemanticOperator {
+                        op: MQLOperator::DateFromString,
+                        args: [date_string, format]
+                            .into_iter()
+                            .flatten()
+                            .chain(std::iter::once(
+                                on_error
+                                    .unwrap_or(air::Expression::Literal(air::LiteralValue::Null)),
+                            ))
+                            .chain(on_null)
+                            .collect(),
+                    },
+                ))
+            }
+            // SQLOperator::IndexOfCP has reversed string and substring arguments. The optional
+            // start index is passed through in place.
             ScalarFunctionType::Sql(SQLOperator::IndexOfCP) => Ok(
                 air::Expression::SQLSemanticOperator(air::SQLSemanticOperator {
                     op: SQLOperator::IndexOfCP,
-                    args: args.into_iter().rev().collect(),
+                    args: swap_index_of_cp_args(args),
                 }),
             ),
             ScalarFunctionType::Sql(op) => Ok(air::Expression::SQLSemanticOperator(
                 air::SQLSemanticOperator { op, args },
             )),
-            // MQLOperator::IndexOfCP has reversed arguments
+            // MQLOperator::IndexOfCP has reversed string and substring arguments. The optional
+            // start index is passed through in place.
             ScalarFunctionType::Mql(MQLOperator::IndexOfCP) => Ok(
                 air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
                     op: MQLOperator::IndexOfCP,
-                    args: args.into_iter().rev().collect(),
+                    args: swap_index_of_cp_args(args),
                 }),
             ),
             ScalarFunctionType::Mql(op) => Ok(air::Expression::MQLSemanticOperator(
@@ -363,6 +618,67 @@ impl MqlTranslator {
         }
     }
 
+    /// Translates MonthName and DayName. $dateToString can only format English month names, so
+    /// other month names, and all day names, are looked up in an array of names using the month
+    /// or day of week number.
+    fn translate_date_name(
+        op: ScalarFunctionType,
+        args: Vec<air::Expression>,
+    ) -> Result<air::Expression> {
+        let locale = match args.get(1) {
+            None => mir::DATE_NAME_LOCALES[0],
+            Some(air::Expression::Literal(air::LiteralValue::String(locale))) => locale.as_str(),
+            Some(_) => return Err(Error::InvalidDateNameLocale),
+        };
+        let (locale, month_names, day_names) = DATE_NAMES
+            .iter()
+            .find(|(l, ..)| *l == locale)
+            .ok_or(Error::InvalidDateNameLocale)?;
+        let date = args[0].clone();
+        let (names, number_op): (&[&str], _) = match op {
+            ScalarFunctionType::MonthName if *locale == mir::DATE_NAME_LOCALES[0] => {
+                return Ok(air::Expression::MQLSemanticOperator(
+                    air::MQLSemanticOperator {
+                        op: MQLOperator::DateToString,
+                        args: vec![
+                            date,
+                            air::Expression::Literal(air::LiteralValue::String("%B".to_string())),
+                        ],
+                    },
+                ));
+            }
+            ScalarFunctionType::MonthName => (month_names, MQLOperator::Month),
+            _ => (day_names, MQLOperator::DayOfWeek),
+        };
+        Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: MQLOperator::ElemAt,
+                args: vec![
+                    air::Expression::Array(
+                        names
+                            .iter()
+                            .map(|name| {
+                                air::Expression::Literal(air::LiteralValue::String(
+                                    name.to_string(),
+                                ))
+                            })
+                            .collect(),
+                    ),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: MQLOperator::Subtract,
+                        args: vec![
+                            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                                op: number_op,
+                                args: vec![date],
+                            }),
+                            air::Expression::Literal(air::LiteralValue::Integer(1)),
+                        ],
+                    }),
+                ],
+            },
+        ))
+    }
+
     fn translate_searched_case(
         &self,
         searched_case: mir::SearchedCaseExpr,
@@ -539,3 +855,10 @@ impl MqlTranslator {
         }
     }
 }
+
+/// Swaps the first two arguments of a POSITION application so that the string comes before the
+/// substring, as $indexOfCP expects.
+fn swap_index_of_cp_args(mut args: Vec<air::Expression>) -> Vec<air::Expression> {
+    args.swap(0, 1);
+    args
+}
@@ -52,6 +52,8 @@ pub enum Error {
     InvalidEquiJoinForeignFieldRef(String),
     #[error("expected document schema type in SchemaEnvironment BTreeMap value, but got {0:?}")]
     DocumentSchemaTypeNotFound(schema::Schema),
+    #[error("locale for MonthName and DayName must be one of the string literals {}", mir::DATE_NAME_LOCALES.join(", "))]
+    InvalidDateNameLocale,
 }
 
 #[derive(Clone)]
@@ -116,6 +118,13 @@ impl MqlTranslator {
         let mut new_registry = MqlMappingRegistry::new();
         let mut replacement_expr = ROOT.clone();
         let mut needs_replacement = false;
+        let outermost_bot_scope = self
+            .mapping_registry
+            .get_registry()
+            .keys()
+            .filter(|k| k.datasource == DatasourceName::Bottom)
+            .map(|k| k.scope)
+            .min();
 
         // Iterate through in descending alphabetical order. This is because a
         // mapped name either just replaces '$'/'.'s with '_' without conflict
@@ -131,10 +140,17 @@ impl MqlTranslator {
             .sorted_by(|(_, v1), (_, v2)| Ord::cmp(&v2.name, &v1.name))
         {
             let (name_to_unset, name_to_set) = match &og_name.datasource {
-                DatasourceName::Bottom => {
+                // Only the outermost Bottom is merged into the root. Bottom
+                // from any inner scope was handled within its own pipeline,
+                // so its mapping is retained as-is.
+                DatasourceName::Bottom if Some(og_name.scope) == outermost_bot_scope => {
                     needs_replacement = true;
                     (mapped_name.name.clone(), "".to_string())
                 }
+                DatasourceName::Bottom => {
+                    new_registry.insert(og_name.clone(), mapped_name.clone());
+                    continue;
+                }
                 DatasourceName::Named(name) => {
                     if *name != *mapped_name.name {
                         needs_replacement = true;
@@ -80,9 +80,11 @@ impl MqlTranslator {
             let mut output_registry = self.mapping_registry.clone();
             let mut add_fields_body = UniqueLinkedHashMap::new();
             for (k, e) in mir_project.expression.clone().into_iter() {
+                let bot_name =
+                    self.get_scoped_bot_name(k.scope, &unique_bot_name, &mir_project.expression);
                 let mapped_k = self.get_mapped_project_name(
                     &k.datasource,
-                    &unique_bot_name,
+                    &bot_name,
                     &mir_project.expression,
                 )?;
                 add_fields_body
@@ -106,9 +108,11 @@ impl MqlTranslator {
             let mut output_registry = MqlMappingRegistry::new();
             let mut project_body = UniqueLinkedHashMap::new();
             for (k, e) in mir_project.expression.clone().into_iter() {
+                let bot_name =
+                    self.get_scoped_bot_name(k.scope, &unique_bot_name, &mir_project.expression);
                 let mapped_k = self.get_mapped_project_name(
                     &k.datasource,
-                    &unique_bot_name,
+                    &bot_name,
                     &mir_project.expression,
                 )?;
                 project_body.insert(
@@ -144,9 +148,18 @@ impl MqlTranslator {
         // These will be all aliased group keys and all the aggregations.
         let mut bot_body = UniqueLinkedHashMap::new();
 
+        // A lone unaliased key is grouped on directly, so that it becomes the _id itself rather
+        // than a field of an _id document.
+        let scalar_id = matches!(mir_group.keys.as_slice(), [key] if key.get_alias().is_none());
+
         // map the group key aliases and translate the expressions. Unliased keys will be Projected
         // straight into the specifications
-        let keys = self.translate_group_keys(mir_group.keys, &mut specifications, &mut bot_body)?;
+        let keys = self.translate_group_keys(
+            mir_group.keys,
+            scalar_id,
+            &mut specifications,
+            &mut bot_body,
+        )?;
 
         // map the group aggregation aliases and translate the expresisons.
         let aggregations =
@@ -186,6 +199,7 @@ impl MqlTranslator {
                 source: Box::new(source_translation),
                 keys,
                 aggregations,
+                scalar_id,
             })),
             specifications,
         }))
@@ -455,6 +469,7 @@ impl MqlTranslator {
             mir::AggregationFunction::Avg => air::AggregationFunction::Avg,
             mir::AggregationFunction::Count => air::AggregationFunction::Count,
             mir::AggregationFunction::First => air::AggregationFunction::First,
+            mir::AggregationFunction::GroupConcat => air::AggregationFunction::GroupConcat,
             mir::AggregationFunction::Last => air::AggregationFunction::Last,
             mir::AggregationFunction::Max => air::AggregationFunction::Max,
             mir::AggregationFunction::MergeDocuments => air::AggregationFunction::MergeDocuments,
@@ -462,6 +477,8 @@ impl MqlTranslator {
             mir::AggregationFunction::StddevPop => air::AggregationFunction::StddevPop,
             mir::AggregationFunction::StddevSamp => air::AggregationFunction::StddevSamp,
             mir::AggregationFunction::Sum => air::AggregationFunction::Sum,
+            mir::AggregationFunction::VarPop => air::AggregationFunction::VarPop,
+            mir::AggregationFunction::VarSamp => air::AggregationFunction::VarSamp,
         }
     }
 
@@ -492,6 +509,7 @@ impl MqlTranslator {
     fn translate_group_keys(
         &self,
         keys: Vec<mir::OptionallyAliasedExpr>,
+        scalar_id: bool,
         specifications: &mut UniqueLinkedHashMap<String, air::ProjectItem>,
         bot_body: &mut UniqueLinkedHashMap<String, air::Expression>,
     ) -> Result<Vec<air::NameExprPair>> {
@@ -502,14 +520,33 @@ impl MqlTranslator {
 
         let mut translated_keys = Vec::new();
 
-        let make_key_ref = |name| air::Expression::FieldRef(format!("_id.{name}").into());
+        let make_key_ref = |name| {
+            if scalar_id {
+                air::Expression::FieldRef("_id".to_string().into())
+            } else {
+                air::Expression::FieldRef(format!("_id.{name}").into())
+            }
+        };
+        // $group puts NULL and MISSING values of a scalar _id into the same group, but keeps
+        // them apart as fields of an _id document, so document keys convert MISSING to NULL.
+        let translate_key = |e| -> Result<air::Expression> {
+            let key = self.translate_expression(e)?;
+            Ok(if scalar_id {
+                key
+            } else {
+                air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                    op: air::MQLOperator::IfNull,
+                    args: vec![key, air::Expression::Literal(air::LiteralValue::Null)],
+                })
+            })
+        };
         for (i, k) in keys.into_iter().enumerate() {
             match k {
                 mir::OptionallyAliasedExpr::Aliased(ae) => {
                     // an aliased key will be projected under Bot
                     translated_keys.push(air::NameExprPair {
                         name: ae.alias.clone(),
-                        expr: self.translate_expression(ae.expr)?,
+                        expr: translate_key(ae.expr)?,
                     });
                     bot_body.insert(ae.alias.clone(), make_key_ref(ae.alias))?;
                 }
@@ -554,7 +591,7 @@ impl MqlTranslator {
                     }
                     translated_keys.push(air::NameExprPair {
                         name: unique_name,
-                        expr: self.translate_expression(e)?,
+                        expr: translate_key(e)?,
                     });
                 }
             }
@@ -584,16 +621,18 @@ impl MqlTranslator {
             } else {
                 alias.clone()
             };
-            let (function, distinct, arg) = match a.agg_expr {
+            let (function, distinct, arg, separator) = match a.agg_expr {
                 mir::AggregationExpr::CountStar(distinct) => (
                     air::AggregationFunction::Count,
                     distinct,
                     Box::new(ROOT.clone()),
+                    None,
                 ),
                 mir::AggregationExpr::Function(afa) => (
                     Self::translate_agg_function(afa.function),
                     afa.distinct,
                     Box::new(self.translate_expression(*afa.arg)?),
+                    afa.separator,
                 ),
             };
             bot_body.insert(
@@ -605,6 +644,7 @@ impl MqlTranslator {
                 function,
                 distinct,
                 arg,
+                separator,
             });
         }
 
@@ -422,6 +422,34 @@ mod date_function {
             ],
         }),
     );
+    test_translate_expression!(
+        datesub,
+        expected = Ok(air::Expression::DateFunction(
+            air::DateFunctionApplication {
+                function: air::DateFunction::Sub,
+                unit: air::DatePart::Month,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                    air::Expression::SQLSemanticOperator(air::SQLSemanticOperator {
+                        op: air::SQLOperator::CurrentTimestamp,
+                        args: vec![],
+                    }),
+                ],
+            }
+        )),
+        input = mir::Expression::DateFunction(mir::DateFunctionApplication {
+            function: mir::DateFunction::Sub,
+            is_nullable: true,
+            date_part: mir::DatePart::Month,
+            args: vec![
+                mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+                mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+                    mir::ScalarFunction::CurrentTimestamp,
+                    vec![],
+                )),
+            ],
+        }),
+    );
     test_translate_expression!(
         datediff,
         expected = Ok(air::Expression::DateFunction(
@@ -1408,6 +1436,30 @@ mod scalar_function {
         )),
     );
 
+    test_translate_expression_with_schema_info!(
+        array_length_guards_size_with_is_array,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Cond,
+                args: vec![
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::IsArray,
+                        args: vec![air::Expression::Literal(air::LiteralValue::Integer(1))],
+                    }),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Size,
+                        args: vec![air::Expression::Literal(air::LiteralValue::Integer(1))],
+                    }),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::ArrayLength,
+            vec![mir::Expression::Literal(mir::LiteralValue::Integer(1))],
+        )),
+    );
+
     test_translate_expression_with_schema_info!(
         position_no_nullish,
         expected = Ok(air::Expression::MQLSemanticOperator(
@@ -1429,6 +1481,51 @@ mod scalar_function {
         }),
     );
 
+    test_translate_expression_with_schema_info!(
+        position_with_start_no_nullish,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::IndexOfCP,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::String("world".into())),
+                    air::Expression::Literal(air::LiteralValue::String("hello".into())),
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+            function: mir::ScalarFunction::Position,
+            args: vec![
+                mir::Expression::Literal(mir::LiteralValue::String("hello".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("world".into())),
+                mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+            ],
+            is_nullable: false,
+        }),
+    );
+
+    test_translate_expression_with_schema_info!(
+        position_with_start_nullish,
+        expected = Ok(air::Expression::SQLSemanticOperator(
+            air::SQLSemanticOperator {
+                op: air::SQLOperator::IndexOfCP,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::String("world".into())),
+                    air::Expression::Literal(air::LiteralValue::String("hello".into())),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Position,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::String("hello".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("world".into())),
+                mir::Expression::Literal(mir::LiteralValue::Null),
+            ],
+        )),
+    );
+
     test_translate_expression_with_schema_info!(
         position_nullish,
         expected = Ok(air::Expression::SQLSemanticOperator(
@@ -1926,6 +2023,404 @@ mod scalar_function {
         }),
     );
 
+    test_translate_expression_with_schema_info!(
+        log_one_arg_is_sql_log_base_e,
+        expected = Ok(air::Expression::SQLSemanticOperator(
+            air::SQLSemanticOperator {
+                op: air::SQLOperator::Log,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Integer(10)),
+                    air::Expression::Literal(air::LiteralValue::Double(std::f64::consts::E)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Log,
+            vec![mir::Expression::Literal(mir::LiteralValue::Integer(10))],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        log_two_args_is_sql_log,
+        expected = Ok(air::Expression::SQLSemanticOperator(
+            air::SQLSemanticOperator {
+                op: air::SQLOperator::Log,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Integer(8)),
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Log,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::Integer(8)),
+                mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    fn int_div(dividend: air::LiteralValue, divisor: air::LiteralValue) -> air::Expression {
+        let convert = |input, to| {
+            air::Expression::Convert(air::Convert {
+                input: Box::new(input),
+                to,
+                on_null: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+                on_error: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+            })
+        };
+        let quotient = air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+            op: air::MQLOperator::Trunc,
+            args: vec![air::Expression::SqlDivide(air::SqlDivide {
+                dividend: Box::new(convert(
+                    air::Expression::Literal(dividend.clone()),
+                    air::Type::Decimal128,
+                )),
+                divisor: Box::new(air::Expression::Literal(divisor.clone())),
+                on_error: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+            })],
+        });
+        let is_long = |arg| {
+            air::Expression::Is(air::Is {
+                expr: Box::new(air::Expression::Literal(arg)),
+                target_type: air::TypeOrMissing::Type(air::Type::Int64),
+            })
+        };
+        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+            op: air::MQLOperator::Cond,
+            args: vec![
+                air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                    op: air::MQLOperator::Or,
+                    args: vec![is_long(dividend), is_long(divisor)],
+                }),
+                convert(quotient.clone(), air::Type::Int64),
+                convert(quotient, air::Type::Int32),
+            ],
+        })
+    }
+
+    test_translate_expression_with_schema_info!(
+        int_div_converts_truncated_quotient_by_operand_type,
+        expected = Ok(int_div(
+            air::LiteralValue::Integer(7),
+            air::LiteralValue::Long(2)
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::IntDiv,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::Integer(7)),
+                mir::Expression::Literal(mir::LiteralValue::Long(2)),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        int_div_divides_large_longs_as_decimals,
+        expected = Ok(int_div(
+            air::LiteralValue::Long(9_007_199_254_740_993),
+            air::LiteralValue::Long(1)
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::IntDiv,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::Long(9_007_199_254_740_993)),
+                mir::Expression::Literal(mir::LiteralValue::Long(1)),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        greatest,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Max,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Integer(1)),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Greatest,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                mir::Expression::Literal(mir::LiteralValue::Null),
+                mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        least,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Min,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Integer(1)),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                    air::Expression::Literal(air::LiteralValue::Integer(2)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Least,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+                mir::Expression::Literal(mir::LiteralValue::Null),
+                mir::Expression::Literal(mir::LiteralValue::Integer(2)),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        is_true_compares_with_true,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Eq,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::Null),
+                    air::Expression::Literal(air::LiteralValue::Boolean(true)),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+            function: mir::ScalarFunction::IsTrue,
+            args: vec![mir::Expression::Literal(mir::LiteralValue::Null)],
+            is_nullable: false,
+        }),
+    );
+
+    test_translate_expression_with_schema_info!(
+        is_unknown_is_null,
+        expected = Ok(air::Expression::Is(air::Is {
+            expr: Box::new(air::Expression::Literal(air::LiteralValue::Null)),
+            target_type: air::TypeOrMissing::Type(air::Type::Null),
+        })),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication {
+            function: mir::ScalarFunction::IsUnknown,
+            args: vec![mir::Expression::Literal(mir::LiteralValue::Null)],
+            is_nullable: false,
+        }),
+    );
+
+    test_translate_expression_with_schema_info!(
+        month_name_is_date_to_string,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::DateToString,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::DateTime(bson::DateTime::MIN)),
+                    air::Expression::Literal(air::LiteralValue::String("%B".to_string())),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::MonthName,
+            vec![mir::Expression::Literal(mir::LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        month_name_with_locale_indexes_names_by_month,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::ElemAt,
+                args: vec![
+                    air::Expression::Array(
+                        [
+                            "janvier",
+                            "février",
+                            "mars",
+                            "avril",
+                            "mai",
+                            "juin",
+                            "juillet",
+                            "août",
+                            "septembre",
+                            "octobre",
+                            "novembre",
+                            "décembre"
+                        ]
+                        .into_iter()
+                        .map(|name| air::Expression::Literal(air::LiteralValue::String(
+                            name.into()
+                        )))
+                        .collect()
+                    ),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Subtract,
+                        args: vec![
+                            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                                op: air::MQLOperator::Month,
+                                args: vec![air::Expression::Literal(air::LiteralValue::DateTime(
+                                    bson::DateTime::MIN
+                                ))],
+                            }),
+                            air::Expression::Literal(air::LiteralValue::Integer(1)),
+                        ],
+                    }),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::MonthName,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::DateTime(bson::DateTime::MIN)),
+                mir::Expression::Literal(mir::LiteralValue::String("fr_FR".to_string())),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        day_name_indexes_names_by_day_of_week,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::ElemAt,
+                args: vec![
+                    air::Expression::Array(
+                        [
+                            "Sunday",
+                            "Monday",
+                            "Tuesday",
+                            "Wednesday",
+                            "Thursday",
+                            "Friday",
+                            "Saturday"
+                        ]
+                        .into_iter()
+                        .map(|name| air::Expression::Literal(air::LiteralValue::String(
+                            name.into()
+                        )))
+                        .collect()
+                    ),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Subtract,
+                        args: vec![
+                            air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                                op: air::MQLOperator::DayOfWeek,
+                                args: vec![air::Expression::Literal(air::LiteralValue::DateTime(
+                                    bson::DateTime::MIN
+                                ))],
+                            }),
+                            air::Expression::Literal(air::LiteralValue::Integer(1)),
+                        ],
+                    }),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::DayName,
+            vec![mir::Expression::Literal(mir::LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        unix_timestamp_diffs_seconds_from_epoch,
+        expected = Ok(air::Expression::DateFunction(
+            air::DateFunctionApplication {
+                function: air::DateFunction::Diff,
+                unit: air::DatePart::Second,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::DateTime(
+                        bson::DateTime::from_millis(0)
+                    )),
+                    air::Expression::Literal(air::LiteralValue::DateTime(bson::DateTime::MIN)),
+                    air::Expression::Literal(air::LiteralValue::String("sunday".to_string())),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::UnixTimestamp,
+            vec![mir::Expression::Literal(mir::LiteralValue::DateTime(
+                bson::DateTime::MIN
+            ))],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        from_unix_timestamp,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::ToDate,
+                args: vec![air::Expression::Literal(air::LiteralValue::Long(0))],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::FromUnixTimestamp,
+            vec![mir::Expression::Literal(mir::LiteralValue::Long(0))],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        date_format,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::DateToString,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::DateTime(bson::DateTime::MIN)),
+                    air::Expression::Literal(air::LiteralValue::String("%Y".into())),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::DateFormat,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::DateTime(bson::DateTime::MIN)),
+                mir::Expression::Literal(mir::LiteralValue::String("%Y".into())),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        parse_date_falls_back_to_null_on_error,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::DateFromString,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::String("2024".into())),
+                    air::Expression::Literal(air::LiteralValue::String("%Y".into())),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::ParseDate,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::String("2024".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("%Y".into())),
+            ],
+        )),
+    );
+
+    test_translate_expression_with_schema_info!(
+        parse_date_reorders_on_null_and_on_error,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::DateFromString,
+                args: vec![
+                    air::Expression::Literal(air::LiteralValue::String("2024".into())),
+                    air::Expression::Literal(air::LiteralValue::String("%Y".into())),
+                    air::Expression::Literal(air::LiteralValue::String("error".into())),
+                    air::Expression::Literal(air::LiteralValue::String("null".into())),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::ParseDate,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::String("2024".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("%Y".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("null".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("error".into())),
+            ],
+        )),
+    );
+
     test_translate_expression_with_schema_info!(
         replace_nullish,
         expected = Ok(air::Expression::MQLSemanticOperator(
@@ -2214,6 +2709,39 @@ mod scalar_function {
         )),
     );
 
+    test_translate_expression_with_schema_info!(
+        split_without_token_number,
+        expected = Ok(air::Expression::MQLSemanticOperator(
+            air::MQLSemanticOperator {
+                op: air::MQLOperator::Cond,
+                args: vec![
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Eq,
+                        args: vec![
+                            air::Expression::Literal(air::LiteralValue::String("l".into())),
+                            air::Expression::Literal(air::LiteralValue::String("".into())),
+                        ],
+                    }),
+                    air::Expression::Literal(air::LiteralValue::Null),
+                    air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                        op: air::MQLOperator::Split,
+                        args: vec![
+                            air::Expression::Literal(air::LiteralValue::String("hello".into())),
+                            air::Expression::Literal(air::LiteralValue::String("l".into())),
+                        ],
+                    }),
+                ],
+            }
+        )),
+        input = mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+            mir::ScalarFunction::Split,
+            vec![
+                mir::Expression::Literal(mir::LiteralValue::String("hello".into())),
+                mir::Expression::Literal(mir::LiteralValue::String("l".into())),
+            ],
+        )),
+    );
+
     test_translate_expression_with_schema_info!(
         current_time_stamp,
         expected = Ok(air::Expression::SQLSemanticOperator(
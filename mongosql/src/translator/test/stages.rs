@@ -183,9 +183,16 @@ mod project {
 }
 
 mod group {
-    use crate::{translator::Error, unchecked_unique_linked_hash_map, util::ROOT};
+    use crate::{air, translator::Error, unchecked_unique_linked_hash_map, util::ROOT};
     use mongosql_datastructures::binding_tuple::Key;
 
+    fn if_null(key: air::Expression) -> air::Expression {
+        air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+            op: air::MQLOperator::IfNull,
+            args: vec![key, air::Expression::Literal(air::LiteralValue::Null)],
+        })
+    }
+
     test_translate_stage!(
         group_count_star,
         expected = Ok(air::Stage::Project(air::Project {
@@ -193,7 +200,7 @@ mod group {
                 source: util::air_collection_stage("test_db", "foo"),
                 keys: vec![air::NameExprPair {
                     name: "x_key".into(),
-                    expr: ROOT.clone()
+                    expr: if_null(ROOT.clone())
                 },],
                 aggregations: vec![
                     // Count(*) is translated as Count(ROOT).
@@ -202,14 +209,17 @@ mod group {
                         function: air::AggregationFunction::Count,
                         distinct: true,
                         arg: ROOT.clone().into(),
+                        separator: None,
                     },
                     air::AccumulatorExpr {
                         alias: "c_nondistinct".into(),
                         function: air::AggregationFunction::Count,
                         distinct: false,
                         arg: ROOT.clone().into(),
+                        separator: None,
                     },
-                ]
+                ],
+                scalar_id: false,
             })
             .into(),
             specifications: unchecked_unique_linked_hash_map! {
@@ -250,7 +260,7 @@ mod group {
                 source: util::air_collection_stage("test_db", "foo"),
                 keys: vec![air::NameExprPair {
                     name: "x_key".into(),
-                    expr: ROOT.clone()
+                    expr: if_null(ROOT.clone())
                 },],
                 aggregations: vec![
                     air::AccumulatorExpr {
@@ -258,14 +268,17 @@ mod group {
                         function: air::AggregationFunction::Max,
                         distinct: true,
                         arg: Box::new(ROOT.clone()),
+                        separator: None,
                     },
                     air::AccumulatorExpr {
                         alias: "min_nondistinct".into(),
                         function: air::AggregationFunction::Min,
                         distinct: false,
                         arg: Box::new(ROOT.clone()),
+                        separator: None,
                     }
-                ]
+                ],
+                scalar_id: false,
             })
             .into(),
             specifications: unchecked_unique_linked_hash_map! {
@@ -294,6 +307,7 @@ mod group {
                             key: Key::named("foo", 0u16),
                         })
                         .into(),
+                        separator: None,
                     }),
                 },
                 mir::AliasedAggregation {
@@ -305,6 +319,7 @@ mod group {
                             key: Key::named("foo", 0u16),
                         })
                         .into(),
+                        separator: None,
                     }),
                 },
             ],
@@ -321,14 +336,15 @@ mod group {
                 keys: vec![
                     air::NameExprPair {
                         name: "__unaliasedKey2".into(),
-                        expr: air::Expression::FieldRef("foo".to_string().into()),
+                        expr: if_null(air::Expression::FieldRef("foo".to_string().into())),
                     },
                     air::NameExprPair {
                         name: "___unaliasedKey2".into(),
-                        expr: air::Expression::FieldRef("foo.x".to_string().into()),
+                        expr: if_null(air::Expression::FieldRef("foo.x".to_string().into())),
                     },
                 ],
-                aggregations: vec![]
+                aggregations: vec![],
+                scalar_id: false,
             })
             .into(),
             specifications: unchecked_unique_linked_hash_map! {
@@ -365,11 +381,11 @@ mod group {
                 keys: vec![
                     air::NameExprPair {
                         name: "__unaliasedKey2".into(),
-                        expr: air::Expression::FieldRef("foo".to_string().into()),
+                        expr: if_null(air::Expression::FieldRef("foo".to_string().into())),
                     },
                     air::NameExprPair {
                         name: "___unaliasedKey2".into(),
-                        expr: air::Expression::FieldRef("foo.x".to_string().into())
+                        expr: if_null(air::Expression::FieldRef("foo.x".to_string().into()))
                     },
                 ],
                 aggregations: vec![air::AccumulatorExpr {
@@ -377,7 +393,9 @@ mod group {
                     function: air::AggregationFunction::Count,
                     distinct: false,
                     arg: ROOT.clone().into(),
-                },]
+                    separator: None,
+                },],
+                scalar_id: false,
             })
             .into(),
             specifications: unchecked_unique_linked_hash_map! {
@@ -410,6 +428,67 @@ mod group {
         })
     );
 
+    test_translate_stage!(
+        lone_unaliased_key_is_scalar_id,
+        expected = Ok(air::Stage::Project(air::Project {
+            source: air::Stage::Group(air::Group {
+                source: util::air_project_collection(None, "foo", None),
+                keys: vec![air::NameExprPair {
+                    name: "__unaliasedKey1".into(),
+                    expr: air::Expression::FieldRef("foo.x".to_string().into()),
+                }],
+                aggregations: vec![],
+                scalar_id: true,
+            })
+            .into(),
+            specifications: unchecked_unique_linked_hash_map! {
+                "foo".to_string() => air::ProjectItem::Assignment(air::Expression::Document(unchecked_unique_linked_hash_map! {
+                    "x".to_string() => air::Expression::FieldRef("_id".to_string().into()),
+                })),
+            }
+        })),
+        input = mir::Stage::Group(mir::Group {
+            source: util::mir_project_collection(None, "foo", None, None),
+            keys: vec![mir::OptionallyAliasedExpr::Unaliased(
+                *util::mir_field_access("foo", "x", true)
+            )],
+            aggregations: vec![],
+            cache: mir::schema::SchemaCache::new(),
+            scope: 0,
+        })
+    );
+
+    test_translate_stage!(
+        lone_aliased_key_is_document_id,
+        expected = Ok(air::Stage::Project(air::Project {
+            source: air::Stage::Group(air::Group {
+                source: util::air_project_collection(None, "foo", None),
+                keys: vec![air::NameExprPair {
+                    name: "x_key".into(),
+                    expr: if_null(air::Expression::FieldRef("foo.x".to_string().into())),
+                }],
+                aggregations: vec![],
+                scalar_id: false,
+            })
+            .into(),
+            specifications: unchecked_unique_linked_hash_map! {
+                "__bot".to_string() => air::ProjectItem::Assignment(air::Expression::Document(unchecked_unique_linked_hash_map! {
+                    "x_key".to_string() => air::Expression::FieldRef("_id.x_key".to_string().into()),
+                })),
+            }
+        })),
+        input = mir::Stage::Group(mir::Group {
+            source: util::mir_project_collection(None, "foo", None, None),
+            keys: vec![mir::OptionallyAliasedExpr::Aliased(mir::AliasedExpr {
+                alias: "x_key".into(),
+                expr: *util::mir_field_access("foo", "x", true),
+            })],
+            aggregations: vec![],
+            cache: mir::schema::SchemaCache::new(),
+            scope: 0,
+        })
+    );
+
     test_translate_stage!(
         unaliased_group_key_with_no_datasource_is_error,
         expected = Err(Error::InvalidGroupKey),
@@ -548,6 +627,62 @@ mod sort {
             cache: mir::schema::SchemaCache::new()
         })
     );
+
+    test_translate_stage!(
+        sort_by_computed_key_then_project_it_away,
+        expected = Ok(air::Stage::Project(air::Project {
+            source: Box::new(air::Stage::Sort(air::Sort {
+                source: Box::new(air::Stage::AddFields(air::AddFields {
+                    source: util::air_project_collection(None, "foo", None),
+                    specifications: unchecked_unique_linked_hash_map! {
+                        "__sortKeys".to_string() => air::Expression::Document(unchecked_unique_linked_hash_map! {
+                            "key0".to_string() => air::Expression::MQLSemanticOperator(air::MQLSemanticOperator {
+                                op: air::MQLOperator::Add,
+                                args: vec![
+                                    air::Expression::FieldRef("foo.b".to_string().into()),
+                                    air::Expression::FieldRef("foo.c".to_string().into()),
+                                ],
+                            }),
+                        }),
+                    },
+                })),
+                specs: vec![air::SortSpecification::Asc("__sortKeys.key0".to_string())],
+            })),
+            specifications: unchecked_unique_linked_hash_map! {
+                "foo".to_string() => air::ProjectItem::Assignment(air::Expression::FieldRef("foo".to_string().into())),
+            },
+        })),
+        input = mir::Stage::Project(mir::Project {
+            is_add_fields: false,
+            source: Box::new(mir::Stage::Sort(mir::Sort {
+                source: Box::new(mir::Stage::Project(mir::Project {
+                    is_add_fields: true,
+                    source: util::mir_project_collection(None, "foo", None, None),
+                    expression: BindingTuple(map! {
+                        Key::named("__sortKeys", 0u16) => mir::Expression::Document(unchecked_unique_linked_hash_map! {
+                            "key0".to_string() => mir::Expression::ScalarFunction(mir::ScalarFunctionApplication::new(
+                                mir::ScalarFunction::Add,
+                                vec![
+                                    *util::mir_field_access("foo", "b", false),
+                                    *util::mir_field_access("foo", "c", false),
+                                ],
+                            )),
+                        }.into()),
+                    }),
+                    cache: mir::schema::SchemaCache::new(),
+                })),
+                specs: vec![mir::SortSpecification::Asc(util::mir_field_path(
+                    "__sortKeys",
+                    vec!["key0"],
+                ))],
+                cache: mir::schema::SchemaCache::new(),
+            })),
+            expression: BindingTuple(map! {
+                Key::named("foo", 0u16) => mir::Expression::Reference(("foo", 0u16).into()),
+            }),
+            cache: mir::schema::SchemaCache::new(),
+        })
+    );
 }
 
 mod collection {
@@ -1290,6 +1425,92 @@ mod translate_plan {
         }),
     );
 
+    test_translate_plan!(
+        project_with_bot_at_multiple_scopes_only_merges_outermost_bot,
+        expected = Ok(air::Stage::ReplaceWith(air::ReplaceWith {
+            source: air::Stage::Project(air::Project {
+                source: util::air_collection_stage("test_db", "foo"),
+                specifications: unchecked_unique_linked_hash_map! {
+                    "__bot".to_string() => air::ProjectItem::Assignment(ROOT.clone()),
+                    "__bot_1".to_string() => air::ProjectItem::Assignment(air::Expression::Literal(air::LiteralValue::Integer(1))),
+                }
+            })
+            .into(),
+            new_root: air::Expression::UnsetField(air::UnsetField {
+                field: "__bot".to_string(),
+                input: air::Expression::SetField(air::SetField {
+                    field: "".to_string(),
+                    input: ROOT.clone().into(),
+                    value: air::Expression::FieldRef("__bot".to_string().into()).into(),
+                })
+                .into()
+            })
+            .into()
+        })),
+        input = mir::Stage::Project(mir::Project {
+            is_add_fields: false,
+            source: util::mir_collection("test_db", "foo"),
+            expression: BindingTuple(map! {
+                Key::bot(0) => mir::Expression::Reference(("foo", 0u16).into()),
+                Key::bot(1) => mir::Expression::Literal(mir::LiteralValue::Integer(1)),
+            }),
+            cache: mir::schema::SchemaCache::new(),
+        }),
+    );
+
+    test_translate_plan!(
+        nested_bot_projections_only_merge_outermost_bot,
+        expected = Ok(air::Stage::ReplaceWith(air::ReplaceWith {
+            source: air::Stage::Project(air::Project {
+                source: air::Stage::Project(air::Project {
+                    source: util::air_collection_stage("test_db", "foo"),
+                    specifications: unchecked_unique_linked_hash_map! {
+                        "__bot".to_string() => air::ProjectItem::Assignment(ROOT.clone()),
+                    }
+                })
+                .into(),
+                specifications: unchecked_unique_linked_hash_map! {
+                    "__bot".to_string() => air::ProjectItem::Assignment(air::Expression::FieldRef("__bot.a".to_string().into())),
+                    "__bot_1".to_string() => air::ProjectItem::Assignment(air::Expression::FieldRef("__bot".to_string().into())),
+                }
+            })
+            .into(),
+            new_root: air::Expression::UnsetField(air::UnsetField {
+                field: "__bot".to_string(),
+                input: air::Expression::SetField(air::SetField {
+                    field: "".to_string(),
+                    input: ROOT.clone().into(),
+                    value: air::Expression::FieldRef("__bot".to_string().into()).into(),
+                })
+                .into()
+            })
+            .into()
+        })),
+        input = mir::Stage::Project(mir::Project {
+            is_add_fields: false,
+            source: Box::new(mir::Stage::Derived(mir::Derived {
+                source: Box::new(mir::Stage::Project(mir::Project {
+                    is_add_fields: false,
+                    source: util::mir_collection("test_db", "foo"),
+                    expression: BindingTuple(map! {
+                        Key::bot(1) => mir::Expression::Reference(("foo", 1u16).into()),
+                    }),
+                    cache: mir::schema::SchemaCache::new(),
+                })),
+                cache: mir::schema::SchemaCache::new(),
+            })),
+            expression: BindingTuple(map! {
+                Key::bot(0) => mir::Expression::FieldAccess(mir::FieldAccess {
+                    expr: Box::new(mir::Expression::Reference(Key::bot(1).into())),
+                    field: "a".to_string(),
+                    is_nullable: true,
+                }),
+                Key::bot(1) => mir::Expression::Reference(Key::bot(1).into()),
+            }),
+            cache: mir::schema::SchemaCache::new(),
+        }),
+    );
+
     // SELECT * FROM `$foo`, `bar.baz`, `$_foo`, `_foo`, `bar`
     test_translate_plan!(
         restore_original_names_with_dots_and_dollars,
@@ -9,10 +9,17 @@ use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ScalarFunctionType {
+    ArrayLength,
+    DayName,
     Divide,
+    EqBoolean(bool),
+    IntDiv,
+    IsNullish,
+    MonthName,
     Mql(MQLOperator),
     Sql(SQLOperator),
     Trim(TrimOperator),
+    UnixTimestamp,
 }
 
 impl MqlTranslator {
@@ -58,6 +65,29 @@ impl MqlTranslator {
         })
     }
 
+    /// Get the name to use for a Bottom datasource at the given scope. The
+    /// outermost Bottom in a Project uses the provided unique_bot_name, and is
+    /// the only Bottom that may be merged into the root of the result
+    /// documents. Bottom at any inner scope (e.g. from a nested subquery
+    /// projection) is qualified with its scope so the two cannot collide.
+    pub(crate) fn get_scoped_bot_name(
+        &mut self,
+        scope: u16,
+        unique_bot_name: &str,
+        project_names: &BindingTuple<mir::Expression>,
+    ) -> String {
+        let outermost_bot_scope = project_names
+            .keys()
+            .filter(|k| k.datasource == DatasourceName::Bottom)
+            .map(|k| k.scope)
+            .min();
+        if outermost_bot_scope.is_none_or(|outermost| scope == outermost) {
+            unique_bot_name.to_string()
+        } else {
+            self.ensure_unique_datasource_name(format!("__bot_{scope}"), project_names)
+        }
+    }
+
     pub(crate) fn get_datasource_name(
         datasource: &DatasourceName,
         unique_bot_name: &str,
@@ -248,6 +278,7 @@ impl From<mir::DateFunction> for air::DateFunction {
         match df {
             mir::DateFunction::Add => air::DateFunction::Add,
             mir::DateFunction::Diff => air::DateFunction::Diff,
+            mir::DateFunction::Sub => air::DateFunction::Sub,
             mir::DateFunction::Trunc => air::DateFunction::Trunc,
         }
     }
@@ -299,6 +330,12 @@ impl From<mir::ScalarFunction> for ScalarFunctionType {
             Sub => ScalarFunctionType::Mql(MQLOperator::Subtract),
             Mul => ScalarFunctionType::Mql(MQLOperator::Multiply),
             Div => ScalarFunctionType::Divide,
+            IntDiv => ScalarFunctionType::IntDiv,
+
+            // Bitwise operators
+            BitAnd => ScalarFunctionType::Mql(MQLOperator::BitAnd),
+            BitOr => ScalarFunctionType::Mql(MQLOperator::BitOr),
+            BitXor => ScalarFunctionType::Mql(MQLOperator::BitXor),
 
             // Comparison operators
             Lt => ScalarFunctionType::Sql(SQLOperator::Lt),
@@ -308,11 +345,17 @@ impl From<mir::ScalarFunction> for ScalarFunctionType {
             Gt => ScalarFunctionType::Sql(SQLOperator::Gt),
             Gte => ScalarFunctionType::Sql(SQLOperator::Gte),
             Between => ScalarFunctionType::Sql(SQLOperator::Between),
+            // $max and $min ignore null and missing values, which matches GREATEST and LEAST.
+            Greatest => ScalarFunctionType::Mql(MQLOperator::Max),
+            Least => ScalarFunctionType::Mql(MQLOperator::Min),
 
             // Boolean operators
             Not => ScalarFunctionType::Sql(SQLOperator::Not),
             And => ScalarFunctionType::Sql(SQLOperator::And),
             Or => ScalarFunctionType::Sql(SQLOperator::Or),
+            IsTrue => ScalarFunctionType::EqBoolean(true),
+            IsFalse => ScalarFunctionType::EqBoolean(false),
+            IsUnknown => ScalarFunctionType::IsNullish,
 
             // Computed Field Access operator
             // when the field is not known until runtime.
@@ -325,6 +368,9 @@ impl From<mir::ScalarFunction> for ScalarFunctionType {
             // Array scalar functions
             Slice => ScalarFunctionType::Sql(SQLOperator::Slice),
             Size => ScalarFunctionType::Sql(SQLOperator::Size),
+            ArrayLength => ScalarFunctionType::ArrayLength,
+            ArrayDistinct => ScalarFunctionType::Mql(MQLOperator::SetUnion),
+            SortArray => ScalarFunctionType::Mql(MQLOperator::SortArray),
 
             // Numeric value scalar functions
             Position => ScalarFunctionType::Sql(SQLOperator::IndexOfCP),
@@ -348,6 +394,10 @@ impl From<mir::ScalarFunction> for ScalarFunctionType {
             // String value scalar functions
             Replace => ScalarFunctionType::Mql(MQLOperator::ReplaceAll),
             Substring => ScalarFunctionType::Sql(SQLOperator::SubstrCP),
+            Left => ScalarFunctionType::Sql(SQLOperator::Left),
+            Right => ScalarFunctionType::Sql(SQLOperator::Right),
+            LPad => ScalarFunctionType::Sql(SQLOperator::LPad),
+            RPad => ScalarFunctionType::Sql(SQLOperator::RPad),
             Upper => ScalarFunctionType::Sql(SQLOperator::ToUpper),
             Lower => ScalarFunctionType::Sql(SQLOperator::ToLower),
             BTrim => ScalarFunctionType::Trim(TrimOperator::Trim),
@@ -369,6 +419,12 @@ impl From<mir::ScalarFunction> for ScalarFunctionType {
             DayOfYear => ScalarFunctionType::Mql(MQLOperator::DayOfYear),
             IsoWeek => ScalarFunctionType::Mql(MQLOperator::IsoWeek),
             IsoWeekday => ScalarFunctionType::Mql(MQLOperator::IsoDayOfWeek),
+            MonthName => ScalarFunctionType::MonthName,
+            DayName => ScalarFunctionType::DayName,
+            UnixTimestamp => ScalarFunctionType::UnixTimestamp,
+            FromUnixTimestamp => ScalarFunctionType::Mql(MQLOperator::ToDate),
+            DateFormat => ScalarFunctionType::Mql(MQLOperator::DateToString),
+            ParseDate => ScalarFunctionType::Mql(MQLOperator::DateFromString),
 
             // MergeObjects merges an array of objects
             MergeObjects => ScalarFunctionType::Mql(MQLOperator::MergeObjects),
@@ -322,6 +322,8 @@ impl TranslateSqlService {
                 SchemaCheckingMode::Relaxed => mongosql::SchemaCheckingMode::Relaxed,
             },
             allow_order_by_missing_columns: false,
+            facet_sibling_aggregations: false,
+            hoist_common_subexpressions: false,
         })
     }
 }